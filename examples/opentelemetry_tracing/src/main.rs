@@ -0,0 +1,86 @@
+//! This example shows how to export traces of update processing to OpenTelemetry collector (Jaeger, Tempo, etc.).
+//!
+//! Each update is processed in the `update` root span with `bot_id`, `update_id`, `update_type`, `chat_id` and `user_id` fields.
+//! Spans of routers, middlewares, filters, handlers and requests to the Telegram Bot API are its children,
+//! so you can find a slow reply and see which part of the processing takes the most time.
+//!
+//! You can run this example by setting `BOT_TOKEN`, optional `RUST_LOG` and `OTEL_EXPORTER_OTLP_ENDPOINT` environment variables and running:
+//! ```bash
+//! RUST_LOG={log_level} BOT_TOKEN={your_bot_token} OTEL_EXPORTER_OTLP_ENDPOINT={collector_endpoint} cargo run --package opentelemetry_tracing
+//! ```
+//!
+//! By default, traces are exported to `http://localhost:4317` by gRPC.
+//! You can run Jaeger with OTLP collector to check traces in UI on `http://localhost:16686`:
+//! ```bash
+//! docker run --rm -p 4317:4317 -p 16686:16686 jaegertracing/all-in-one:latest
+//! ```
+
+use opentelemetry::{global, trace::TracerProvider as _, KeyValue};
+use opentelemetry_sdk::{propagation::TraceContextPropagator, runtime, trace, Resource};
+use telers::{
+    enums::UpdateType,
+    event::{telegram::HandlerResult, EventReturn, ToServiceProvider as _},
+    methods::SendMessage,
+    types::Message,
+    Bot, Dispatcher, Router,
+};
+use tracing::{event, Level};
+use tracing_subscriber::{fmt, layer::SubscriberExt as _, util::SubscriberInitExt as _, EnvFilter};
+
+async fn echo_handler(bot: Bot, message: Message) -> HandlerResult {
+    // This request is a child span of the `handler` span, which is a child of the `update` span
+    bot.send(SendMessage::new(
+        message.chat().id(),
+        message.text().unwrap_or("Send me a text message"),
+    ))
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    // Trace context is propagated to outgoing requests in `traceparent` header,
+    // so a proxy or a local Bot API server can continue the trace
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
+    let tracer_provider = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(opentelemetry_otlp::new_exporter().tonic())
+        .with_trace_config(
+            trace::Config::default()
+                .with_resource(Resource::new([KeyValue::new("service.name", "echo_bot")])),
+        )
+        .install_batch(runtime::Tokio)
+        .expect("Failed to install OpenTelemetry tracer");
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_env("RUST_LOG"))
+        .with(tracing_opentelemetry::layer().with_tracer(tracer_provider.tracer("telers")))
+        .init();
+
+    let bot = Bot::from_env_by_key("BOT_TOKEN");
+
+    let mut router = Router::new("main");
+    router.message.register(echo_handler);
+
+    let dispatcher = Dispatcher::builder()
+        .main_router(router)
+        .bot(bot)
+        .allowed_update(UpdateType::Message)
+        .build();
+
+    match dispatcher
+        .to_service_provider_default()
+        .unwrap()
+        .run_polling()
+        .await
+    {
+        Ok(()) => event!(Level::INFO, "Bot stopped"),
+        Err(err) => event!(Level::ERROR, error = %err, "Bot stopped"),
+    }
+
+    // Export remaining spans before exit
+    global::shutdown_tracer_provider();
+}
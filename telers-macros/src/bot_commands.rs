@@ -0,0 +1,451 @@
+use crate::{attrs_parsing::parse_attr, ty::is_option};
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{
+    parse::{Parse, ParseStream},
+    Fields, Item, ItemEnum, LitChar, LitStr, Token,
+};
+
+mod keywords {
+    syn::custom_keyword!(prefix);
+    syn::custom_keyword!(rename);
+    syn::custom_keyword!(description);
+    syn::custom_keyword!(rest);
+}
+
+/// Default prefix of the commands
+const DEFAULT_PREFIX: char = '/';
+/// Maximum length of the command name
+const MAX_COMMAND_LEN: usize = 32;
+
+/// Parse a single char from a string literal, for example, `"/"`
+fn parse_char(lit: &LitStr, name: &str) -> Result<LitChar, syn::Error> {
+    let value = lit.value();
+    let mut chars = value.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(char), None) => Ok(LitChar::new(char, lit.span())),
+        _ => Err(syn::Error::new_spanned(
+            lit,
+            format!("{name} must be a single char"),
+        )),
+    }
+}
+
+/// Enum attributes
+/// # Fields
+/// * `prefix` - prefix of the commands (optional, `/` by default)
+/// # Examples
+/// ```not_rust
+/// #[command(prefix = "!")]
+/// enum Type {}
+/// ```
+#[derive(Default)]
+struct EnumAttrs {
+    prefix: Option<LitChar>,
+}
+
+impl Parse for EnumAttrs {
+    fn parse(input: ParseStream) -> Result<Self, syn::Error> {
+        let mut prefix = None;
+
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+
+            // If we found `,` token, then we need to skip it and continue parsing
+            if lookahead.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+
+                continue;
+            }
+
+            if lookahead.peek(keywords::prefix) {
+                let input_prefix: keywords::prefix = input.parse()?;
+                input.parse::<Token![=]>()?;
+
+                if prefix.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        input_prefix,
+                        "duplicate `prefix` attribute",
+                    ));
+                }
+
+                prefix = Some(parse_char(&input.parse()?, "prefix")?);
+
+                continue;
+            }
+
+            // If we found unknown attribute, then we need to return error
+            return Err(syn::Error::new(input.span(), "expected `prefix` attribute"));
+        }
+
+        Ok(Self { prefix })
+    }
+}
+
+/// Variant attributes
+/// # Fields
+/// * `rename` - name of the command (optional, name of the variant in `snake_case` by default)
+/// * `description` - description of the command (optional).
+///   Commands without description aren't included in the list of bot commands
+/// # Examples
+/// ```not_rust
+/// #[command(rename = "start", description = "Start the bot")]
+/// Variant,
+/// ```
+#[derive(Default)]
+struct VariantAttrs {
+    rename: Option<LitStr>,
+    description: Option<LitStr>,
+}
+
+impl Parse for VariantAttrs {
+    fn parse(input: ParseStream) -> Result<Self, syn::Error> {
+        let mut rename = None;
+        let mut description = None;
+
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+
+            // If we found `,` token, then we need to skip it and continue parsing
+            if lookahead.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+
+                continue;
+            }
+
+            if lookahead.peek(keywords::rename) {
+                let input_rename: keywords::rename = input.parse()?;
+                input.parse::<Token![=]>()?;
+
+                if rename.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        input_rename,
+                        "duplicate `rename` attribute",
+                    ));
+                }
+
+                rename = Some(input.parse()?);
+
+                continue;
+            }
+
+            if lookahead.peek(keywords::description) {
+                let input_description: keywords::description = input.parse()?;
+                input.parse::<Token![=]>()?;
+
+                if description.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        input_description,
+                        "duplicate `description` attribute",
+                    ));
+                }
+
+                description = Some(input.parse()?);
+
+                continue;
+            }
+
+            // If we found unknown attribute, then we need to return error
+            return Err(syn::Error::new(
+                input.span(),
+                "expected `rename` or `description` attribute",
+            ));
+        }
+
+        Ok(Self {
+            rename,
+            description,
+        })
+    }
+}
+
+/// Field attributes
+/// # Fields
+/// * `rest` - the field gets the rest of the text (optional). Only the last field can have this attribute
+/// # Examples
+/// ```not_rust
+/// Variant(#[command(rest)] String),
+/// ```
+#[derive(Default)]
+struct FieldAttrs {
+    rest: bool,
+}
+
+impl Parse for FieldAttrs {
+    fn parse(input: ParseStream) -> Result<Self, syn::Error> {
+        let mut rest = false;
+
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+
+            // If we found `,` token, then we need to skip it and continue parsing
+            if lookahead.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+
+                continue;
+            }
+
+            if lookahead.peek(keywords::rest) {
+                let input_rest: keywords::rest = input.parse()?;
+
+                if rest {
+                    return Err(syn::Error::new_spanned(
+                        input_rest,
+                        "duplicate `rest` attribute",
+                    ));
+                }
+
+                rest = true;
+
+                continue;
+            }
+
+            // If we found unknown attribute, then we need to return error
+            return Err(syn::Error::new(input.span(), "expected `rest` attribute"));
+        }
+
+        Ok(Self { rest })
+    }
+}
+
+/// Convert `PascalCase` name of the variant to `snake_case` name of the command
+fn to_snake_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+
+    for (index, char) in name.chars().enumerate() {
+        if char.is_uppercase() {
+            if index != 0 {
+                result.push('_');
+            }
+            result.extend(char.to_lowercase());
+        } else {
+            result.push(char);
+        }
+    }
+
+    result
+}
+
+/// Check the command name by Telegram rules: 1-32 chars, only lowercase English letters, digits and underscores
+fn validate_command(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_COMMAND_LEN
+        && name
+            .chars()
+            .all(|char| char.is_ascii_lowercase() || char.is_ascii_digit() || char == '_')
+}
+
+#[allow(clippy::too_many_lines)]
+fn expand_enum(
+    ItemEnum {
+        attrs,
+        ident,
+        generics,
+        variants,
+        ..
+    }: &ItemEnum,
+) -> Result<TokenStream, syn::Error> {
+    if !generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            generics,
+            "generic enums aren't supported",
+        ));
+    }
+
+    if variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "expected at least one variant",
+        ));
+    }
+
+    let enum_attrs: EnumAttrs = parse_attr("command", attrs)?.unwrap_or_default();
+    let prefix = enum_attrs
+        .prefix
+        .unwrap_or_else(|| LitChar::new(DEFAULT_PREFIX, ident.span()));
+
+    let mut names = vec![];
+    let mut parse_arms = vec![];
+    let mut bot_commands = vec![];
+
+    for variant in variants {
+        let variant_ident = &variant.ident;
+        let variant_attrs: VariantAttrs =
+            parse_attr("command", &variant.attrs)?.unwrap_or_default();
+
+        let name = match variant_attrs.rename {
+            Some(ref rename) => rename.value(),
+            None => to_snake_case(&variant_ident.to_string()),
+        };
+
+        if !validate_command(&name) {
+            return Err(syn::Error::new_spanned(
+                variant_ident,
+                format!("invalid command `{name}`, it must be 1-{MAX_COMMAND_LEN} chars long and contain only lowercase English letters, digits and underscores"),
+            ));
+        }
+
+        if names.contains(&name) {
+            return Err(syn::Error::new_spanned(
+                variant_ident,
+                format!("duplicate command `{name}`"),
+            ));
+        }
+
+        let mut values = vec![];
+        let mut has_optional = false;
+
+        for (index, field) in variant.fields.iter().enumerate() {
+            let field_attrs: FieldAttrs = parse_attr("command", &field.attrs)?.unwrap_or_default();
+
+            if field_attrs.rest && index + 1 != variant.fields.len() {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "only the last field can have `rest` attribute",
+                ));
+            }
+
+            let argument = match field.ident {
+                Some(ref field_ident) => field_ident.to_string(),
+                None => (index + 1).to_string(),
+            };
+
+            let is_optional = is_option(&field.ty);
+
+            // Arguments are positional, so a required argument after an optional one can't be parsed unambiguously
+            if has_optional && !is_optional {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "required field can't follow an optional field",
+                ));
+            }
+            has_optional |= is_optional;
+
+            let value = match (field_attrs.rest, is_optional) {
+                (true, true) => quote! { args.rest_option(#argument)? },
+                (true, false) => quote! { args.rest(#argument)? },
+                (false, true) => quote! { args.next_option(#argument)? },
+                (false, false) => quote! { args.next(#argument)? },
+            };
+
+            values.push(match field.ident {
+                Some(ref field_ident) => quote! { #field_ident: #value },
+                None => value,
+            });
+        }
+
+        let construct = match variant.fields {
+            Fields::Named(_) => quote! { Self::#variant_ident { #(#values),* } },
+            Fields::Unnamed(_) => quote! { Self::#variant_ident(#(#values),*) },
+            Fields::Unit => quote! { Self::#variant_ident },
+        };
+
+        parse_arms.push(quote! { #name => #construct, });
+
+        if let Some(description) = variant_attrs.description {
+            bot_commands.push(quote! {
+                ::telers::types::BotCommand::new(#name, #description)
+            });
+        }
+
+        names.push(name);
+    }
+
+    Ok(quote_spanned! { ident.span() =>
+        #[automatically_derived]
+        impl ::telers::utils::bot_commands::BotCommands for #ident {
+            const PREFIX: char = #prefix;
+
+            fn names() -> &'static [&'static str] {
+                &[#(#names),*]
+            }
+
+            fn parse(command: &str, args: &str) -> ::std::result::Result<Self, ::telers::utils::bot_commands::ErrorKind> {
+                #[allow(unused_mut)]
+                let mut args = ::telers::utils::bot_commands::Args::new(command, args);
+
+                let value = match command {
+                    #(#parse_arms)*
+                    _ => {
+                        return Err(::telers::utils::bot_commands::ErrorKind::UnknownCommand {
+                            command: command.to_owned(),
+                        })
+                    }
+                };
+
+                args.finish()?;
+
+                Ok(value)
+            }
+
+            fn bot_commands() -> ::std::vec::Vec<::telers::types::BotCommand> {
+                vec![#(#bot_commands),*]
+            }
+        }
+
+        #[automatically_derived]
+        impl<__C> ::telers::extractors::FromEventAndContext<__C> for #ident {
+            type Error = ::telers::utils::bot_commands::ErrorKind;
+
+            #[inline]
+            fn extract(
+                _bot: ::std::sync::Arc<::telers::client::Bot<__C>>,
+                update: ::std::sync::Arc<::telers::types::Update>,
+                _context: ::std::sync::Arc<::telers::context::Context>,
+            ) -> Result<Self, Self::Error> {
+                <Self as ::telers::utils::bot_commands::BotCommands>::from_update(&update)
+            }
+        }
+    })
+}
+
+pub(crate) fn expand(item: Item) -> Result<TokenStream, syn::Error> {
+    match item {
+        Item::Enum(item) => expand_enum(&item),
+        _ => Err(syn::Error::new_spanned(item, "expected `enum`")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_snake_case() {
+        assert_eq!(to_snake_case("Start"), "start");
+        assert_eq!(to_snake_case("BanUser"), "ban_user");
+        assert_eq!(to_snake_case("Top10"), "top10");
+    }
+
+    #[test]
+    fn test_expand_field_order() {
+        let item: Item = syn::parse_quote! {
+            enum Command {
+                Add { title: String, priority: Option<u8>, #[command(rest)] note: Option<String> },
+            }
+        };
+        assert!(expand(item).is_ok());
+
+        let item: Item = syn::parse_quote! {
+            enum Command {
+                Add { priority: Option<u8>, title: String },
+            }
+        };
+        assert_eq!(
+            expand(item).unwrap_err().to_string(),
+            "required field can't follow an optional field"
+        );
+    }
+
+    #[test]
+    fn test_validate_command() {
+        assert!(validate_command("start"));
+        assert!(validate_command("ban_user2"));
+        assert!(!validate_command(""));
+        assert!(!validate_command("Start"));
+        assert!(!validate_command("ban-user"));
+        assert!(!validate_command(&"a".repeat(33)));
+    }
+}
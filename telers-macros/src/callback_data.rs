@@ -0,0 +1,255 @@
+use crate::{attrs_parsing::parse_attr, ty::is_option};
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote, Item, ItemStruct, LitChar, LitStr, Token,
+};
+
+mod keywords {
+    syn::custom_keyword!(prefix);
+    syn::custom_keyword!(sep);
+}
+
+/// Default separator between the prefix and the values of the fields
+const DEFAULT_SEPARATOR: char = ':';
+
+/// All callback data attributes
+/// # Fields
+/// * `prefix` - prefix of the packed data (required)
+/// * `separator` - separator between the prefix and the values of the fields (optional, `:` by default)
+/// # Examples
+/// ```not_rust
+/// #[callback_data(prefix = "buy")]
+/// struct Type;
+///
+/// #[callback_data(prefix = "buy", sep = "|")]
+/// struct AnotherType;
+/// ```
+/// # Notes
+/// If any unknown attribute is found, then we return error
+///
+/// If the prefix is empty, the separator isn't a single char or the prefix contains the separator, then we return error
+struct CallbackDataAttrs {
+    prefix: LitStr,
+    separator: LitChar,
+}
+
+/// Parse `#[callback_data(...)]` attributes
+/// # Examples
+/// ```not_rust
+/// #[callback_data(prefix = "buy", sep = ":")]
+/// ```
+impl Parse for CallbackDataAttrs {
+    fn parse(input: ParseStream) -> Result<Self, syn::Error> {
+        let mut prefix: Option<LitStr> = None;
+        let mut separator: Option<LitStr> = None;
+
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+
+            // If we found `,` token, then we need to skip it and continue parsing
+            if lookahead.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+
+                continue;
+            }
+
+            if lookahead.peek(keywords::prefix) {
+                let input_prefix: keywords::prefix = input.parse()?;
+                input.parse::<Token![=]>()?;
+
+                if prefix.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        input_prefix,
+                        "duplicate `prefix` attribute",
+                    ));
+                }
+
+                prefix = Some(input.parse()?);
+
+                continue;
+            }
+
+            if lookahead.peek(keywords::sep) {
+                let input_sep: keywords::sep = input.parse()?;
+                input.parse::<Token![=]>()?;
+
+                if separator.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        input_sep,
+                        "duplicate `sep` attribute",
+                    ));
+                }
+
+                separator = Some(input.parse()?);
+
+                continue;
+            }
+
+            // If we found unknown attribute, then we need to return error
+            return Err(syn::Error::new(
+                input.span(),
+                "expected `prefix` or `sep` attribute",
+            ));
+        }
+
+        let prefix =
+            prefix.ok_or_else(|| syn::Error::new(input.span(), "missing `prefix` attribute"))?;
+
+        if prefix.value().is_empty() {
+            return Err(syn::Error::new_spanned(prefix, "prefix can't be empty"));
+        }
+
+        let separator = match separator {
+            Some(separator) => {
+                let value = separator.value();
+                let mut chars = value.chars();
+
+                match (chars.next(), chars.next()) {
+                    (Some(char), None) => LitChar::new(char, separator.span()),
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            separator,
+                            "separator must be a single char",
+                        ))
+                    }
+                }
+            }
+            None => LitChar::new(DEFAULT_SEPARATOR, Span::call_site()),
+        };
+
+        if prefix.value().contains(separator.value()) {
+            return Err(syn::Error::new_spanned(
+                prefix,
+                "prefix can't contain the separator",
+            ));
+        }
+
+        Ok(Self { prefix, separator })
+    }
+}
+
+fn expand_struct(
+    ItemStruct {
+        attrs,
+        ident,
+        generics,
+        fields,
+        ..
+    }: &ItemStruct,
+) -> Result<TokenStream, syn::Error> {
+    let CallbackDataAttrs { prefix, separator } = match parse_attr("callback_data", attrs) {
+        Ok(Some(attrs)) => attrs,
+        Ok(None) => {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "missing `#[callback_data(...)]` attribute",
+            ))
+        }
+        Err(err) => {
+            return Err(syn::Error::new_spanned(
+                ident,
+                format!("failed to parse `#[callback_data(...)]` attributes: {err}"),
+            ))
+        }
+    };
+
+    let mut pack_fields = vec![];
+    let mut unpack_fields = vec![];
+
+    for (index, field) in fields.iter().enumerate() {
+        let (member, name) = match &field.ident {
+            Some(field_ident) => (quote! { #field_ident }, field_ident.to_string()),
+            None => {
+                let index = syn::Index::from(index);
+                (quote! { #index }, index.index.to_string())
+            }
+        };
+
+        if is_option(&field.ty) {
+            pack_fields.push(quote! { packer.push_option(#name, self.#member.as_ref())?; });
+            unpack_fields.push(quote! { #member: unpacker.next_option(#name)? });
+        } else {
+            pack_fields.push(quote! { packer.push(#name, &self.#member)?; });
+            unpack_fields.push(quote! { #member: unpacker.next(#name)? });
+        }
+    }
+
+    let fields_count = fields.len();
+    let (pack, unpack) = if fields.is_empty() {
+        (
+            quote! {
+                ::telers::utils::callback_data::Packer::new(Self::PREFIX, Self::SEPARATOR).finish()
+            },
+            quote! {
+                ::telers::utils::callback_data::Unpacker::new(data, Self::PREFIX, Self::SEPARATOR, 0)?;
+
+                Ok(Self {})
+            },
+        )
+    } else {
+        (
+            quote! {
+                let mut packer = ::telers::utils::callback_data::Packer::new(Self::PREFIX, Self::SEPARATOR);
+                #(#pack_fields)*
+                packer.finish()
+            },
+            quote! {
+                let mut unpacker = ::telers::utils::callback_data::Unpacker::new(
+                    data,
+                    Self::PREFIX,
+                    Self::SEPARATOR,
+                    #fields_count,
+                )?;
+
+                Ok(Self { #(#unpack_fields),* })
+            },
+        )
+    };
+
+    let (ident_impl_generics, ident_ty_generics, ident_where_clause) = generics.split_for_impl();
+
+    // We use `__` prefix here to avoid name conflicts
+    let mut extract_generics = generics.clone();
+    extract_generics.params.push(parse_quote! { __C });
+    let (extract_impl_generics, _, _) = extract_generics.split_for_impl();
+
+    Ok(quote_spanned! { ident.span() =>
+        #[automatically_derived]
+        impl #ident_impl_generics ::telers::utils::callback_data::CallbackData for #ident #ident_ty_generics #ident_where_clause {
+            const PREFIX: &'static str = #prefix;
+            const SEPARATOR: char = #separator;
+
+            fn pack(&self) -> ::std::result::Result<::std::string::String, ::telers::utils::callback_data::ErrorKind> {
+                #pack
+            }
+
+            fn unpack(data: &str) -> ::std::result::Result<Self, ::telers::utils::callback_data::ErrorKind> {
+                #unpack
+            }
+        }
+
+        #[automatically_derived]
+        impl #extract_impl_generics ::telers::extractors::FromEventAndContext<__C> for #ident #ident_ty_generics #ident_where_clause {
+            type Error = ::telers::utils::callback_data::ErrorKind;
+
+            #[inline]
+            fn extract(
+                _bot: ::std::sync::Arc<::telers::client::Bot<__C>>,
+                update: ::std::sync::Arc<::telers::types::Update>,
+                _context: ::std::sync::Arc<::telers::context::Context>,
+            ) -> Result<Self, Self::Error> {
+                <Self as ::telers::utils::callback_data::CallbackData>::from_update(&update)
+            }
+        }
+    })
+}
+
+pub(crate) fn expand(item: Item) -> Result<TokenStream, syn::Error> {
+    match item {
+        Item::Struct(item) => expand_struct(&item),
+        _ => Err(syn::Error::new_spanned(item, "expected `struct`")),
+    }
+}
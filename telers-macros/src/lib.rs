@@ -7,9 +7,27 @@
 /// Derive an implementation of `FromEventAndContext` for the given type.
 /// This macro will generate an implementation of `FromEventAndContext` for the whole given type.
 /// It will use the key attribute by which this type will be extracted from context.
+///
+/// ## `FromEvent`
+///
+/// Derive an implementation of `FromEventAndContext` for the given type.
+/// This macro will generate an implementation of `FromEventAndContext` for the type converted from the event.
+///
+/// ## `CallbackData`
+///
+/// Derive an implementation of `CallbackData` and `FromEventAndContext` for the given struct.
+/// This macro will generate packing of the struct into callback data and unpacking of it back.
+///
+/// ## `BotCommands`
+///
+/// Derive an implementation of `BotCommands` and `FromEventAndContext` for the given enum.
+/// This macro will generate parsing of commands with typed arguments and descriptions of the commands.
 pub(crate) mod attrs_parsing;
 pub(crate) mod stream;
+pub(crate) mod ty;
 
+mod bot_commands;
+mod callback_data;
 mod from_context;
 mod from_event;
 
@@ -244,6 +262,102 @@ pub fn derive_from_event(item: TokenStream) -> TokenStream {
     expand_with(item, from_event::expand)
 }
 
+/// Derive an implementation of `CallbackData` and `FromEventAndContext` for the given struct.
+///
+/// This macro supports the following attributes:
+/// * `#[callback_data(prefix = "...")]` - the prefix of the packed data to distinguish different types of callback data.
+/// * `#[callback_data(sep = "...")]` - the separator between the prefix and the values of the fields. \
+///   It must be a single char and it's `:` by default.
+///
+/// Values of the fields are converted with `ToString` and `FromStr`, so each field type must implement them.
+/// Fields of `Option<T>` type are packed as empty strings if they are `None`, \
+/// so packing of `Some` with an empty value returns an error, because it would be unpacked as `None`.
+///
+/// The generated `FromEventAndContext` implementation parses the data of the callback query from the update,
+/// so the struct can be used as handler argument.
+///
+/// ```rust
+/// use telers::utils::callback_data::CallbackData;
+///
+/// #[derive(Debug, PartialEq, CallbackData)]
+/// #[callback_data(prefix = "page", sep = "|")]
+/// struct Page {
+///  number: u32,
+///  query: Option<String>,
+/// }
+///
+/// let data = Page { number: 2, query: None }.pack().unwrap();
+///
+/// assert_eq!(data, "page|2|");
+/// assert_eq!(Page::unpack(&data).unwrap(), Page { number: 2, query: None });
+/// ```
+///
+/// Tuple and unit structs are supported as well:
+///
+/// ```rust
+/// use telers::utils::callback_data::CallbackData;
+///
+/// #[derive(CallbackData)]
+/// #[callback_data(prefix = "item")]
+/// struct Item(i64);
+///
+/// #[derive(CallbackData)]
+/// #[callback_data(prefix = "back")]
+/// struct Back;
+///
+/// assert_eq!(Item(5).pack().unwrap(), "item:5");
+/// assert_eq!(Back.pack().unwrap(), "back");
+/// ```
+#[proc_macro_derive(CallbackData, attributes(callback_data))]
+pub fn derive_callback_data(item: TokenStream) -> TokenStream {
+    expand_with(item, callback_data::expand)
+}
+
+/// Derive an implementation of `BotCommands` and `FromEventAndContext` for the given enum.
+///
+/// Each variant of the enum is a command and its fields are the arguments of the command.
+/// This macro supports the following attributes:
+/// * `#[command(prefix = "...")]` on the enum - the prefix of the commands. \
+///   It must be a single char and it's `/` by default.
+/// * `#[command(rename = "...")]` on a variant - the name of the command. \
+///   By default, it's the name of the variant in `snake_case`.
+/// * `#[command(description = "...")]` on a variant - the description of the command. \
+///   Commands without description aren't included in `BotCommands::bot_commands`, so they are hidden in the menu.
+/// * `#[command(rest)]` on the last field - the field gets the rest of the text, including whitespaces and newlines.
+///
+/// Arguments are separated by whitespaces and parsed with `FromStr`, an argument with whitespaces can be quoted with `"`.
+/// Fields of `Option<T>` type are optional arguments, so they must go after required ones.
+///
+/// The generated `FromEventAndContext` implementation parses the command from the message of the update,
+/// so the enum can be used as handler argument. \
+/// It doesn't check the bot mention, so use it with `BotCommands::filter`, which skips commands addressed to other bots.
+///
+/// ```rust
+/// use telers::utils::bot_commands::BotCommands;
+///
+/// #[derive(Debug, PartialEq, BotCommands)]
+/// #[command(prefix = "!")]
+/// enum Command {
+///  #[command(description = "Show help")]
+///  Help,
+///  #[command(rename = "ban", description = "Ban a user for some days")]
+///  BanUser { user_id: i64, days: Option<u32> },
+///  Note(#[command(rest)] String),
+/// }
+///
+/// assert_eq!(Command::parse_text("!help").unwrap(), Command::Help);
+/// assert_eq!(
+///  Command::parse_text("!ban 42 7").unwrap(),
+///  Command::BanUser { user_id: 42, days: Some(7) },
+/// );
+/// assert_eq!(Command::parse_text("!note \"quoted\" text").unwrap(), Command::Note("\"quoted\" text".to_owned()));
+/// assert_eq!(Command::bot_commands().len(), 2);
+/// ```
+#[proc_macro_derive(BotCommands, attributes(command))]
+pub fn derive_bot_commands(item: TokenStream) -> TokenStream {
+    expand_with(item, bot_commands::expand)
+}
+
 fn expand_with<F, I, K>(input: TokenStream, f: F) -> TokenStream
 where
     F: FnOnce(I) -> syn::Result<K>,
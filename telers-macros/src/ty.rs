@@ -0,0 +1,20 @@
+use syn::{GenericArgument, PathArguments, Type};
+
+/// Check if the type is `Option<T>` type
+pub(crate) fn is_option(ty: &Type) -> bool {
+    let Type::Path(ty) = ty else {
+        return false;
+    };
+    let Some(segment) = ty.path.segments.last() else {
+        return false;
+    };
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => {
+            segment.ident == "Option"
+                && args.args.len() == 1
+                && matches!(args.args.first(), Some(GenericArgument::Type(_)))
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,71 @@
+//! Benchmarks of building request bodies for methods without files.
+//!
+//! Requests without files are sent with `application/json` body,
+//! these benchmarks compare it with `multipart/form-data` body, which is used for requests with files.
+//!
+//! You can run these benchmarks by running:
+//! ```bash
+//! cargo bench --package telers --bench request_body
+//! ```
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use reqwest::Client;
+use telers::{
+    client::Reqwest,
+    enums::ParseMode,
+    methods::{AnswerCallbackQuery, SendMessage},
+    types::{InlineKeyboardButton, InlineKeyboardMarkup, LinkPreviewOptions, ReplyParameters},
+};
+
+const URL: &str = "https://api.telegram.org/bot1234567890:AAAA/sendMessage";
+
+fn send_message() -> SendMessage {
+    SendMessage::new(1_234_567_890, "<b>Hello, world!</b> ".repeat(20))
+        .parse_mode(ParseMode::HTML)
+        .link_preview_options(LinkPreviewOptions::new().is_disabled(true))
+        .reply_parameters(ReplyParameters::new(1))
+        .reply_markup(InlineKeyboardMarkup::new((0..5).map(|row| {
+            (0..3).map(move |column| {
+                InlineKeyboardButton::new(format!("Button {row}:{column}"))
+                    .callback_data(format!("button:{row}:{column}"))
+            })
+        })))
+}
+
+fn answer_callback_query() -> AnswerCallbackQuery {
+    AnswerCallbackQuery::new("1234567890").text("Done")
+}
+
+fn bench_request_body(c: &mut Criterion) {
+    let session = Reqwest::default();
+    let client = Client::new();
+
+    let mut group = c.benchmark_group("send_message");
+    let method = send_message();
+    group.bench_function("json", |b| {
+        b.iter(|| client.post(URL).json(black_box(&method)).build().unwrap());
+    });
+    group.bench_function("multipart", |b| {
+        b.iter(|| {
+            let form = session.build_form_data(black_box(&method), None).unwrap();
+            client.post(URL).multipart(form).build().unwrap()
+        });
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("answer_callback_query");
+    let method = answer_callback_query();
+    group.bench_function("json", |b| {
+        b.iter(|| client.post(URL).json(black_box(&method)).build().unwrap());
+    });
+    group.bench_function("multipart", |b| {
+        b.iter(|| {
+            let form = session.build_form_data(black_box(&method), None).unwrap();
+            client.post(URL).multipart(form).build().unwrap()
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_request_body);
+criterion_main!(benches);
@@ -3,14 +3,20 @@
 //!
 //! Components are:
 //! - [`bot`] module with the main entry point for the library
+//! - [`default`] module with default properties of the bot, which are applied to outgoing methods
 //! - [`session`] module with components for sending requests
 //! - [`telegram`] module with configuration of the Telegram Bot API
 //!
 //! Check each submodule for more information.
 
 pub mod bot;
+pub mod default;
 pub mod session;
 pub mod telegram;
 
-pub use bot::Bot;
+pub use bot::{Bot, WithBusinessConnectionId};
+pub use default::{
+    DefaultBotProperties, DefaultFields, DefaultFieldsRef, ParseModeField, ParseModeFieldRef,
+    WithDefaultProperties,
+};
 pub use session::{Reqwest, Session};
@@ -12,6 +12,15 @@
 //!
 //! You can use custom client by using [`Bot::with_client`] method.
 //!
+//! You can set default properties of outgoing methods (like `parse_mode`) by using [`Bot::with_default_properties`] method,
+//! check [`default`] module for more information.
+//!
+//! You can register request middlewares by using [`Bot::with_request_middleware`] method.
+//! They are called for every request sent by the bot, check [`middlewares`] module for more information.
+//!
+//! You can scope the bot to a business connection by using [`Bot::with_business_connection_id`] method,
+//! so methods which are called on behalf of the business account don't need `business_connection_id` to be repeated.
+//!
 //! # Examples
 //! ```rust
 //! use telers::{Bot, methods::SendMessage};
@@ -42,27 +51,36 @@
 //!
 //! [`examples`]: https://github.com/Desiders/telers/tree/dev-1.x/examples
 //! [`methods`]: crate::methods
+//! [`middlewares`]: crate::client::session::middlewares
+//! [`default`]: crate::client::default
 
-use super::{session::base::Session, Reqwest};
+use super::{
+    default::DefaultBotProperties,
+    session::{base::Session, middlewares::Middleware as RequestMiddleware},
+    Reqwest,
+};
 
 use crate::{errors::SessionErrorKind, methods::TelegramMethod, utils::token};
 
 use std::{
+    borrow::Cow,
     env,
     fmt::{self, Debug, Display, Formatter},
+    iter::once,
+    sync::Arc,
 };
 use tracing::instrument;
 
 /// Represents a bot with its token and ID, also contains client for sending requests to Telegram API.
 /// # Notes
-/// This structure is cheap to clone, because it contains only [`String`], [`i64`] fields and a client.
+/// This structure is cheap to clone, because it contains only [`String`], [`i64`] fields, request middlewares wrapped in [`Arc`] and a client.
 ///
 /// Default client is [`Reqwest`], which also is cheap to clone.
 ///
 /// You can use custom client by using [`Bot::with_client`] method.
 ///
 /// Check [module docs](crate::client::bot) for examples.
-#[derive(Clone, Default)]
+#[derive(Clone)]
 pub struct Bot<Client: ?Sized = Reqwest> {
     /// Bot token, which is used to receive updates and send requests to the Telegram API
     pub token: String,
@@ -70,6 +88,12 @@ pub struct Bot<Client: ?Sized = Reqwest> {
     pub hidden_token: String,
     /// Bot id, extracted from the token
     pub bot_id: i64,
+    /// Default properties, which are applied to every outgoing method which supports them
+    pub(crate) default_properties: Arc<DefaultBotProperties>,
+    /// Request middlewares, which are called for every request in order of registration
+    pub(crate) request_middlewares: Arc<[Arc<dyn RequestMiddleware>]>,
+    /// Business connection, which is applied to every outgoing method which supports it
+    pub(crate) business_connection_id: Option<Arc<str>>,
     /// Client for sending requests to Telegram API
     client: Client,
 }
@@ -90,9 +114,155 @@ impl<Client> Bot<Client> {
             token,
             hidden_token,
             bot_id,
+            default_properties: Arc::default(),
+            request_middlewares: Arc::new([]),
+            business_connection_id: None,
             client,
         }
     }
+
+    /// Set default properties, which will be applied to every outgoing method which supports them,
+    /// if the method leaves these fields `None`
+    #[must_use]
+    pub fn with_default_properties(self, val: DefaultBotProperties) -> Self {
+        Self {
+            default_properties: Arc::new(val),
+            ..self
+        }
+    }
+
+    /// Get a copy of the bot without default properties.
+    /// Use it to opt out of default properties for a single call.
+    #[must_use]
+    pub fn without_default_properties(&self) -> Self
+    where
+        Client: Clone,
+    {
+        Self {
+            default_properties: Arc::default(),
+            ..self.clone()
+        }
+    }
+
+    /// Get default properties of the bot
+    #[must_use]
+    pub fn default_properties(&self) -> &DefaultBotProperties {
+        &self.default_properties
+    }
+
+    /// Register request middleware, which will be called for every request sent by the bot.
+    /// Middlewares are called in order of registration.
+    #[must_use]
+    pub fn with_request_middleware(self, val: impl RequestMiddleware + 'static) -> Self {
+        Self {
+            request_middlewares: self
+                .request_middlewares
+                .iter()
+                .cloned()
+                .chain(once(Arc::new(val) as _))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Get request middlewares of the bot in order of registration
+    #[must_use]
+    pub fn request_middlewares(&self) -> &[Arc<dyn RequestMiddleware>] {
+        &self.request_middlewares
+    }
+
+    /// Scope the bot to a business connection, which will be applied to every outgoing method which supports it,
+    /// if the method leaves `business_connection_id` field `None`
+    /// # Notes
+    /// Methods which can be sent only on behalf of a business connection (like [`ReadBusinessMessage`]),
+    /// return [`SessionErrorKind::MissingField`] error without sending the request,
+    /// if neither the method nor the bot specify `business_connection_id`
+    ///
+    /// [`ReadBusinessMessage`]: crate::methods::ReadBusinessMessage
+    #[must_use]
+    pub fn with_business_connection_id(self, val: impl Into<Arc<str>>) -> Self {
+        Self {
+            business_connection_id: Some(val.into()),
+            ..self
+        }
+    }
+
+    /// Get a copy of the bot without business connection scope
+    #[must_use]
+    pub fn without_business_connection_id(&self) -> Self
+    where
+        Client: Clone,
+    {
+        Self {
+            business_connection_id: None,
+            ..self.clone()
+        }
+    }
+
+    /// Get business connection the bot is scoped to
+    #[must_use]
+    pub fn business_connection_id(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    /// Fill `business_connection_id` of the method by business connection the bot is scoped to,
+    /// if the method leaves the field `None`
+    pub fn apply_business_connection_id<T>(&self, method: &mut Cow<'_, T>)
+    where
+        T: WithBusinessConnectionId,
+    {
+        if let (None, Some(business_connection_id)) = (
+            method.business_connection_id_ref(),
+            self.business_connection_id(),
+        ) {
+            *method.to_mut().business_connection_id_mut() = Some(business_connection_id.to_owned());
+        }
+    }
+
+    /// Check that `business_connection_id` is specified by the method or by the bot.
+    /// It's used by methods which can be sent only on behalf of a business connection
+    /// # Errors
+    /// If neither the method nor the bot specify `business_connection_id`
+    pub fn require_business_connection_id<T>(&self, method: &T) -> Result<(), SessionErrorKind>
+    where
+        T: WithBusinessConnectionId,
+    {
+        if method.business_connection_id_ref().is_none() && self.business_connection_id.is_none() {
+            return Err(SessionErrorKind::MissingField("business_connection_id"));
+        }
+
+        Ok(())
+    }
+}
+
+/// Methods which can be sent on behalf of a business connection,
+/// so `business_connection_id` can be filled by business connection the bot is scoped to.
+/// Check [`Bot::with_business_connection_id`] for more information.
+pub trait WithBusinessConnectionId: Clone {
+    /// Get `business_connection_id` field of the method
+    #[must_use]
+    fn business_connection_id_ref(&self) -> Option<&str>;
+
+    /// Get mutable `business_connection_id` field of the method
+    #[must_use]
+    fn business_connection_id_mut(&mut self) -> &mut Option<String>;
+}
+
+impl<Client> Default for Bot<Client>
+where
+    Client: Default,
+{
+    fn default() -> Self {
+        Self {
+            token: String::default(),
+            hidden_token: String::default(),
+            bot_id: 0,
+            default_properties: Arc::default(),
+            request_middlewares: Arc::new([]),
+            business_connection_id: None,
+            client: Client::default(),
+        }
+    }
 }
 
 impl Bot<Reqwest> {
@@ -131,6 +301,9 @@ impl<Client> Debug for Bot<Client> {
         f.debug_struct("Bot")
             .field("token", &self.hidden_token)
             .field("bot_id", &self.bot_id)
+            .field("default_properties", &self.default_properties)
+            .field("request_middlewares", &self.request_middlewares.len())
+            .field("business_connection_id", &self.business_connection_id)
             .finish_non_exhaustive()
     }
 }
@@ -180,6 +353,7 @@ impl<Client: Session> Bot<Client> {
     /// # Notes
     /// This method uses passed timeout for requests.
     /// If you want to use default timeout, use [`Bot::send`] method.
+    #[instrument(skip(self, method))]
     pub async fn send_with_timeout<T, TRef>(
         &self,
         method: TRef,
@@ -195,3 +369,95 @@ impl<Client: Session> Bot<Client> {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        methods::{ReadBusinessMessage, SendMessage, SendPhoto},
+        types::InputFile,
+    };
+
+    use bytes::Bytes;
+    use futures::stream;
+
+    #[test]
+    fn test_apply_business_connection_id() {
+        let bot = Bot::<Reqwest>::default().with_business_connection_id("bot_connection");
+
+        // Business connection of the bot is filled in
+        let method = SendMessage::new(1, "test");
+        let request = method.build_request(&bot);
+        assert_eq!(
+            request.data.business_connection_id.as_deref(),
+            Some("bot_connection")
+        );
+
+        // Explicitly set business connection takes precedence over the bot's one
+        let method = SendMessage::new(1, "test").business_connection_id("method_connection");
+        let request = method.build_request(&bot);
+        assert!(matches!(request.data, Cow::Borrowed(_)));
+        assert_eq!(
+            request.data.business_connection_id.as_deref(),
+            Some("method_connection")
+        );
+
+        // Business connection isn't filled in, if the bot isn't scoped to it
+        let method = SendMessage::new(1, "test");
+        let request = method.build_request(&bot.without_business_connection_id());
+        assert!(matches!(request.data, Cow::Borrowed(_)));
+        assert_eq!(request.data.business_connection_id, None);
+    }
+
+    #[test]
+    fn test_apply_business_connection_id_to_stream_file() {
+        let bot = Bot::<Reqwest>::default().with_business_connection_id("bot_connection");
+
+        let method = SendPhoto::new(
+            1,
+            InputFile::stream(stream::iter([Ok(Bytes::from_static(b"test"))])),
+        );
+        let request = method.build_request(&bot);
+        assert!(matches!(request.data, Cow::Owned(_)));
+
+        // Multipart part of the file is named by its ID, so the data must refer to the same ID
+        let [InputFile::Stream(file)] = request.files.as_deref().unwrap() else {
+            unreachable!()
+        };
+        let data = serde_json::to_value(request.data.as_ref()).unwrap();
+        assert_eq!(data["photo"], format!("attach://{}", file.id()));
+        assert_eq!(data["business_connection_id"], "bot_connection");
+    }
+
+    #[test]
+    fn test_require_business_connection_id() {
+        let bot = Bot::<Reqwest>::default();
+
+        let method = ReadBusinessMessage::new(1, 1);
+        assert!(matches!(
+            method.validate(&bot),
+            Err(SessionErrorKind::MissingField("business_connection_id"))
+        ));
+        assert!(method
+            .validate(&bot.clone().with_business_connection_id("bot_connection"))
+            .is_ok());
+        assert!(method
+            .business_connection_id("method_connection")
+            .validate(&bot)
+            .is_ok());
+
+        // Methods which don't require business connection are always valid
+        assert!(SendMessage::new(1, "test").validate(&bot).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_send_without_required_business_connection_id() {
+        let bot = Bot::<Reqwest>::default();
+
+        // The request isn't sent, so there is no network error
+        assert!(matches!(
+            bot.send(ReadBusinessMessage::new(1, 1)).await,
+            Err(SessionErrorKind::MissingField("business_connection_id"))
+        ));
+    }
+}
@@ -0,0 +1,448 @@
+//! This module contains the [`DefaultBotProperties`] struct, which represents default properties of the bot,
+//! that are applied to every outgoing method which supports them.
+//!
+//! Default properties are filled in [`TelegramMethod::build_request`] only if the method leaves the field `None`,
+//! so values set explicitly in the method always take precedence over defaults.
+//! Default `parse_mode` also isn't applied if the method has entities, because entities can be specified instead of `parse_mode`.
+//!
+//! To opt out of a default property for a single call, set the property explicitly in the method,
+//! for example, `protect_content(false)`. To send a method without default `parse_mode` while keeping other defaults,
+//! set `parse_mode` to [`NO_PARSE_MODE`]. It's cleared before sending, so `parse_mode` isn't sent at all.
+//! To opt out of all default properties for a single call, use [`Bot::without_default_properties`] method.
+//!
+//! # Examples
+//! ```rust
+//! use telers::{
+//!     client::{default::NO_PARSE_MODE, DefaultBotProperties},
+//!     enums::ParseMode,
+//!     methods::SendMessage,
+//!     Bot,
+//! };
+//!
+//! async fn call_method(bot: Bot) {
+//!     let bot = bot.with_default_properties(
+//!         DefaultBotProperties::new()
+//!             .parse_mode(ParseMode::HTML)
+//!             .protect_content(true),
+//!     );
+//!
+//!     // `parse_mode` and `protect_content` are filled by default properties
+//!     let _ = bot.send(SendMessage::new(1, "<b>Hello, world!</b>")).await;
+//!     // `parse_mode` isn't sent, but `protect_content` is filled by default properties
+//!     let _ = bot
+//!         .send(SendMessage::new(1, "<b>Hello, world!</b>").parse_mode(NO_PARSE_MODE))
+//!         .await;
+//!     // `parse_mode` and `protect_content` aren't filled, because the bot is without default properties
+//!     let _ = bot
+//!         .without_default_properties()
+//!         .send(SendMessage::new(1, "<b>Hello, world!</b>"))
+//!         .await;
+//! }
+//! ```
+//!
+//! [`TelegramMethod::build_request`]: crate::methods::TelegramMethod::build_request
+//! [`Bot::without_default_properties`]: crate::client::Bot#method.without_default_properties
+
+use crate::types::LinkPreviewOptions;
+
+use std::borrow::Cow;
+
+/// Explicit "no parse mode" value of `parse_mode` fields of the methods.
+/// Default `parse_mode` isn't applied to the fields with this value, and the value is cleared before sending
+pub const NO_PARSE_MODE: &str = "";
+
+/// Default properties of the bot, which are applied to every outgoing method which supports them.
+/// Check [module docs](crate::client::default) for more information.
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq)]
+pub struct DefaultBotProperties {
+    /// Mode for parsing entities in the text and captions. See [`formatting options`](https://core.telegram.org/bots/api#formatting-options) for more details.
+    pub parse_mode: Option<String>,
+    /// Link preview generation options for the messages
+    pub link_preview_options: Option<LinkPreviewOptions>,
+    /// Sends the messages [silently](https://telegram.org/blog/channels-2-0#silent-messages). Users will receive a notification with no sound.
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent messages from forwarding and saving
+    pub protect_content: Option<bool>,
+}
+
+impl DefaultBotProperties {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn parse_mode(self, val: impl Into<String>) -> Self {
+        Self {
+            parse_mode: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn link_preview_options(self, val: LinkPreviewOptions) -> Self {
+        Self {
+            link_preview_options: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn disable_notification(self, val: bool) -> Self {
+        Self {
+            disable_notification: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn protect_content(self, val: bool) -> Self {
+        Self {
+            protect_content: Some(val),
+            ..self
+        }
+    }
+}
+
+impl DefaultBotProperties {
+    #[must_use]
+    pub fn parse_mode_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            parse_mode: val.map(Into::into),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn link_preview_options_option(self, val: Option<LinkPreviewOptions>) -> Self {
+        Self {
+            link_preview_options: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn disable_notification_option(self, val: Option<bool>) -> Self {
+        Self {
+            disable_notification: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn protect_content_option(self, val: Option<bool>) -> Self {
+        Self {
+            protect_content: val,
+            ..self
+        }
+    }
+}
+
+impl DefaultBotProperties {
+    /// Check if no default properties are set
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.parse_mode.is_none()
+            && self.link_preview_options.is_none()
+            && self.disable_notification.is_none()
+            && self.protect_content.is_none()
+    }
+}
+
+impl DefaultBotProperties {
+    /// Fill unset fields of the method by default properties and clear [`NO_PARSE_MODE`] values.
+    /// The method is cloned only if any of its fields is changed.
+    /// # Returns
+    /// The changed copy of the method or the method itself, if nothing is changed
+    #[must_use]
+    pub fn apply<'a, T>(&self, method: &'a T) -> Cow<'a, T>
+    where
+        T: WithDefaultProperties,
+    {
+        if !self.is_changing(method.default_fields_ref()) {
+            return Cow::Borrowed(method);
+        }
+
+        let mut changed_method = method.clone();
+        self.fill(changed_method.default_fields());
+        Cow::Owned(changed_method)
+    }
+
+    /// Check if any field is changed by [`DefaultBotProperties::fill`]
+    fn is_changing(&self, fields: DefaultFieldsRef<'_>) -> bool {
+        fields.parse_mode.into_iter().any(
+            |ParseModeFieldRef {
+                 parse_mode,
+                 has_entities,
+             }| {
+                parse_mode.as_deref() == Some(NO_PARSE_MODE)
+                    || (parse_mode.is_none() && !has_entities && self.parse_mode.is_some())
+            },
+        ) || is_field_filled(fields.link_preview_options, &self.link_preview_options)
+            || is_field_filled(fields.disable_notification, &self.disable_notification)
+            || is_field_filled(fields.protect_content, &self.protect_content)
+    }
+
+    /// Fill unset fields by default properties
+    fn fill(&self, fields: DefaultFields<'_>) {
+        for ParseModeField {
+            parse_mode,
+            has_entities,
+        } in fields.parse_mode
+        {
+            if parse_mode.as_deref() == Some(NO_PARSE_MODE) {
+                *parse_mode = None;
+            } else if let (None, false, Some(default)) =
+                (&parse_mode, has_entities, &self.parse_mode)
+            {
+                *parse_mode = Some(default.clone());
+            }
+        }
+
+        fill_field(fields.link_preview_options, &self.link_preview_options);
+        fill_field(fields.disable_notification, &self.disable_notification);
+        fill_field(fields.protect_content, &self.protect_content);
+    }
+}
+
+/// Check if the field is filled by default value, if the method supports the field and the field is unset
+fn is_field_filled<T>(field: Option<&Option<T>>, default: &Option<T>) -> bool {
+    matches!((field, default), (Some(None), Some(_)))
+}
+
+/// Fill the field by default value, if the method supports the field and the field is unset
+fn fill_field<T: Clone>(field: Option<&mut Option<T>>, default: &Option<T>) {
+    if let (Some(field @ None), Some(default)) = (field, default) {
+        *field = Some(default.clone());
+    }
+}
+
+/// `parse_mode` field of the method, which is filled by default `parse_mode`
+pub struct ParseModeField<'a> {
+    pub parse_mode: &'a mut Option<String>,
+    /// Default `parse_mode` isn't applied, if entities are specified instead of `parse_mode`
+    pub has_entities: bool,
+}
+
+impl<'a> ParseModeField<'a> {
+    #[must_use]
+    pub fn new(parse_mode: &'a mut Option<String>, has_entities: bool) -> Self {
+        Self {
+            parse_mode,
+            has_entities,
+        }
+    }
+}
+
+/// Fields of the method, which are filled by default properties.
+/// `None` (or empty list for `parse_mode`) means the method doesn't support the property
+#[derive(Default)]
+pub struct DefaultFields<'a> {
+    /// `parse_mode` fields of the method, for example, `parse_mode` of the caption and of each media in the group
+    pub parse_mode: Vec<ParseModeField<'a>>,
+    pub link_preview_options: Option<&'a mut Option<LinkPreviewOptions>>,
+    pub disable_notification: Option<&'a mut Option<bool>>,
+    pub protect_content: Option<&'a mut Option<bool>>,
+}
+
+/// Read-only `parse_mode` field of the method, which is used to check if the field is filled by default `parse_mode`
+pub struct ParseModeFieldRef<'a> {
+    pub parse_mode: &'a Option<String>,
+    /// Default `parse_mode` isn't applied, if entities are specified instead of `parse_mode`
+    pub has_entities: bool,
+}
+
+impl<'a> ParseModeFieldRef<'a> {
+    #[must_use]
+    pub fn new(parse_mode: &'a Option<String>, has_entities: bool) -> Self {
+        Self {
+            parse_mode,
+            has_entities,
+        }
+    }
+}
+
+/// Read-only fields of the method, which are filled by default properties.
+/// It's the same as [`DefaultFields`], but is used to check the method before cloning it
+#[derive(Default)]
+pub struct DefaultFieldsRef<'a> {
+    pub parse_mode: Vec<ParseModeFieldRef<'a>>,
+    pub link_preview_options: Option<&'a Option<LinkPreviewOptions>>,
+    pub disable_notification: Option<&'a Option<bool>>,
+    pub protect_content: Option<&'a Option<bool>>,
+}
+
+/// Methods which support default properties of the bot
+pub trait WithDefaultProperties: Clone {
+    /// Get fields of the method, which are filled by default properties
+    #[must_use]
+    fn default_fields(&mut self) -> DefaultFields<'_>;
+
+    /// Get read-only fields of the method, which are filled by default properties.
+    /// They must be the same fields as returned by [`WithDefaultProperties::default_fields`]
+    #[must_use]
+    fn default_fields_ref(&self) -> DefaultFieldsRef<'_>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{Bot, Reqwest},
+        enums::ParseMode,
+        methods::{SendChecklist, SendMediaGroup, SendMessage, SendPhoto, TelegramMethod as _},
+        types::{
+            InputChecklist, InputChecklistTask, InputFile, InputMedia, InputMediaPhoto,
+            MessageEntity,
+        },
+    };
+
+    use bytes::Bytes;
+    use futures::stream;
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_apply() {
+        let bot = Bot::<Reqwest>::default().with_default_properties(
+            DefaultBotProperties::new()
+                .parse_mode(ParseMode::HTML)
+                .protect_content(true),
+        );
+
+        let method = SendMessage::new(1, "test");
+        let request = method.build_request(&bot);
+        assert!(matches!(request.data, Cow::Owned(_)));
+        assert_eq!(request.data.parse_mode.as_deref(), Some("HTML"));
+        assert_eq!(request.data.protect_content, Some(true));
+        assert_eq!(request.data.disable_notification, None);
+
+        // Explicitly set fields take precedence over defaults
+        let method = SendMessage::new(1, "test")
+            .parse_mode(ParseMode::MarkdownV2)
+            .protect_content(false);
+        let request = method.build_request(&bot);
+        assert_eq!(request.data.parse_mode.as_deref(), Some("MarkdownV2"));
+        assert_eq!(request.data.protect_content, Some(false));
+
+        // Default `parse_mode` isn't applied if entities are set
+        let method = SendMessage::new(1, "test").entities([MessageEntity::new_bold(0, 4)]);
+        let request = method.build_request(&bot);
+        assert_eq!(request.data.parse_mode, None);
+    }
+
+    #[test]
+    fn test_without_default_properties() {
+        let bot = Bot::<Reqwest>::default()
+            .with_default_properties(DefaultBotProperties::new().parse_mode(ParseMode::HTML));
+
+        let method = SendMessage::new(1, "test");
+        let request = method.build_request(&bot.without_default_properties());
+        assert!(matches!(request.data, Cow::Borrowed(_)));
+        assert_eq!(request.data.parse_mode, None);
+
+        // The method isn't cloned, if all fields are set explicitly
+        let method = SendMessage::new(1, "test").parse_mode(ParseMode::MarkdownV2);
+        let request = method.build_request(&bot);
+        assert!(matches!(request.data, Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_no_parse_mode() {
+        let bot = Bot::<Reqwest>::default().with_default_properties(
+            DefaultBotProperties::new()
+                .parse_mode(ParseMode::HTML)
+                .protect_content(true),
+        );
+
+        // `parse_mode` is cleared, but other defaults are still applied
+        let method = SendMessage::new(1, "test").parse_mode(NO_PARSE_MODE);
+        let request = method.build_request(&bot);
+        assert_eq!(request.data.parse_mode, None);
+        assert_eq!(request.data.protect_content, Some(true));
+
+        // `parse_mode` is cleared even if the bot is without default properties
+        let request = method.build_request(&bot.without_default_properties());
+        assert!(matches!(request.data, Cow::Owned(_)));
+        assert_eq!(request.data.parse_mode, None);
+    }
+
+    #[test]
+    fn test_apply_to_media_group() {
+        let bot = Bot::<Reqwest>::default()
+            .with_default_properties(DefaultBotProperties::new().parse_mode(ParseMode::HTML));
+
+        let method = SendMediaGroup::new(
+            1,
+            [
+                InputMediaPhoto::new(InputFile::id("file_id_1")).caption("<b>test</b>"),
+                InputMediaPhoto::new(InputFile::id("file_id_2"))
+                    .caption("test")
+                    .caption_entities([MessageEntity::new_bold(0, 4)]),
+                InputMediaPhoto::new(InputFile::id("file_id_3"))
+                    .caption("test")
+                    .parse_mode(NO_PARSE_MODE),
+            ],
+        );
+        let request = method.build_request(&bot);
+        let parse_modes = request
+            .data
+            .media
+            .iter()
+            .map(|media| match media {
+                InputMedia::Photo(inner) => inner.parse_mode.as_deref(),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(parse_modes, [Some("HTML"), None, None]);
+    }
+
+    #[test]
+    fn test_apply_to_checklist() {
+        let bot = Bot::<Reqwest>::default()
+            .with_default_properties(DefaultBotProperties::new().parse_mode(ParseMode::HTML));
+
+        let method = SendChecklist::new(
+            1,
+            InputChecklist::new(
+                "<b>test</b>",
+                [
+                    InputChecklistTask::new(1, "<b>test</b>"),
+                    InputChecklistTask::new(2, "test")
+                        .text_entities([MessageEntity::new_bold(0, 4)]),
+                ],
+            ),
+        );
+        let request = method.build_request(&bot.with_business_connection_id("connection"));
+        let checklist = &request.data.checklist;
+        assert_eq!(checklist.parse_mode.as_deref(), Some("HTML"));
+        assert_eq!(
+            checklist
+                .tasks
+                .iter()
+                .map(|task| task.parse_mode.as_deref())
+                .collect::<Vec<_>>(),
+            [Some("HTML"), None]
+        );
+    }
+
+    #[test]
+    fn test_apply_to_stream_file() {
+        let bot = Bot::<Reqwest>::default()
+            .with_default_properties(DefaultBotProperties::new().parse_mode(ParseMode::HTML));
+
+        let method = SendPhoto::new(
+            1,
+            InputFile::stream(stream::iter([Ok(Bytes::from_static(b"test"))])),
+        )
+        .caption("<b>test</b>");
+        let request = method.build_request(&bot);
+        assert!(matches!(request.data, Cow::Owned(_)));
+
+        // Multipart part of the file is named by its ID, so the data must refer to the same ID
+        let [InputFile::Stream(file)] = request.files.as_deref().unwrap() else {
+            unreachable!()
+        };
+        let data = serde_json::to_value(request.data.as_ref()).unwrap();
+        assert_eq!(data["photo"], format!("attach://{}", file.id()));
+    }
+}
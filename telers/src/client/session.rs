@@ -2,12 +2,15 @@
 //!
 //! Components are:
 //! - [`base`] module with basic types and traits for sending requests
+//! - [`middlewares`] module with request middlewares, which are called for every outgoing request
 //! - [`reqwest`] module with reqwest client implementation
 //!
 //! Check each submodule for more information.
 
 pub mod base;
+pub mod middlewares;
 pub mod reqwest;
 
 pub use self::reqwest::Reqwest;
 pub use base::{ClientResponse, Session, StatusCode};
+pub use middlewares::Middleware as RequestMiddleware;
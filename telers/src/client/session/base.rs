@@ -5,6 +5,10 @@
 //!
 //! [`Reqwest`]: crate::client::session::reqwest::Reqwest
 
+use super::middlewares::{
+    base::wrap_session_and_middlewares_to_next, Request as MiddlewareRequest,
+};
+
 use crate::{
     client::{telegram::APIServer, Bot},
     errors::{SessionErrorKind, TelegramErrorKind},
@@ -215,8 +219,12 @@ pub trait Session: Send + Sync {
     /// * `method` - Telegram method for building and sending request
     /// * `timeout` - Request timeout.
     /// If `None`, then client timeout will be used, which is [`DEFAULT_TIMEOUT`] by default.
+    /// # Notes
+    /// If the bot has request middlewares, the request is passed through them before [`Session::send_request`]
     /// # Errors
+    /// - If the method misses a required field, see [`TelegramMethod::validate`]
     /// - If the request cannot be send or decoded
+    /// - If the request data cannot be serialized for request middlewares
     /// - If the response cannot be parsed
     /// - If the response represents an telegram api error
     #[instrument(skip(self, bot, method, timeout), fields(bot_id))]
@@ -233,18 +241,33 @@ pub trait Session: Send + Sync {
     {
         Span::current().record("bot_id", bot.bot_id);
 
-        let response = self
-            .send_request(bot, method, timeout)
-            .await
-            .map_err(|err| {
-                event!(
-                    Level::ERROR,
-                    error = %err,
-                    "Cannot send request to Telegram API",
-                );
+        method.validate(bot).map_err(|err| {
+            event!(
+                Level::ERROR,
+                error = %err,
+                "Request is invalid and isn't sent to Telegram API",
+            );
 
-                err
-            })?;
+            err
+        })?;
+
+        let response = if bot.request_middlewares.is_empty() {
+            self.send_request(bot, method, timeout).await
+        } else {
+            let request =
+                MiddlewareRequest::from_method_request(method.build_request(bot), timeout)?;
+
+            wrap_session_and_middlewares_to_next(self, bot, &bot.request_middlewares)(request).await
+        }
+        .map_err(|err| {
+            event!(
+                Level::ERROR,
+                error = %err,
+                "Cannot send request to Telegram API",
+            );
+
+            err
+        })?;
 
         event!(
             Level::TRACE,
@@ -0,0 +1,27 @@
+//! This module contains request middlewares.
+//!
+//! Request middlewares are called for every outgoing request to the Telegram Bot API, which is sent by [`Bot::send`].
+//! These middlewares have access to the middlewares/session-chain and can inspect, modify or short-circuit
+//! the [`request`] (method name, serialized data and files) and the [`response`].
+//!
+//! Use request middlewares in some cases:
+//! - If you need to log or audit outgoing requests
+//! - If you need to set default values of the request data
+//! - If you need to block some requests, for example, sending messages to some chats in staging
+//!
+//! Request middlewares are registered in the [`Bot`] with [`Bot::with_request_middleware`] method
+//! and work with any [`Session`] implementation, because the last step of the chain calls [`Session::send_request`].
+//!
+//! [`Bot`]: crate::client::Bot
+//! [`Bot::send`]: crate::client::Bot#method.send
+//! [`Bot::with_request_middleware`]: crate::client::Bot#method.with_request_middleware
+//! [`Session`]: crate::client::Session
+//! [`Session::send_request`]: crate::client::Session#tymethod.send_request
+//! [`request`]: Request
+//! [`response`]: crate::client::session::ClientResponse
+
+pub mod base;
+pub mod logging;
+
+pub use base::{Middleware, Next, Request};
+pub use logging::Logging;
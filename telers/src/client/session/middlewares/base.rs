@@ -0,0 +1,245 @@
+use crate::{
+    client::{session::base::ClientResponse, Bot, Session},
+    methods::{Request as MethodRequest, TelegramMethod},
+    types::InputFile,
+};
+
+use async_trait::async_trait;
+use serde::Serialize;
+use serde_json::Value;
+use std::{future::Future, pin::Pin, sync::Arc};
+
+/// Request to Telegram Bot API, which is passed through the request middlewares chain
+#[derive(Debug, Clone)]
+pub struct Request<'a> {
+    /// Telegram API method name
+    pub method_name: &'a str,
+    /// Serialized Telegram API method data
+    pub data: Value,
+    /// Files to send
+    pub files: Option<Box<[&'a InputFile<'a>]>>,
+    /// Request timeout.
+    /// If `None`, then client timeout will be used.
+    pub timeout: Option<f32>,
+}
+
+impl<'a> Request<'a> {
+    #[must_use]
+    pub fn new(
+        method_name: &'a str,
+        data: Value,
+        files: Option<Box<[&'a InputFile<'a>]>>,
+        timeout: Option<f32>,
+    ) -> Self {
+        Self {
+            method_name,
+            data,
+            files,
+            timeout,
+        }
+    }
+
+    /// Creates a new request from the request of the method.
+    /// Data and files are taken from the same request of the method, so the data refers to the files by their IDs.
+    /// # Errors
+    /// If the data of the method cannot be serialized
+    pub fn from_method_request<T>(
+        request: MethodRequest<'a, T>,
+        timeout: Option<f32>,
+    ) -> Result<Self, serde_json::Error>
+    where
+        T: Serialize + Clone,
+    {
+        Ok(Self::new(
+            request.method_name,
+            serde_json::to_value(request.data.as_ref())?,
+            request.files,
+            timeout,
+        ))
+    }
+}
+
+impl TelegramMethod for Request<'_> {
+    type Method = Value;
+    type Return = Value;
+
+    fn build_request<Client>(&self, _bot: &Bot<Client>) -> MethodRequest<'_, Self::Method> {
+        MethodRequest::new(self.method_name, &self.data, self.files.clone())
+    }
+}
+
+/// The middleware chain and the session at the end
+pub type Next<'a> = Box<
+    dyn FnOnce(
+            Request<'a>,
+        )
+            -> Pin<Box<dyn Future<Output = Result<ClientResponse, anyhow::Error>> + Send + 'a>>
+        + Send
+        + 'a,
+>;
+
+/// Request middlewares called for every outgoing request to the Telegram Bot API.
+///
+/// Middleware can:
+/// - Inspect and modify [`Request`] before passing it to the next middleware or session
+/// - Inspect and modify [`ClientResponse`] returned by the next middleware or session
+/// - Short-circuit the chain by returning [`ClientResponse`] or error without calling the next middleware
+///
+/// Implement this trait for your own middlewares
+#[async_trait]
+pub trait Middleware: Send + Sync {
+    /// Execute middleware
+    /// # Arguments
+    /// * `request` - Request to Telegram Bot API
+    /// * `next` - Call next middleware or session, if middlewares are empty or already called
+    /// # Returns
+    /// [`ClientResponse`] from session or middleware
+    /// # Errors
+    /// If any request middleware returns an error
+    /// If the request cannot be send or decoded
+    async fn call<'a>(
+        &self,
+        request: Request<'a>,
+        next: Next<'a>,
+    ) -> Result<ClientResponse, anyhow::Error>;
+}
+
+#[async_trait]
+impl<T: ?Sized> Middleware for Arc<T>
+where
+    T: Middleware,
+{
+    async fn call<'a>(
+        &self,
+        request: Request<'a>,
+        next: Next<'a>,
+    ) -> Result<ClientResponse, anyhow::Error> {
+        T::call(self, request, next).await
+    }
+}
+
+/// Wrap session and middlewares to [`Next`] function
+#[must_use]
+pub fn wrap_session_and_middlewares_to_next<'a, S, Client>(
+    session: &'a S,
+    bot: &'a Bot<Client>,
+    middlewares: &'a [Arc<dyn Middleware>],
+) -> Next<'a>
+where
+    S: Session + ?Sized,
+    Client: Session,
+{
+    Box::new(move |request: Request<'a>| {
+        Box::pin(async move {
+            let Some((middleware, middlewares)) = middlewares.split_first() else {
+                return session.send_request(bot, &request, request.timeout).await;
+            };
+
+            middleware
+                .call(
+                    request,
+                    wrap_session_and_middlewares_to_next(session, bot, middlewares),
+                )
+                .await
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{DefaultBotProperties, Reqwest},
+        enums::ParseMode,
+        errors::{SessionErrorKind, TelegramErrorKind},
+        methods::{SendMessage, SendPhoto},
+    };
+
+    use bytes::Bytes;
+    use futures::stream;
+    use serde_json::json;
+
+    struct Blocker;
+
+    #[async_trait]
+    impl Middleware for Blocker {
+        async fn call<'a>(
+            &self,
+            request: Request<'a>,
+            next: Next<'a>,
+        ) -> Result<ClientResponse, anyhow::Error> {
+            if request.data["chat_id"] == 1 {
+                return Ok(ClientResponse::new(
+                    403,
+                    r#"{"ok":false,"error_code":403,"description":"Blocked"}"#,
+                ));
+            }
+            next(request).await
+        }
+    }
+
+    /// Checks that the data refers to the files of the request by their IDs and blocks the request
+    struct FilesChecker;
+
+    #[async_trait]
+    impl Middleware for FilesChecker {
+        async fn call<'a>(
+            &self,
+            request: Request<'a>,
+            _next: Next<'a>,
+        ) -> Result<ClientResponse, anyhow::Error> {
+            let [InputFile::Stream(file)] = request.files.as_deref().unwrap() else {
+                unreachable!()
+            };
+            assert_eq!(request.data["photo"], format!("attach://{}", file.id()));
+            assert_eq!(request.data["parse_mode"], "HTML");
+
+            Ok(ClientResponse::new(
+                403,
+                r#"{"ok":false,"error_code":403,"description":"Checked"}"#,
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_short_circuit() {
+        let bot = Bot::<Reqwest>::default().with_request_middleware(Blocker);
+
+        match bot.send(SendMessage::new(1, "test")).await {
+            Err(SessionErrorKind::Telegram(TelegramErrorKind::Forbidden { message })) => {
+                assert_eq!(message.as_ref(), "Blocked");
+            }
+            _ => panic!("Unexpected result"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_files_of_changed_method() {
+        let bot = Bot::<Reqwest>::default()
+            .with_default_properties(DefaultBotProperties::new().parse_mode(ParseMode::HTML))
+            .with_request_middleware(FilesChecker);
+
+        let method = SendPhoto::new(
+            1,
+            InputFile::stream(stream::iter([Ok(Bytes::from_static(b"test"))])),
+        )
+        .caption("<b>test</b>");
+        match bot.send(method).await {
+            Err(SessionErrorKind::Telegram(TelegramErrorKind::Forbidden { message })) => {
+                assert_eq!(message.as_ref(), "Checked");
+            }
+            _ => panic!("Unexpected result"),
+        }
+    }
+
+    #[test]
+    fn test_build_request() {
+        let bot = Bot::<Reqwest>::default();
+        let request = Request::new("sendMessage", json!({"chat_id": 1}), None, None);
+
+        let method_request = request.build_request(&bot);
+        assert_eq!(method_request.method_name, "sendMessage");
+        assert_eq!(method_request.data.as_ref(), &json!({"chat_id": 1}));
+        assert!(method_request.files.is_none());
+    }
+}
@@ -0,0 +1,62 @@
+use super::base::{Middleware, Next, Request};
+
+use crate::client::session::base::ClientResponse;
+
+use async_trait::async_trait;
+use std::{
+    fmt::{self, Display, Formatter},
+    time::Instant,
+};
+use tracing::{event, instrument, Level};
+
+/// Request middleware, which logs method name, status code and execution time of every request
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Logging;
+
+impl Logging {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Display for Logging {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Logging")
+    }
+}
+
+#[async_trait]
+impl Middleware for Logging {
+    #[instrument(skip(self, request, next), fields(method_name = request.method_name))]
+    async fn call<'a>(
+        &self,
+        request: Request<'a>,
+        next: Next<'a>,
+    ) -> Result<ClientResponse, anyhow::Error> {
+        event!(Level::DEBUG, data = %request.data, "Send request");
+
+        let now = Instant::now();
+        let result = next(request).await;
+        let elapsed = now.elapsed();
+
+        match result {
+            Ok(ref response) => {
+                event!(
+                    Level::DEBUG,
+                    status_code = response.status_code.as_u16(),
+                    "Request finished. Execution time: {elapsed:.2?}",
+                );
+            }
+            Err(ref err) => {
+                event!(
+                    Level::ERROR,
+                    error = %err,
+                    "Request failed. Execution time: {elapsed:.2?}",
+                );
+            }
+        }
+
+        result
+    }
+}
@@ -21,6 +21,8 @@ use crate::{
 };
 
 use async_trait::async_trait;
+#[cfg(feature = "opentelemetry")]
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use reqwest::{
     multipart::{Form, Part},
     Body, Client, ClientBuilder,
@@ -54,18 +56,19 @@ impl Reqwest {
 
     /// Builds a form data from the given data and files.
     /// # Notes
-    /// This method uses [`MultipartSerializer`] to serialize the data in custom format that Telegram Bot API accepts.
+    /// This method uses custom multipart serializer to serialize the data in format that Telegram Bot API accepts.
+    /// It's used only for requests with files to upload, other requests are sent with `application/json` body.
     /// # Warnings
     /// Be aware that build [`InputFile::Stream`] will be taken and cannot be used again without set stream again.
     /// Check its documentation for more information.
     /// # Errors
-    /// Returns a [`SerializerError`] if the form cannot be built.
+    /// Returns an error if the form cannot be built.
     #[instrument(skip(self, data))]
-    async fn build_form_data<'a, Data: ?Sized>(
+    pub fn build_form_data<'a, Data: ?Sized>(
         &self,
         data: &Data,
         files: Option<&[&InputFile<'a>]>,
-    ) -> Result<Form, SerializerError>
+    ) -> Result<Form, anyhow::Error>
     where
         Data: Serialize,
     {
@@ -109,7 +112,8 @@ impl Reqwest {
                         return Err(SerializerError::Custom(Cow::Owned(format!(
                             "File stream with index `{index}` already taken. \
                             Read `StreamFile::take_stream` documentation for more information."
-                        ))));
+                        )))
+                        .into());
                     };
                     let id = file.id().to_string();
                     let file_name = file.file_name();
@@ -161,10 +165,15 @@ impl Session for Reqwest {
     /// # Warning
     /// If the timeout is not set, the default timeout will not be used.
     ///
-    /// Uses always `POST` method to send a request and `multipart/form-data` content type even if files are not provided.
+    /// Uses always `POST` method to send a request.
+    /// Requests with files to upload are sent with `multipart/form-data` content type,
+    /// other requests are sent with `application/json` content type.
     /// # Errors
     /// Returns an error if the request cannot be sent or the response cannot be received.
-    #[instrument(skip(self, bot, method, timeout), fields(files, method_name, timeout))]
+    #[instrument(
+        skip(self, bot, method, timeout),
+        fields(files, method_name, timeout, otel.name, otel.kind = "client")
+    )]
     async fn send_request<Client, T>(
         &self,
         bot: &Bot<Client>,
@@ -180,36 +189,42 @@ impl Session for Reqwest {
 
         Span::current()
             .record("files", field::debug(&request.files))
-            .record("method_name", request.method_name);
+            .record("method_name", request.method_name)
+            .record("otel.name", request.method_name);
 
-        let form = self
-            .build_form_data(request.data, request.files.as_deref())
-            .await
-            .map_err(|err| {
-                event!(
-                    Level::ERROR,
-                    error = %err,
-                    "Cannot build a form",
-                );
+        let url = self.api.api_url(&bot.token, request.method_name);
 
-                err
-            })?;
+        let mut request_builder = match request.files.as_deref() {
+            Some(files) if !files.is_empty() => {
+                let form = self
+                    .build_form_data(request.data.as_ref(), Some(files))
+                    .map_err(|err| {
+                        event!(
+                            Level::ERROR,
+                            error = %err,
+                            "Cannot build a form",
+                        );
 
-        let url = self.api.api_url(&bot.token, request.method_name);
+                        err
+                    })?;
+
+                self.client.post(url.as_ref()).multipart(form)
+            }
+            _ => self.client.post(url.as_ref()).json(request.data.as_ref()),
+        };
 
-        let response = if let Some(timeout) = timeout {
+        if let Some(timeout) = timeout {
             Span::current().record("timeout", timeout);
 
-            self.client
-                .post(url.as_ref())
-                .multipart(form)
-                .timeout(Duration::from_secs_f32(timeout))
-        } else {
-            self.client.post(url.as_ref()).multipart(form)
+            request_builder = request_builder.timeout(Duration::from_secs_f32(timeout));
+        }
+
+        #[cfg(feature = "opentelemetry")]
+        {
+            request_builder = request_builder.headers(trace_context_headers());
         }
-        .send()
-        .await
-        .map_err(|err| {
+
+        let response = request_builder.send().await.map_err(|err| {
             event!(
                 Level::ERROR,
                 error = %err,
@@ -235,3 +250,143 @@ impl Session for Reqwest {
         Ok(ClientResponse::new(status_code, content))
     }
 }
+
+/// Builds headers with trace context of the current span to propagate it to the server.
+/// # Notes
+/// Headers are built by the global text map propagator, so set it with
+/// [`opentelemetry::global::set_text_map_propagator`] to enable propagation.
+/// By default, the propagator is no-op and headers are empty.
+#[cfg(feature = "opentelemetry")]
+fn trace_context_headers() -> HeaderMap {
+    use opentelemetry::{global, propagation::Injector};
+    use tracing_opentelemetry::OpenTelemetrySpanExt as _;
+
+    struct HeaderInjector<'a>(&'a mut HeaderMap);
+
+    impl Injector for HeaderInjector<'_> {
+        fn set(&mut self, key: &str, value: String) {
+            let (Ok(name), Ok(value)) = (
+                HeaderName::from_bytes(key.as_bytes()),
+                HeaderValue::from_str(&value),
+            ) else {
+                return;
+            };
+
+            self.0.insert(name, value);
+        }
+    }
+
+    let mut headers = HeaderMap::new();
+
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(
+            &Span::current().context(),
+            &mut HeaderInjector(&mut headers),
+        );
+    });
+
+    headers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::telegram::{APIServer, BareFilesPathWrapper},
+        methods::{AnswerCallbackQuery, SendPhoto},
+    };
+
+    use tokio::{
+        io::{AsyncReadExt as _, AsyncWriteExt as _},
+        net::TcpListener,
+    };
+
+    /// Accepts the connections and returns `Content-Type` header of each request
+    async fn accept_requests(listener: TcpListener, count: usize) -> Vec<String> {
+        let mut content_types = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            let (mut stream, _) = listener.accept().await.unwrap();
+
+            let mut request = vec![];
+            let mut buf = [0; 1024];
+            let headers_end = loop {
+                let len = stream.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..len]);
+
+                if let Some(index) = request.windows(4).position(|window| window == b"\r\n\r\n") {
+                    break index + 4;
+                }
+            };
+
+            let headers = String::from_utf8_lossy(&request[..headers_end]).to_lowercase();
+            let header = |name: &str| {
+                headers.lines().find_map(|line| {
+                    line.strip_prefix(name)
+                        .map(|value| value.trim_start_matches(':').trim().to_owned())
+                })
+            };
+            let content_length: usize = header("content-length").unwrap().parse().unwrap();
+            content_types.push(header("content-type").unwrap());
+
+            while request.len() - headers_end < content_length {
+                let len = stream.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..len]);
+            }
+
+            let body = r#"{"ok":true,"result":true}"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len(),
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+        }
+
+        content_types
+    }
+
+    #[tokio::test]
+    async fn test_send_request_content_type() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(accept_requests(listener, 2));
+
+        let session = Reqwest::default().with_api_server(Cow::Owned(APIServer::new(
+            &format!("http://{address}/bot{{token}}/{{method_name}}"),
+            &format!("http://{address}/file/bot{{token}}/{{path}}"),
+            false,
+            BareFilesPathWrapper,
+        )));
+        let bot = Bot::with_client("1234567890:AAAA", session);
+
+        assert!(bot.send(AnswerCallbackQuery::new("1")).await.unwrap());
+        // Response isn't a message, we check only the request content type
+        let _ = bot
+            .send(SendPhoto::new(1, InputFile::buffered(b"photo".to_vec())))
+            .await;
+
+        let content_types = server.await.unwrap();
+        assert_eq!(content_types[0], "application/json");
+        assert!(content_types[1].starts_with("multipart/form-data"));
+    }
+
+    #[test]
+    fn test_build_form_data_with_null() {
+        let session = Reqwest::default();
+        let file = InputFile::buffered(b"photo".to_vec());
+        // For example, a request middleware sets the field to null
+        let data = serde_json::json!({
+            "chat_id": 1,
+            "caption": null,
+            "photo": file.str_to_file(),
+        });
+
+        assert!(session.build_form_data(&data, Some(&[&file])).is_ok());
+    }
+
+    #[cfg(feature = "opentelemetry")]
+    #[test]
+    fn test_trace_context_headers_without_propagator() {
+        assert!(trace_context_headers().is_empty());
+    }
+}
@@ -23,6 +23,9 @@
 //! For example, specify `message`, `edited_channel_post`, `callback_query` to only receive updates of these types.
 //! See [`UpdateType`] for a complete list of available update types.
 //! By default, all update types except [`ChatMember`] are enabled.
+//! * `Sync menu commands`:
+//!   Set the bot menu from descriptions of commands registered in filters of the router tree at startup
+//!   (by [`Dispatcher::run_polling`] or [`Dispatcher::emit_startup`]), see [`Builder::sync_menu_commands`] method.
 //!
 //! Dispatcher supports startup and shutdown events.
 //! You can register handlers for these observers (startup and shutdown) in the main router and handle them (see [`router module`]).
@@ -45,6 +48,7 @@
 //! [`Dispatcher::new`]: Dispatcher#method.new
 //! [`Builder::polling_timeout`]: Builder#method.polling_timeout
 //! [`Builder::backoff`]: Builder#method.backoff
+//! [`Builder::sync_menu_commands`]: Builder#method.sync_menu_commands
 //! [`Dispatcher::run_polling`]: Service#method.run_polling
 //! [`Dispatcher::emit_startup`]: Service#method.emit_startup
 //! [`Dispatcher::emit_shutdown`]: Service#method.emit_shutdown
@@ -58,13 +62,14 @@ use crate::{
     client::{Bot, Session},
     context::Context,
     enums::UpdateType,
-    errors::EventErrorKind,
+    errors::{EventErrorKind, SessionErrorKind},
     event::{
         service::{ServiceProvider, ToServiceProvider},
         simple::HandlerResult as SimpleHandlerResult,
     },
-    methods::GetUpdates,
-    types::Update,
+    filters::MenuCommand,
+    methods::{DeleteMyCommands, GetMyCommands, GetUpdates, SetMyCommands},
+    types::{BotCommand, BotCommandScope, Update},
 };
 
 use backoff::{backoff::Backoff, exponential::ExponentialBackoff, SystemClock};
@@ -97,6 +102,7 @@ pub struct Dispatcher<Client, Propagator, BackoffType = ExponentialBackoff<Syste
     polling_timeout: Option<i64>,
     backoff: BackoffType,
     allowed_updates: Box<[UpdateType]>,
+    sync_menu_commands: bool,
 }
 
 impl<Client, Propagator, BackoffType> Dispatcher<Client, Propagator, BackoffType> {
@@ -140,6 +146,7 @@ impl<Client, Propagator, BackoffType> Dispatcher<Client, Propagator, BackoffType
             polling_timeout,
             backoff,
             allowed_updates: allowed_updates.into_iter().collect(),
+            sync_menu_commands: false,
         }
     }
 }
@@ -170,6 +177,7 @@ pub struct Builder<Client, Propagator, BackoffType = ExponentialBackoff<SystemCl
     polling_timeout: Option<i64>,
     backoff: BackoffType,
     allowed_updates: Vec<UpdateType>,
+    sync_menu_commands: bool,
 }
 
 impl<Client, Propagator> Default for Builder<Client, Propagator>
@@ -185,6 +193,7 @@ where
             polling_timeout: Some(DEFAULT_POLLING_TIMEOUT),
             backoff: ExponentialBackoff::default(),
             allowed_updates: vec![],
+            sync_menu_commands: false,
         }
     }
 }
@@ -201,6 +210,7 @@ where
             polling_timeout: Some(DEFAULT_POLLING_TIMEOUT),
             backoff,
             allowed_updates: vec![],
+            sync_menu_commands: false,
         }
     }
 }
@@ -311,6 +321,25 @@ impl<Client, Propagator, BackoffType> Builder<Client, Propagator, BackoffType> {
         }
     }
 
+    /// Set the bot menu from descriptions of commands registered in filters of the router tree
+    /// (see [`CommandBuilder::description`]) when startup events are emitted, so when polling is started too.
+    /// Commands are set by [`SetMyCommands`] or deleted by [`DeleteMyCommands`] for each scope and language
+    /// only if the current commands received by [`GetMyCommands`] differ.
+    /// Commands of the default scope for all languages are deleted if no one is registered,
+    /// but commands of other scopes and languages, which are no longer registered, aren't deleted,
+    /// see [`Service::set_menu_commands`] for more information.
+    /// # Default
+    /// `false`
+    ///
+    /// [`CommandBuilder::description`]: crate::filters::CommandBuilder::description
+    #[must_use]
+    pub fn sync_menu_commands(self, val: bool) -> Self {
+        Self {
+            sync_menu_commands: val,
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn build(self) -> Dispatcher<Client, Propagator, BackoffType> {
         Dispatcher {
@@ -319,6 +348,7 @@ impl<Client, Propagator, BackoffType> Builder<Client, Propagator, BackoffType> {
             polling_timeout: self.polling_timeout,
             backoff: self.backoff,
             allowed_updates: self.allowed_updates.into_iter().collect(),
+            sync_menu_commands: self.sync_menu_commands,
         }
     }
 }
@@ -346,6 +376,7 @@ where
             polling_timeout: self.polling_timeout,
             backoff: self.backoff,
             allowed_updates: self.allowed_updates,
+            sync_menu_commands: self.sync_menu_commands,
         }))
     }
 }
@@ -356,6 +387,7 @@ pub struct Service<Client, PropagatorService, BackoffType> {
     polling_timeout: Option<i64>,
     backoff: BackoffType,
     allowed_updates: Box<[UpdateType]>,
+    sync_menu_commands: bool,
 }
 
 impl<Client, PropagatorService, BackoffType> ServiceProvider
@@ -366,7 +398,6 @@ impl<Client, PropagatorService, BackoffType> ServiceProvider
 impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService, BackoffType> {
     /// Main entry point for incoming updates.
     /// This method will propagate update to the main router.
-    #[instrument(skip(self, bot, update))]
     pub async fn feed_update(
         self: Arc<Self>,
         bot: Arc<Bot<Client>>,
@@ -382,9 +413,14 @@ impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService,
 
     /// Main entry point for incoming updates with user context.
     /// This method will propagate update to the main router.
+    /// # Notes
+    /// This method creates the `update` span, which is the root span of the update lifecycle.
+    /// Spans of routers, middlewares, filters, handlers and requests sent by [`Bot::send`] inside are its children,
+    /// so you can trace processing of the update end to end (see `opentelemetry` feature).
     #[instrument(
+        name = "update",
         skip(self, bot, update, context),
-        fields(bot_id, update_id, update_type)
+        fields(bot_id, update_id, update_type, chat_id, user_id, otel.kind = "consumer")
     )]
     pub async fn feed_update_with_context(
         self: Arc<Self>,
@@ -401,7 +437,9 @@ impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService,
         Span::current()
             .record("bot_id", bot.bot_id)
             .record("update_id", update.id)
-            .record("update_type", field::debug(&update_type));
+            .record("update_type", field::debug(&update_type))
+            .record("chat_id", update.chat_id())
+            .record("user_id", update.from_id());
 
         self.main_router
             .propagate_event(update_type, Request::new(bot, update, context))
@@ -617,7 +655,7 @@ impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService,
     {
         event!(Level::TRACE, "Start emit startup observers");
 
-        if let Err(err) = self.main_router.emit_startup().await {
+        if let Err(err) = self.emit_startup().await {
             event!(Level::ERROR, error = %err, "Error while emit startup");
 
             return Err(err.into());
@@ -675,19 +713,101 @@ impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService,
         }
     }
 
-    /// Emit startup events.
-    /// Use this method if you want to emit startup events manually
+    /// Set the bot menu from descriptions of commands registered in filters of the main router handlers.
+    /// Commands are set or deleted for each scope and language only if the current commands differ.
+    /// # Notes
+    /// This method is called automatically in `emit_startup` method (so in `run_polling` method too)
+    /// if [`Builder::sync_menu_commands`] is enabled.
+    ///
+    /// Telegram API doesn't allow to get scopes and languages with set commands,
+    /// so commands of scopes and languages, which are no longer registered, aren't deleted,
+    /// except the default scope for all languages.
+    /// Delete them manually by [`DeleteMyCommands`], if you remove all commands of a scope or a language
+    /// # Errors
+    /// If error occurred in the process of sending request to the Telegram API or parsing response
+    #[instrument(skip(self, bot), fields(bot_id = bot.bot_id))]
+    pub async fn set_menu_commands(&self, bot: &Bot<Client>) -> Result<(), SessionErrorKind>
+    where
+        Client: Session,
+        PropagatorService: PropagateEvent<Client>,
+    {
+        for (scope, language_code, commands) in
+            group_menu_commands(self.main_router.menu_commands())
+        {
+            let current_commands = bot
+                .send(
+                    GetMyCommands::new()
+                        .scope(scope.clone())
+                        .language_code_option(language_code.clone()),
+                )
+                .await?;
+
+            if current_commands == commands {
+                event!(
+                    Level::TRACE,
+                    ?scope,
+                    ?language_code,
+                    "Menu commands are up to date"
+                );
+
+                continue;
+            }
+
+            if commands.is_empty() {
+                bot.send(
+                    DeleteMyCommands::new()
+                        .scope(scope.clone())
+                        .language_code_option(language_code.clone()),
+                )
+                .await?;
+
+                event!(
+                    Level::INFO,
+                    ?scope,
+                    ?language_code,
+                    "Menu commands are deleted"
+                );
+            } else {
+                bot.send(
+                    SetMyCommands::new(commands)
+                        .scope(scope.clone())
+                        .language_code_option(language_code.clone()),
+                )
+                .await?;
+
+                event!(Level::INFO, ?scope, ?language_code, "Menu commands are set");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Emit startup events and set the bot menu, if [`Builder::sync_menu_commands`] is enabled.
+    /// Use this method if you want to emit startup events manually, for example, with webhooks
     /// # Notes
     /// This method is called automatically in `run_polling` method,
-    /// but not in `run_polling_without_startup_and_shutdown` method
+    /// but not in `run_polling_without_startup_and_shutdown` method.
+    ///
+    /// Errors of setting the bot menu are logged and don't stop the startup, see [`Service::set_menu_commands`]
     /// # Errors
     /// If any startup observer returns error
     #[instrument(skip(self))]
     pub async fn emit_startup(&self) -> SimpleHandlerResult
     where
+        Client: Session,
         PropagatorService: PropagateEvent<Client>,
     {
-        self.main_router.emit_startup().await
+        self.main_router.emit_startup().await?;
+
+        if self.sync_menu_commands {
+            for bot in &*self.bots {
+                if let Err(err) = self.set_menu_commands(bot).await {
+                    event!(Level::ERROR, error = %err, bot = %bot, "Error while set menu commands");
+                }
+            }
+        }
+
+        Ok(())
     }
 
     /// Emit shutdown events.
@@ -706,6 +826,67 @@ impl<Client, PropagatorService, BackoffType> Service<Client, PropagatorService,
     }
 }
 
+/// Commands of the bot menu for the scope and the language
+type MenuCommandsGroup = (BotCommandScope, Option<Box<str>>, Vec<BotCommand>);
+
+/// Check that the command name is allowed by Telegram API:
+/// 1-32 characters, only lowercase English letters, digits and underscores
+fn is_valid_command_name(command: &str) -> bool {
+    (1..=32).contains(&command.len())
+        && command
+            .chars()
+            .all(|char| matches!(char, 'a'..='z' | '0'..='9' | '_'))
+}
+
+/// Group commands of the bot menu by scope and language in order of registration.
+/// The first command with the same name in the group is used.
+/// Commands with names which aren't allowed by Telegram API are skipped, so they don't fail the whole group.
+/// The group of the default scope for all languages is always present to delete outdated commands.
+fn group_menu_commands(
+    menu_commands: impl IntoIterator<Item = MenuCommand>,
+) -> Vec<MenuCommandsGroup> {
+    let mut groups: Vec<MenuCommandsGroup> = vec![(BotCommandScope::default(), None, vec![])];
+
+    for MenuCommand {
+        command,
+        scope,
+        language_code,
+    } in menu_commands
+    {
+        if !is_valid_command_name(&command.command) {
+            event!(
+                Level::WARN,
+                command = command.command,
+                "Command name isn't allowed in the bot menu, so the command is skipped",
+            );
+
+            continue;
+        }
+
+        let commands = match groups
+            .iter_mut()
+            .find(|(group_scope, group_language_code, _)| {
+                *group_scope == scope && *group_language_code == language_code
+            }) {
+            Some((_, _, commands)) => commands,
+            None => {
+                groups.push((scope, language_code, vec![]));
+                // `unwrap` is safe here, because we just added element to the vector
+                &mut groups.last_mut().unwrap().2
+            }
+        };
+
+        if commands
+            .iter()
+            .all(|registered| registered.command != command.command)
+        {
+            commands.push(command);
+        }
+    }
+
+    groups
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -779,5 +960,109 @@ mod tests {
         assert_eq!(dispatcher.bots.len(), 2);
         assert_eq!(dispatcher.polling_timeout, Some(123));
         assert_eq!(dispatcher.allowed_updates.len(), 3);
+        assert!(!dispatcher.sync_menu_commands);
+    }
+
+    #[test]
+    fn test_menu_commands() {
+        use crate::filters::Command;
+
+        let mut router = Router::<Reqwest>::new("main");
+        router
+            .message
+            .register(|| async { Ok(EventReturn::Finish) })
+            .filter(
+                Command::builder()
+                    .command("start")
+                    .description("Start the bot")
+                    .description_with_language("uk", "Запустити бота")
+                    .build(),
+            );
+
+        let mut sub_router = Router::new("sub");
+        sub_router
+            .message
+            .register(|| async { Ok(EventReturn::Finish) })
+            .filter(
+                Command::builder()
+                    .commands(["ban", "start"])
+                    .description("Ban the user")
+                    .scope(BotCommandScope::all_chat_administrators())
+                    .build(),
+            );
+        sub_router
+            .message
+            .register(|| async { Ok(EventReturn::Finish) })
+            .filter(
+                Command::builder()
+                    .command("start")
+                    .description("Duplicate")
+                    .build(),
+            );
+        router.include(sub_router);
+
+        let dispatcher = Dispatcher::builder()
+            .main_router(router)
+            .sync_menu_commands(true)
+            .build()
+            .to_service_provider_default()
+            .unwrap();
+
+        assert!(dispatcher.sync_menu_commands);
+        assert_eq!(
+            group_menu_commands(dispatcher.main_router.menu_commands()),
+            [
+                (
+                    BotCommandScope::default(),
+                    None,
+                    vec![BotCommand::new("start", "Start the bot")]
+                ),
+                (
+                    BotCommandScope::default(),
+                    Some("uk".into()),
+                    vec![BotCommand::new("start", "Запустити бота")]
+                ),
+                (
+                    BotCommandScope::all_chat_administrators(),
+                    None,
+                    vec![
+                        BotCommand::new("ban", "Ban the user"),
+                        BotCommand::new("start", "Ban the user")
+                    ]
+                ),
+            ]
+        );
+
+        // The group of the default scope is always present to delete outdated commands
+        assert_eq!(
+            group_menu_commands([]),
+            [(BotCommandScope::default(), None, vec![])]
+        );
+
+        // Commands with names which aren't allowed by Telegram API are skipped
+        assert_eq!(
+            group_menu_commands([
+                MenuCommand::new(
+                    BotCommand::new("Start", "Start the bot"),
+                    BotCommandScope::default(),
+                    None::<Box<str>>,
+                ),
+                MenuCommand::new(
+                    BotCommand::new("a".repeat(33), "Too long"),
+                    BotCommandScope::default(),
+                    None::<Box<str>>,
+                ),
+                MenuCommand::new(
+                    BotCommand::new("help_2", "Help"),
+                    BotCommandScope::default(),
+                    None::<Box<str>>,
+                ),
+            ]),
+            [(
+                BotCommandScope::default(),
+                None,
+                vec![BotCommand::new("help_2", "Help")]
+            )]
+        );
     }
 }
@@ -17,6 +17,8 @@ pub enum ContentType {
     Document,
     #[strum(serialize = "photo")]
     Photo,
+    #[strum(serialize = "paid_media")]
+    PaidMedia,
     #[strum(serialize = "sticker")]
     Sticker,
     #[strum(serialize = "story")]
@@ -35,6 +37,8 @@ pub enum ContentType {
     Game,
     #[strum(serialize = "poll")]
     Poll,
+    #[strum(serialize = "checklist")]
+    Checklist,
     #[strum(serialize = "venue")]
     Venue,
     #[strum(serialize = "location")]
@@ -67,6 +71,8 @@ pub enum ContentType {
     Invoice,
     #[strum(serialize = "successful_payment")]
     SuccessfulPayment,
+    #[strum(serialize = "refunded_payment")]
+    RefundedPayment,
     #[strum(serialize = "users_shared")]
     UsersShared,
     #[strum(serialize = "chat_shared")]
@@ -81,6 +87,8 @@ pub enum ContentType {
     ProximityAlertTriggered,
     #[strum(serialize = "chat_boost_added")]
     ChatBoostAdded,
+    #[strum(serialize = "chat_background_set")]
+    ChatBackgroundSet,
     #[strum(serialize = "forum_topic_created")]
     ForumTopicCreated,
     #[strum(serialize = "forum_topic_edited")]
@@ -101,6 +109,20 @@ pub enum ContentType {
     GiveawayWinners,
     #[strum(serialize = "giveaway_completed")]
     GiveawayCompleted,
+    #[strum(serialize = "checklist_tasks_done")]
+    ChecklistTasksDone,
+    #[strum(serialize = "checklist_tasks_added")]
+    ChecklistTasksAdded,
+    #[strum(serialize = "suggested_post_approved")]
+    SuggestedPostApproved,
+    #[strum(serialize = "suggested_post_approval_failed")]
+    SuggestedPostApprovalFailed,
+    #[strum(serialize = "suggested_post_declined")]
+    SuggestedPostDeclined,
+    #[strum(serialize = "suggested_post_paid")]
+    SuggestedPostPaid,
+    #[strum(serialize = "suggested_post_refunded")]
+    SuggestedPostRefunded,
     #[strum(serialize = "video_chat_scheduled")]
     VideoChatScheduled,
     #[strum(serialize = "video_chat_started")]
@@ -115,13 +137,14 @@ pub enum ContentType {
 
 impl ContentType {
     #[must_use]
-    pub const fn all() -> [ContentType; 52] {
+    pub const fn all() -> [ContentType; 63] {
         [
             ContentType::Text,
             ContentType::Animation,
             ContentType::Audio,
             ContentType::Document,
             ContentType::Photo,
+            ContentType::PaidMedia,
             ContentType::Sticker,
             ContentType::Story,
             ContentType::Video,
@@ -131,6 +154,7 @@ impl ContentType {
             ContentType::Dice,
             ContentType::Game,
             ContentType::Poll,
+            ContentType::Checklist,
             ContentType::Venue,
             ContentType::Location,
             ContentType::NewChatMembers,
@@ -147,6 +171,7 @@ impl ContentType {
             ContentType::PinnedMessage,
             ContentType::Invoice,
             ContentType::SuccessfulPayment,
+            ContentType::RefundedPayment,
             ContentType::UsersShared,
             ContentType::ChatShared,
             ContentType::ConnectedWebsite,
@@ -154,6 +179,7 @@ impl ContentType {
             ContentType::PassportData,
             ContentType::ProximityAlertTriggered,
             ContentType::ChatBoostAdded,
+            ContentType::ChatBackgroundSet,
             ContentType::ForumTopicCreated,
             ContentType::ForumTopicEdited,
             ContentType::ForumTopicClosed,
@@ -164,6 +190,13 @@ impl ContentType {
             ContentType::Giveaway,
             ContentType::GiveawayWinners,
             ContentType::GiveawayCompleted,
+            ContentType::ChecklistTasksDone,
+            ContentType::ChecklistTasksAdded,
+            ContentType::SuggestedPostApproved,
+            ContentType::SuggestedPostApprovalFailed,
+            ContentType::SuggestedPostDeclined,
+            ContentType::SuggestedPostPaid,
+            ContentType::SuggestedPostRefunded,
             ContentType::VideoChatScheduled,
             ContentType::VideoChatStarted,
             ContentType::VideoChatEnded,
@@ -199,6 +232,7 @@ impl From<&Message> for ContentType {
             Message::Audio(_) => ContentType::Audio,
             Message::Document(_) => ContentType::Document,
             Message::Photo(_) => ContentType::Photo,
+            Message::PaidMedia(_) => ContentType::PaidMedia,
             Message::Sticker(_) => ContentType::Sticker,
             Message::Story(_) => ContentType::Story,
             Message::Video(_) => ContentType::Video,
@@ -208,6 +242,7 @@ impl From<&Message> for ContentType {
             Message::Dice(_) => ContentType::Dice,
             Message::Game(_) => ContentType::Game,
             Message::Poll(_) => ContentType::Poll,
+            Message::Checklist(_) => ContentType::Checklist,
             Message::Venue(_) => ContentType::Venue,
             Message::Location(_) => ContentType::Location,
             Message::NewChatMembers(_) => ContentType::NewChatMembers,
@@ -224,6 +259,7 @@ impl From<&Message> for ContentType {
             Message::Pinned(_) => ContentType::PinnedMessage,
             Message::Invoice(_) => ContentType::Invoice,
             Message::SuccessfulPayment(_) => ContentType::SuccessfulPayment,
+            Message::RefundedPayment(_) => ContentType::RefundedPayment,
             Message::UsersShared(_) => ContentType::UsersShared,
             Message::ChatShared(_) => ContentType::ChatShared,
             Message::ConnectedWebsite(_) => ContentType::ConnectedWebsite,
@@ -231,6 +267,7 @@ impl From<&Message> for ContentType {
             Message::PassportData(_) => ContentType::PassportData,
             Message::ProximityAlertTriggered(_) => ContentType::ProximityAlertTriggered,
             Message::ChatBoostAdded(_) => ContentType::ChatBoostAdded,
+            Message::ChatBackgroundSet(_) => ContentType::ChatBackgroundSet,
             Message::ForumTopicCreated(_) => ContentType::ForumTopicCreated,
             Message::ForumTopicEdited(_) => ContentType::ForumTopicEdited,
             Message::ForumTopicClosed(_) => ContentType::ForumTopicClosed,
@@ -241,6 +278,13 @@ impl From<&Message> for ContentType {
             Message::Giveaway(_) => ContentType::Giveaway,
             Message::GiveawayWinners(_) => ContentType::GiveawayWinners,
             Message::GiveawayCompleted(_) => ContentType::GiveawayCompleted,
+            Message::ChecklistTasksDone(_) => ContentType::ChecklistTasksDone,
+            Message::ChecklistTasksAdded(_) => ContentType::ChecklistTasksAdded,
+            Message::SuggestedPostApproved(_) => ContentType::SuggestedPostApproved,
+            Message::SuggestedPostApprovalFailed(_) => ContentType::SuggestedPostApprovalFailed,
+            Message::SuggestedPostDeclined(_) => ContentType::SuggestedPostDeclined,
+            Message::SuggestedPostPaid(_) => ContentType::SuggestedPostPaid,
+            Message::SuggestedPostRefunded(_) => ContentType::SuggestedPostRefunded,
             Message::VideoChatScheduled(_) => ContentType::VideoChatScheduled,
             Message::VideoChatStarted(_) => ContentType::VideoChatStarted,
             Message::VideoChatEnded(_) => ContentType::VideoChatEnded,
@@ -49,13 +49,15 @@ pub enum Telegram {
     ChatBoost,
     #[strum(serialize = "removed_chat_boost")]
     RemovedChatBoost,
+    #[strum(serialize = "purchased_paid_media")]
+    PurchasedPaidMedia,
     #[strum(serialize = "update")]
     Update,
 }
 
 impl Telegram {
     #[must_use]
-    pub const fn all() -> [Telegram; 23] {
+    pub const fn all() -> [Telegram; 24] {
         [
             Telegram::Message,
             Telegram::InlineQuery,
@@ -79,6 +81,7 @@ impl Telegram {
             Telegram::ChatJoinRequest,
             Telegram::ChatBoost,
             Telegram::RemovedChatBoost,
+            Telegram::PurchasedPaidMedia,
             Telegram::Update,
         ]
     }
@@ -109,6 +112,7 @@ impl From<Telegram> for Option<UpdateType> {
             Telegram::ChatJoinRequest => Some(UpdateType::ChatJoinRequest),
             Telegram::ChatBoost => Some(UpdateType::ChatBoost),
             Telegram::RemovedChatBoost => Some(UpdateType::RemovedChatBoost),
+            Telegram::PurchasedPaidMedia => Some(UpdateType::PurchasedPaidMedia),
             Telegram::Update => None,
         }
     }
@@ -139,6 +143,7 @@ impl PartialEq<UpdateType> for Telegram {
             Telegram::ChatJoinRequest => *other == UpdateType::ChatJoinRequest,
             Telegram::ChatBoost => *other == UpdateType::ChatBoost,
             Telegram::RemovedChatBoost => *other == UpdateType::RemovedChatBoost,
+            Telegram::PurchasedPaidMedia => *other == UpdateType::PurchasedPaidMedia,
             Telegram::Update => false,
         }
     }
@@ -51,11 +51,13 @@ pub enum UpdateType {
     ChatBoost,
     #[strum(serialize = "removed_chat_boost")]
     RemovedChatBoost,
+    #[strum(serialize = "purchased_paid_media")]
+    PurchasedPaidMedia,
 }
 
 impl UpdateType {
     #[must_use]
-    pub const fn all() -> [Self; 22] {
+    pub const fn all() -> [Self; 23] {
         [
             UpdateType::Message,
             UpdateType::InlineQuery,
@@ -79,6 +81,7 @@ impl UpdateType {
             UpdateType::ChatJoinRequest,
             UpdateType::ChatBoost,
             UpdateType::RemovedChatBoost,
+            UpdateType::PurchasedPaidMedia,
         ]
     }
 }
@@ -108,6 +111,7 @@ impl<'a> From<&'a UpdateKind> for UpdateType {
             UpdateKind::ChatJoinRequest(_) => UpdateType::ChatJoinRequest,
             UpdateKind::ChatBoost(_) => UpdateType::ChatBoost,
             UpdateKind::RemovedChatBoost(_) => UpdateType::RemovedChatBoost,
+            UpdateKind::PurchasedPaidMedia(_) => UpdateType::PurchasedPaidMedia,
         }
     }
 }
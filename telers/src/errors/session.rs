@@ -25,4 +25,7 @@ pub enum ErrorKind {
     /// Error by Telegram API
     #[error(transparent)]
     Telegram(#[from] TelegramErrorKind),
+    /// Method misses a required field, which isn't filled by the bot either, so the request isn't sent
+    #[error("Required field `{0}` isn't specified")]
+    MissingField(&'static str),
 }
@@ -13,17 +13,18 @@ use crate::{
     context::Context,
     errors::{ExtractionError, HandlerError},
     extractors::FromEventAndContext,
-    filters::Filter,
+    filters::{Filter, MenuCommand},
     types::Update,
 };
 
 use std::{
+    any::type_name,
     fmt::{self, Debug, Formatter},
     future::Future,
     result::Result as StdResult,
     sync::Arc,
 };
-use tracing::{event, instrument, Level};
+use tracing::{event, instrument, span, Instrument as _, Level};
 
 pub type BoxedHandlerService<Client> =
     BoxService<Request<Client>, Response<Client>, ExtractionError>;
@@ -167,6 +168,17 @@ pub struct HandlerObjectService<Client> {
     filters: Box<[Arc<dyn Filter<Client>>]>,
 }
 
+impl<Client> HandlerObjectService<Client> {
+    /// Commands of the bot menu from the handler filters
+    #[must_use]
+    pub fn menu_commands(&self) -> Vec<MenuCommand> {
+        self.filters
+            .iter()
+            .flat_map(|filter| filter.menu_commands())
+            .collect()
+    }
+}
+
 impl<Client> HandlerObjectService<Client>
 where
     Client: Sync,
@@ -175,9 +187,10 @@ where
     /// If the handler pass all them, it will be called.
     #[instrument(skip(self, request))]
     pub async fn check(&self, request: &Request<Client>) -> bool {
-        for filter in &*self.filters {
+        for (index, filter) in self.filters.iter().enumerate() {
             if !filter
                 .check(&request.bot, &request.update, &request.context)
+                .instrument(span!(Level::INFO, "filter", index))
                 .await
             {
                 return false;
@@ -219,7 +232,11 @@ where
             match Args::extract(bot, update, context) {
                 Ok(extracted_args) => Ok(Response {
                     request,
-                    handler_result: handler.call(extracted_args).await.into(),
+                    handler_result: handler
+                        .call(extracted_args)
+                        .instrument(span!(Level::INFO, "handler", handler = type_name::<H>()))
+                        .await
+                        .into(),
                 }),
                 Err(extraction_err) => {
                     let extraction_err = extraction_err.into();
@@ -12,7 +12,7 @@ use crate::{
         },
     },
     extractors::FromEventAndContext,
-    filters::Filter,
+    filters::{Filter, MenuCommand},
     middlewares::{
         inner::{
             wrap_handler_and_middlewares_to_next, Manager as InnerMiddlewareManager,
@@ -27,7 +27,7 @@ use std::{
     fmt::{self, Debug, Formatter},
     sync::Arc,
 };
-use tracing::{event, instrument, Level};
+use tracing::{event, instrument, span, Instrument as _, Level};
 
 pub struct Request<Client> {
     pub bot: Arc<Bot<Client>>,
@@ -240,11 +240,25 @@ pub struct Service<Client> {
 impl<Client> ServiceProvider for Service<Client> {}
 
 impl<Client> Service<Client> {
+    /// Commands of the bot menu from the observer and handlers filters
+    #[must_use]
+    pub fn menu_commands(&self) -> Vec<MenuCommand> {
+        self.common
+            .menu_commands()
+            .into_iter()
+            .chain(
+                self.handlers
+                    .iter()
+                    .flat_map(HandlerObjectService::menu_commands),
+            )
+            .collect()
+    }
+
     /// Propagate event to handlers and stops propagation on first match.
     /// Handler will be called when all its filters is pass.
     /// # Errors
     /// - If any handler returns error. Probably it's error to extract args to the handler.
-    #[instrument(skip(self, request))]
+    #[instrument(skip(self, request), fields(observer = %self.event_name))]
     pub async fn trigger(
         &self,
         request: Request<Client>,
@@ -278,7 +292,14 @@ impl<Client> Service<Client> {
                         Arc::clone(&handler.service),
                         middlewares.to_vec().into_boxed_slice(), // we use it instead of `into` because some versions of rustc can't infer type
                     ));
-                    middleware.call(handler_request.clone(), next).await
+                    middleware
+                        .call(handler_request.clone(), next)
+                        .instrument(span!(
+                            Level::INFO,
+                            "inner_middleware",
+                            observer = %self.event_name
+                        ))
+                        .await
                 }
                 None => handler
                     .call(handler_request.clone())
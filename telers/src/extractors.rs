@@ -6,6 +6,8 @@
 //! The trait also is implemented for `Option<T>`, `Result<T, E>` where `T: FromEventAndContext`,
 //! so you can don't implement it for your types if you want to use them as optional or result arguments.
 //!
+//! Entities of the message, like URLs, mentions, hashtags and bot commands, can be extracted with the extractors from [`entities`] module.
+//!
 //! # Implementing trait
 //!
 //! Ways to implement [`FromEventAndContext`] for your own types:
@@ -242,7 +244,10 @@
 //!
 //! This code similar to the previous one, but more useful in cases when `from` type is a foreign type.
 
+pub mod entities;
+
 pub use crate::{FromContext, FromEvent};
+pub use entities::{BotCommands, Hashtags, Mentions, Urls};
 
 use crate::{
     client::{Bot, Reqwest},
@@ -0,0 +1,157 @@
+//! Extractors of the message entities.
+//!
+//! Each extractor collects entities of the kind from both text and caption of the message:
+//! * [`Urls`] - URLs, including URLs of text links
+//! * [`Mentions`] - mentions, for example, `@username`
+//! * [`Hashtags`] - hashtags, for example, `#hashtag`
+//! * [`BotCommands`] - bot commands, for example, `/start@bot_username`
+//!
+//! Extraction fails if the update doesn't contain a message.
+//! If the message doesn't contain entities of the kind, the list is empty.
+
+use super::FromEventAndContext;
+
+use crate::{
+    client::Bot,
+    context::Context,
+    errors::ExtractionError,
+    types::{Message, MessageEntityKind, Update},
+};
+
+use std::sync::Arc;
+
+/// Get the texts of entities of the kind from text and caption of the message
+fn entities_texts<'a>(message: &'a Message, kind: &MessageEntityKind) -> Vec<&'a str> {
+    message
+        .parse_entities(std::slice::from_ref(kind))
+        .into_iter()
+        .chain(message.parse_caption_entities(std::slice::from_ref(kind)))
+        .map(|(_, text)| text)
+        .collect()
+}
+
+macro_rules! impl_entities_extractor {
+    ($name:ident, $kind:expr, $doc:literal) => {
+        #[doc = $doc]
+        #[derive(Debug, Clone, Default, Hash, PartialEq, Eq)]
+        pub struct $name(pub Box<[Box<str>]>);
+
+        impl $name {
+            /// Collect the entities from text and caption of the message
+            #[must_use]
+            pub fn from_message(message: &Message) -> Self {
+                Self(
+                    entities_texts(message, &$kind)
+                        .into_iter()
+                        .map(Into::into)
+                        .collect(),
+                )
+            }
+        }
+
+        impl<Client> FromEventAndContext<Client> for $name {
+            type Error = ExtractionError;
+
+            fn extract(
+                _bot: Arc<Bot<Client>>,
+                update: Arc<Update>,
+                _context: Arc<Context>,
+            ) -> Result<Self, Self::Error> {
+                match update.message() {
+                    Some(message) => Ok(Self::from_message(message)),
+                    None => Err(ExtractionError::new("Update doesn't contain a message")),
+                }
+            }
+        }
+    };
+}
+
+impl_entities_extractor!(
+    Mentions,
+    MessageEntityKind::Mention,
+    "Mentions of the message, for example, `@username`"
+);
+impl_entities_extractor!(
+    Hashtags,
+    MessageEntityKind::Hashtag,
+    "Hashtags of the message, for example, `#hashtag`"
+);
+impl_entities_extractor!(
+    BotCommands,
+    MessageEntityKind::BotCommand,
+    "Bot commands of the message, for example, `/start@bot_username`"
+);
+
+/// URLs of the message, including URLs of text links
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq)]
+pub struct Urls(pub Box<[Box<str>]>);
+
+impl Urls {
+    /// Collect the URLs from text and caption of the message
+    #[must_use]
+    pub fn from_message(message: &Message) -> Self {
+        Self(
+            message
+                .parse_entities(&[])
+                .into_iter()
+                .chain(message.parse_caption_entities(&[]))
+                .filter_map(|(entity, text)| match entity.kind {
+                    MessageEntityKind::Url => Some(text.into()),
+                    MessageEntityKind::TextLink(text_link) => Some(text_link.url.into()),
+                    _ => None,
+                })
+                .collect(),
+        )
+    }
+}
+
+impl<Client> FromEventAndContext<Client> for Urls {
+    type Error = ExtractionError;
+
+    fn extract(
+        _bot: Arc<Bot<Client>>,
+        update: Arc<Update>,
+        _context: Arc<Context>,
+    ) -> Result<Self, Self::Error> {
+        match update.message() {
+            Some(message) => Ok(Self::from_message(message)),
+            None => Err(ExtractionError::new("Update doesn't contain a message")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{MessageEntity, MessageText};
+
+    #[test]
+    fn test_from_message() {
+        let text = "👋 @user #тег /start https://a.b link";
+        let message = Message::Text(Box::new(MessageText {
+            text: text.into(),
+            entities: Some(
+                [
+                    MessageEntity::new_mention(3, 5),
+                    MessageEntity::new_hashtag(9, 4),
+                    MessageEntity::new_bot_command(14, 6),
+                    MessageEntity::new_url(21, 11),
+                    MessageEntity::new_text_link(33, 4, "https://c.d"),
+                ]
+                .into(),
+            ),
+            ..Default::default()
+        }));
+
+        assert_eq!(&*Mentions::from_message(&message).0, [Box::from("@user")]);
+        assert_eq!(&*Hashtags::from_message(&message).0, [Box::from("#тег")]);
+        assert_eq!(
+            &*BotCommands::from_message(&message).0,
+            [Box::from("/start")]
+        );
+        assert_eq!(
+            &*Urls::from_message(&message).0,
+            [Box::from("https://a.b"), Box::from("https://c.d")]
+        );
+    }
+}
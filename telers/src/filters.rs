@@ -10,6 +10,14 @@
 //! Each filter has a method [`Filter::invert`], [`Filter::and`] and [`Filter::or`] to create [`Invert`], [`And`] and [`Or`] filters respectively.
 //!
 //! Ready-made implementations:
+//! * [`CallbackData`]:
+//!   Filter for checking the callback data of the callback query.
+//!   Parses the data as a type that implements [`CallbackDataTrait`] and checks it with predicates.
+//!   Creates with `CallbackData::filter` method of the type and `filter` method to add predicates.
+//! * [`ChatMemberSubscription`]:
+//!   Filter for checking the paid subscription events in `chat_member` and `my_chat_member` updates.
+//!   Usually used with [`SubscriptionEvent`] to check whether the subscription was renewed or expired.
+//!   Creates with `one`, `many`, `renewal` or `expiration` methods.
 //! * [`ChatType`]:
 //! Filter for checking the type of chat.
 //! Usually used with [`ChatTypeEnum`] (or its string representation) to check the type of chat.
@@ -20,6 +28,10 @@
 //! for example, text, [`BotCommand`] (just alias to text of command) or [`Regex`].
 //! You can create a filter with `new` method with transferring all necessary data at once, or use [`CommandBuilder`] to create a filter step by step.
 //! Instead of [`CommandBuilder`] you can use [`Command`] `one`, `one_with_prefix`, `many`, `many_with_prefix` methods.
+//! * [`CommandStart`]:
+//!   This filter checks if the message is a `/start` command with or without a deep link payload.
+//!   Creates with `new`, `deep_link` or `deep_link_encoded` (the payload is decoded from base64url) methods.
+//!   The payload is available in handlers as [`StartPayload`].
 //! * [`ContentType`]:
 //! Filter for checking the type of the message content.
 //! Usually used with [`ContentTypeEnum`] (or its string representation) to check the type of content.
@@ -48,6 +60,7 @@
 //! so if at least one check is successful, the filter will return the value `true`.
 //!
 //! [`Cow`]: std::borrow::Cow
+//! [`CallbackDataTrait`]: crate::utils::callback_data::CallbackData
 //! [`Regex`]: regex::Regex
 //! [`ChatTypeEnum`]: crate::enums::ChatType
 //! [`ContentTypeEnum`]: crate::enums::ContentType
@@ -60,8 +73,11 @@
 //! [`update`]: crate::types::Update
 
 pub mod base;
+pub mod callback_data;
+pub mod chat_member_subscription;
 pub mod chat_type;
 pub mod command;
+pub mod command_start;
 pub mod content_type;
 pub mod logical;
 pub mod state;
@@ -69,8 +85,11 @@ pub mod text;
 pub mod user;
 
 pub use base::Filter;
+pub use callback_data::CallbackData;
+pub use chat_member_subscription::{ChatMemberSubscription, SubscriptionEvent};
 pub use chat_type::ChatType;
-pub use command::{Builder as CommandBuilder, Command, CommandObject};
+pub use command::{Builder as CommandBuilder, Command, CommandObject, MenuCommand};
+pub use command_start::{CommandStart, StartPayload};
 pub use content_type::ContentType;
 pub use logical::{And, Invert, Or};
 pub use state::{State, StateType};
@@ -1,4 +1,4 @@
-use super::{And, Invert, Or};
+use super::{And, Invert, MenuCommand, Or};
 
 use crate::{
     client::{Bot, Reqwest},
@@ -22,6 +22,16 @@ pub trait Filter<Client = Reqwest>: Send + Sync {
     /// `true` if the filter passes, otherwise `false`
     async fn check(&self, bot: &Bot<Client>, update: &Update, context: &Context) -> bool;
 
+    /// Commands of the bot menu that the filter handles.
+    /// They are collected from the router tree to set the bot menu, see [`Builder::sync_menu_commands`]
+    /// # Notes
+    /// Most filters don't handle commands, so by default it returns nothing
+    ///
+    /// [`Builder::sync_menu_commands`]: crate::dispatcher::Builder::sync_menu_commands
+    fn menu_commands(&self) -> Vec<MenuCommand> {
+        vec![]
+    }
+
     /// Invert result of the filter
     /// # Notes
     /// This method is used to create [`Invert`] filter
@@ -62,6 +72,10 @@ where
     async fn check(&self, bot: &Bot<Client>, update: &Update, context: &Context) -> bool {
         T::check(self, bot, update, context).await
     }
+
+    fn menu_commands(&self) -> Vec<MenuCommand> {
+        T::menu_commands(self)
+    }
 }
 
 /// To possible use function-like as filters
@@ -0,0 +1,113 @@
+use super::base::Filter;
+
+use crate::{
+    client::Bot, context::Context, types::Update,
+    utils::callback_data::CallbackData as CallbackDataTrait,
+};
+
+use async_trait::async_trait;
+use std::{fmt::Debug, marker::PhantomData};
+
+type Predicate<T> = Box<dyn Fn(&T) -> bool + Send + Sync>;
+
+/// Filter for checking the callback data of the callback query.
+/// Passes if the data is parsed as `T` and all predicates return `true`.
+/// # Notes
+/// Usually created with [`CallbackData::filter`](CallbackDataTrait::filter) method of the type
+pub struct CallbackData<T> {
+    predicates: Box<[Predicate<T>]>,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> CallbackData<T> {
+    /// Creates a new [`CallbackData`] filter without predicates
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            predicates: Box::default(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Add a predicate on the parsed data, for example, `|data: &Buy| data.count > 0`
+    #[must_use]
+    pub fn filter(self, predicate: impl Fn(&T) -> bool + Send + Sync + 'static) -> Self {
+        let mut predicates = self.predicates.into_vec();
+        predicates.push(Box::new(predicate));
+
+        Self {
+            predicates: predicates.into(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for CallbackData<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Debug for CallbackData<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CallbackData")
+            .field("predicates", &self.predicates.len())
+            .finish()
+    }
+}
+
+impl<T> CallbackData<T>
+where
+    T: CallbackDataTrait,
+{
+    #[must_use]
+    pub fn validate_data(&self, data: &str) -> bool {
+        match T::unpack(data) {
+            Ok(data) => self.check_predicates(&data),
+            Err(_) => false,
+        }
+    }
+
+    fn check_predicates(&self, data: &T) -> bool {
+        self.predicates.iter().all(|predicate| predicate(data))
+    }
+}
+
+#[async_trait]
+impl<Client, T> Filter<Client> for CallbackData<T>
+where
+    Client: Sync,
+    T: CallbackDataTrait,
+{
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        match T::from_update(update) {
+            Ok(data) => self.check_predicates(&data),
+            Err(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(crate::utils::callback_data::CallbackData)]
+    #[callback_data(prefix = "buy")]
+    struct Buy {
+        id: i64,
+    }
+
+    #[test]
+    fn test_validate_data() {
+        let filter = Buy::filter();
+
+        assert!(filter.validate_data("buy:1"));
+        assert!(!filter.validate_data("sell:1"));
+        assert!(!filter.validate_data("buy:one"));
+
+        let filter = Buy::filter().filter(|data| data.id > 0);
+
+        assert!(filter.validate_data("buy:1"));
+        assert!(!filter.validate_data("buy:-1"));
+    }
+}
@@ -0,0 +1,119 @@
+use super::base::Filter;
+
+use crate::{
+    client::Bot,
+    context::Context,
+    types::{ChatMemberUpdated, Update, UpdateKind},
+};
+
+use async_trait::async_trait;
+
+/// Event of a paid chat subscription
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum SubscriptionEvent {
+    /// The user's subscription was renewed. See [`ChatMemberUpdated::is_subscription_renewal`].
+    Renewal,
+    /// The user's subscription expired. See [`ChatMemberUpdated::is_subscription_expiration`].
+    Expiration,
+}
+
+impl SubscriptionEvent {
+    #[must_use]
+    pub const fn all() -> [SubscriptionEvent; 2] {
+        [SubscriptionEvent::Renewal, SubscriptionEvent::Expiration]
+    }
+}
+
+/// Filter for checking the paid subscription events in `chat_member` and `my_chat_member` updates
+#[derive(Debug, Clone)]
+pub struct ChatMemberSubscription {
+    events: Box<[SubscriptionEvent]>,
+}
+
+impl ChatMemberSubscription {
+    /// Creates a new [`ChatMemberSubscription`] filter with one allowed subscription event
+    #[must_use]
+    pub fn one(event: SubscriptionEvent) -> Self {
+        Self {
+            events: [event].into(),
+        }
+    }
+
+    /// Creates a new [`ChatMemberSubscription`] filter with many allowed subscription events
+    pub fn many(events: impl IntoIterator<Item = SubscriptionEvent>) -> Self {
+        Self {
+            events: events.into_iter().collect(),
+        }
+    }
+
+    /// Creates a new [`ChatMemberSubscription`] filter that passes subscription renewals
+    #[must_use]
+    pub fn renewal() -> Self {
+        Self::one(SubscriptionEvent::Renewal)
+    }
+
+    /// Creates a new [`ChatMemberSubscription`] filter that passes subscription expirations
+    #[must_use]
+    pub fn expiration() -> Self {
+        Self::one(SubscriptionEvent::Expiration)
+    }
+}
+
+impl ChatMemberSubscription {
+    #[must_use]
+    pub fn validate(&self, chat_member_updated: &ChatMemberUpdated) -> bool {
+        self.events.iter().any(|event| match event {
+            SubscriptionEvent::Renewal => chat_member_updated.is_subscription_renewal(),
+            SubscriptionEvent::Expiration => chat_member_updated.is_subscription_expiration(),
+        })
+    }
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for ChatMemberSubscription {
+    async fn check(&self, _bot: &Bot<Client>, update: &Update, _context: &Context) -> bool {
+        match &update.kind {
+            UpdateKind::ChatMember(chat_member_updated)
+            | UpdateKind::MyChatMember(chat_member_updated) => self.validate(chat_member_updated),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Chat, ChatMember, ChatMemberMember, User};
+
+    #[test]
+    fn test_validate() {
+        let member = |until_date| {
+            ChatMember::Member(ChatMemberMember {
+                user: User::default(),
+                until_date: Some(until_date),
+            })
+        };
+        let chat_member_updated = ChatMemberUpdated {
+            chat: Chat::default(),
+            from: User::default(),
+            date: 100,
+            old_chat_member: member(100),
+            new_chat_member: member(200),
+            invite_link: None,
+            via_chat_folder_invite_link: None,
+        };
+
+        // The subscription events themselves are checked in `ChatMemberUpdated` tests
+        assert_eq!(
+            ChatMemberSubscription::renewal().validate(&chat_member_updated),
+            chat_member_updated.is_subscription_renewal()
+        );
+        assert_eq!(
+            ChatMemberSubscription::expiration().validate(&chat_member_updated),
+            chat_member_updated.is_subscription_expiration()
+        );
+        assert!(
+            ChatMemberSubscription::many(SubscriptionEvent::all()).validate(&chat_member_updated)
+        );
+    }
+}
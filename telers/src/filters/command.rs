@@ -6,7 +6,7 @@ use crate::{
     errors::SessionErrorKind,
     extractors::FromContext,
     methods::GetMe,
-    types::{BotCommand, Update},
+    types::{BotCommand, BotCommandScope, Update},
 };
 
 use async_trait::async_trait;
@@ -19,7 +19,7 @@ use tracing::{event, instrument, Level};
 /// * [`PatternType::Text(Cow<str>)`] - A command pattern with text
 /// * [`PatternType::Object(BotCommand)`] -
 /// A command pattern with [`BotCommand`] object. \
-/// Just a shortcut for [`PatternType::Text(command.command)`].
+/// It's checked like [`PatternType::Text(command.command)`], but its description is used in the bot menu for all languages.
 /// * [`PatternType::Regex(Regex)`] -
 /// A command pattern with regex, compiled with [`Regex`] struct. \
 /// If filter used with `ignore_case` flag, then the regex will be compiled with `(?i)` flag (ignore case sensitive flag).
@@ -54,6 +54,36 @@ impl From<Regex> for PatternType<'_> {
     }
 }
 
+/// Represents a command of the bot menu in the scope for users with the language
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct MenuCommand {
+    /// Command with its description
+    pub command: BotCommand,
+    /// Scope of users for which the command is shown
+    pub scope: BotCommandScope,
+    /// Two-letter ISO 639-1 language code. If `None`, the command is shown for all users from the scope,
+    /// for whose language there are no dedicated commands
+    pub language_code: Option<Box<str>>,
+}
+
+impl MenuCommand {
+    #[must_use]
+    pub fn new(
+        command: BotCommand,
+        scope: impl Into<BotCommandScope>,
+        language_code: Option<impl Into<Box<str>>>,
+    ) -> Self {
+        Self {
+            command,
+            scope: scope.into(),
+            language_code: language_code.map(Into::into),
+        }
+    }
+}
+
+/// Description of the commands in the bot menu with its language code
+type Description = (Option<Box<str>>, Box<str>);
+
 /// This filter checks if the message is a command.
 ///
 /// Filter accepts [`PatternType`] that represents a command pattern type for verification,
@@ -62,6 +92,9 @@ impl From<Regex> for PatternType<'_> {
 /// # Notes
 /// You can use parsed command using [`CommandObject`] struct in handler arguments,
 /// or get it from [`Context`] by `command` key.
+///
+/// Text commands with `/` prefix and description can be shown in the bot menu,
+/// see [`Builder::description`] and [`Builder::scope`]. [`BotCommand`] patterns are shown with their own descriptions.
 #[derive(Debug, Clone)]
 pub struct Command<'a> {
     /// List of commands ([`Cow`], [`BotCommand`] or compiled [`Regex`] patterns)
@@ -72,6 +105,10 @@ pub struct Command<'a> {
     ignore_case: bool,
     /// Ignore bot mention
     ignore_mention: bool,
+    /// Descriptions of the commands in the bot menu with their language codes
+    descriptions: Box<[Description]>,
+    /// Scopes of the bot menu in which the commands are shown
+    scopes: Box<[BotCommandScope]>,
 }
 
 impl<'a> Command<'a> {
@@ -98,9 +135,9 @@ impl<'a> Command<'a> {
                 .into_iter()
                 .map(|command| match command.into() {
                     PatternType::Text(text) => PatternType::Text(text.to_lowercase().into()),
-                    // We convert object to text, because this pattern type is just a shortcut for text
                     PatternType::Object(command) => {
-                        PatternType::Text(command.command.to_lowercase().into())
+                        let command_name = command.command.to_lowercase();
+                        PatternType::Object(command.command(command_name))
                     }
                     PatternType::Regex(regex) => {
                         if ignore_mention {
@@ -116,8 +153,7 @@ impl<'a> Command<'a> {
                 .into_iter()
                 .map(|command| match command.into() {
                     PatternType::Text(text) => PatternType::Text(text),
-                    // We convert object to text, because this pattern type is just a shortcut for text
-                    PatternType::Object(command) => PatternType::Text(command.command.into()),
+                    PatternType::Object(command) => PatternType::Object(command),
                     PatternType::Regex(regex) => {
                         if ignore_mention {
                             event!(Level::WARN, "Ignore mention flag doesn't work with regexes");
@@ -134,6 +170,8 @@ impl<'a> Command<'a> {
             prefix,
             ignore_case,
             ignore_mention,
+            descriptions: Box::new([]),
+            scopes: Box::new([]),
         }
     }
 
@@ -195,6 +233,8 @@ impl Default for Command<'_> {
             prefix: '/',
             ignore_case: false,
             ignore_mention: false,
+            descriptions: Box::new([]),
+            scopes: Box::new([]),
         }
     }
 }
@@ -205,6 +245,8 @@ pub struct Builder<'a> {
     prefix: char,
     ignore_case: bool,
     ignore_mention: bool,
+    descriptions: Vec<Description>,
+    scopes: Vec<BotCommandScope>,
 }
 
 impl<'a> Builder<'a> {
@@ -261,14 +303,80 @@ impl<'a> Builder<'a> {
         }
     }
 
+    /// Description of the commands in the bot menu for users without a dedicated language.
+    /// Commands without description aren't shown in the bot menu
+    #[must_use]
+    pub fn description(self, val: impl Into<Box<str>>) -> Self {
+        Self {
+            descriptions: self
+                .descriptions
+                .into_iter()
+                .chain(once((None, val.into())))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Description of the commands in the bot menu for users with the language
+    /// # Arguments
+    /// * `language_code` - Two-letter ISO 639-1 language code
+    /// * `val` - Description of the commands
+    #[must_use]
+    pub fn description_with_language(
+        self,
+        language_code: impl Into<Box<str>>,
+        val: impl Into<Box<str>>,
+    ) -> Self {
+        Self {
+            descriptions: self
+                .descriptions
+                .into_iter()
+                .chain(once((Some(language_code.into()), val.into())))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Scope of users for which the commands are shown in the bot menu.
+    /// If no scopes are set, the default scope is used
+    #[must_use]
+    pub fn scope(self, val: impl Into<BotCommandScope>) -> Self {
+        Self {
+            scopes: self.scopes.into_iter().chain(once(val.into())).collect(),
+            ..self
+        }
+    }
+
+    /// Scopes of users for which the commands are shown in the bot menu.
+    /// If no scopes are set, the default scope is used
+    #[must_use]
+    pub fn scopes<T, I>(self, val: I) -> Self
+    where
+        T: Into<BotCommandScope>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            scopes: self
+                .scopes
+                .into_iter()
+                .chain(val.into_iter().map(Into::into))
+                .collect(),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn build(self) -> Command<'a> {
-        Command::new(
-            self.commands,
-            self.prefix,
-            self.ignore_case,
-            self.ignore_mention,
-        )
+        Command {
+            descriptions: self.descriptions.into(),
+            scopes: self.scopes.into(),
+            ..Command::new(
+                self.commands,
+                self.prefix,
+                self.ignore_case,
+                self.ignore_mention,
+            )
+        }
     }
 }
 
@@ -280,6 +388,8 @@ impl Default for Builder<'_> {
             prefix: '/',
             ignore_case: false,
             ignore_mention: false,
+            descriptions: vec![],
+            scopes: vec![],
         }
     }
 }
@@ -331,8 +441,10 @@ impl Command<'_> {
                         return true;
                     }
                 }
-                PatternType::Object(_) => {
-                    unreachable!("`PatternType::Object` should be converted to `PatternType::Text` before validation")
+                PatternType::Object(allowed_command) => {
+                    if command_ref == allowed_command.command {
+                        return true;
+                    }
                 }
             }
         }
@@ -340,6 +452,63 @@ impl Command<'_> {
         false
     }
 
+    /// Commands of the bot menu: each text command and [`BotCommand`] with `/` prefix for each scope and description.
+    /// Regex patterns and commands with other prefixes can't be shown in the bot menu, so they are skipped
+    #[must_use]
+    pub fn menu_commands(&self) -> Vec<MenuCommand> {
+        if self.prefix != '/' {
+            return vec![];
+        }
+
+        let default_scopes = [BotCommandScope::default()];
+        let scopes = if self.scopes.is_empty() {
+            &default_scopes[..]
+        } else {
+            &self.scopes
+        };
+
+        let mut menu_commands = vec![];
+
+        for pattern in &*self.commands {
+            let (command, object_description) = match pattern {
+                PatternType::Text(command) => (command.as_ref(), None),
+                PatternType::Object(command) => {
+                    (command.command.as_str(), Some(command.description.as_str()))
+                }
+                PatternType::Regex(_) => continue,
+            };
+
+            // Description of the command object is used for all languages instead of the filter description,
+            // but descriptions with specific languages are still used
+            let descriptions = object_description
+                .map(|description| (None, description))
+                .into_iter()
+                .chain(
+                    self.descriptions
+                        .iter()
+                        .filter(|(language_code, _)| {
+                            object_description.is_none() || language_code.is_some()
+                        })
+                        .map(|(language_code, description)| {
+                            (language_code.as_ref(), description.as_ref())
+                        }),
+                )
+                .collect::<Vec<_>>();
+
+            for scope in scopes {
+                for (language_code, description) in &descriptions {
+                    menu_commands.push(MenuCommand {
+                        command: BotCommand::new(command, *description),
+                        scope: scope.clone(),
+                        language_code: language_code.cloned(),
+                    });
+                }
+            }
+        }
+
+        menu_commands
+    }
+
     /// # Errors
     /// If error occurred in the process of sending request to the Telegram API or parsing response
     pub async fn validate_command_object(
@@ -455,6 +624,10 @@ where
             }
         }
     }
+
+    fn menu_commands(&self) -> Vec<MenuCommand> {
+        self.menu_commands()
+    }
 }
 
 #[cfg(test)]
@@ -618,6 +791,92 @@ mod tests {
 
         let command_obj = CommandObject::extract("/STOP").unwrap();
         assert!(!command.validate_command(&command_obj));
+
+        // Command objects are checked by their command text
+        let command = Command::builder()
+            .command(BotCommand::new("Start", "Start the bot"))
+            .ignore_case(true)
+            .build();
+
+        let command_obj = CommandObject::extract("/START").unwrap();
+        assert!(command.validate_command(&command_obj));
+
+        let command_obj = CommandObject::extract("/stop").unwrap();
+        assert!(!command.validate_command(&command_obj));
+    }
+
+    #[test]
+    fn test_menu_commands() {
+        let command = Command::builder()
+            .commands(["start", "help"])
+            .command(Regex::new(r"^st").unwrap())
+            .description("Start the bot")
+            .description_with_language("uk", "Запустити бота")
+            .scope(BotCommandScope::all_private_chats())
+            .build();
+
+        let menu_commands = command.menu_commands();
+
+        assert_eq!(menu_commands.len(), 4);
+        assert_eq!(
+            menu_commands[0],
+            MenuCommand::new(
+                BotCommand::new("start", "Start the bot"),
+                BotCommandScope::all_private_chats(),
+                None::<Box<str>>,
+            )
+        );
+        assert_eq!(
+            menu_commands[3],
+            MenuCommand::new(
+                BotCommand::new("help", "Запустити бота"),
+                BotCommandScope::all_private_chats(),
+                Some("uk"),
+            )
+        );
+
+        // Commands without description aren't shown in the bot menu
+        assert!(Command::one("start").menu_commands().is_empty());
+        // Description of the command object is used for all languages
+        assert_eq!(
+            Command::builder()
+                .command(BotCommand::new("Start", "Start the bot"))
+                .description("Filter description")
+                .description_with_language("uk", "Запустити бота")
+                .ignore_case(true)
+                .build()
+                .menu_commands(),
+            [
+                MenuCommand::new(
+                    BotCommand::new("start", "Start the bot"),
+                    BotCommandScope::default(),
+                    None::<Box<str>>,
+                ),
+                MenuCommand::new(
+                    BotCommand::new("start", "Запустити бота"),
+                    BotCommandScope::default(),
+                    Some("uk"),
+                ),
+            ]
+        );
+        // Commands with other prefixes can't be shown in the bot menu
+        assert!(Command::builder()
+            .command("start")
+            .prefix('!')
+            .description("Start the bot")
+            .build()
+            .menu_commands()
+            .is_empty());
+        // Default scope is used if no scopes are set
+        assert_eq!(
+            Command::builder()
+                .command("start")
+                .description("Start the bot")
+                .build()
+                .menu_commands()[0]
+                .scope,
+            BotCommandScope::default()
+        );
     }
 
     // TODO: Add tests for `validate_mention` method
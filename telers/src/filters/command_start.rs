@@ -0,0 +1,209 @@
+use super::{
+    base::Filter,
+    command::{Command, CommandObject, MenuCommand},
+};
+
+use crate::{
+    client::{Bot, Session},
+    context::Context,
+    extractors::FromContext,
+    types::Update,
+    utils::deep_linking::decode_payload,
+};
+
+use async_trait::async_trait;
+use tracing::{event, instrument, Level};
+
+/// This filter checks if the message is a `/start` command with or without a deep link payload.
+///
+/// # Notes
+/// You can use the payload using [`StartPayload`] struct in handler arguments,
+/// or get it from [`Context`] by `start_payload` key.
+/// If the payload isn't required, use `Option<StartPayload>` to get it.
+///
+/// Parsed command is also available as [`CommandObject`], like in the [`Command`] filter.
+///
+/// To create deep links, see [`deep_linking`](crate::utils::deep_linking) module.
+#[derive(Debug, Clone)]
+pub struct CommandStart<'a> {
+    /// Filter of the `/start` command
+    command: Command<'a>,
+    /// Pass only commands with payload
+    deep_link: bool,
+    /// Decode the payload with base64url
+    deep_link_encoded: bool,
+}
+
+impl<'a> CommandStart<'a> {
+    /// Creates a new [`CommandStart`] filter that passes `/start` command with or without payload
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            command: Command::one("start"),
+            deep_link: false,
+            deep_link_encoded: false,
+        }
+    }
+
+    /// Creates a new [`CommandStart`] filter that passes `/start` command only with payload
+    #[must_use]
+    pub fn deep_link() -> Self {
+        Self {
+            deep_link: true,
+            ..Self::new()
+        }
+    }
+
+    /// Creates a new [`CommandStart`] filter that passes `/start` command only with base64url encoded payload
+    /// and decodes it, see [`encode_payload`](crate::utils::deep_linking::encode_payload)
+    #[must_use]
+    pub fn deep_link_encoded() -> Self {
+        Self {
+            deep_link: true,
+            deep_link_encoded: true,
+            ..Self::new()
+        }
+    }
+
+    /// Replace the filter of the `/start` command,
+    /// for example, to ignore bot mention or show the command with description in the bot menu
+    #[must_use]
+    pub fn command(self, val: Command<'a>) -> Self {
+        Self {
+            command: val,
+            ..self
+        }
+    }
+}
+
+impl Default for CommandStart<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommandStart<'_> {
+    /// Get the payload of the command, decoded if the filter is created with [`CommandStart::deep_link_encoded`]
+    /// # Returns
+    /// - `Some(Some(payload))` if the payload is valid
+    /// - `Some(None)` if the payload is missing, but it isn't required
+    /// - `None` if the payload is missing, but it's required, or the payload can't be decoded
+    #[must_use]
+    pub fn validate_payload(&self, command: &CommandObject) -> Option<Option<StartPayload>> {
+        let payload = command.args.join(" ");
+        let payload = payload.trim();
+
+        if payload.is_empty() {
+            return if self.deep_link { None } else { Some(None) };
+        }
+
+        let payload = if self.deep_link_encoded {
+            match decode_payload(payload) {
+                Ok(payload) => payload.into_boxed_str(),
+                Err(err) => {
+                    event!(Level::TRACE, error = %err, "Failed to decode deep link payload");
+
+                    return None;
+                }
+            }
+        } else {
+            payload.into()
+        };
+
+        Some(Some(StartPayload { payload }))
+    }
+}
+
+/// Represents a deep link payload of the `/start` command
+#[derive(Debug, Clone, Hash, PartialEq, Eq, FromContext)]
+#[context(
+    key = "start_payload",
+    description = "Deep link payload of the `/start` command. This type is available only if the command start filter is used, filter is passed and the payload isn't empty."
+)]
+pub struct StartPayload {
+    /// Payload, decoded if the filter is created with [`CommandStart::deep_link_encoded`]
+    pub payload: Box<str>,
+}
+
+#[async_trait]
+impl<Client> Filter<Client> for CommandStart<'_>
+where
+    Client: Session,
+{
+    #[instrument]
+    async fn check(&self, bot: &Bot<Client>, update: &Update, context: &Context) -> bool {
+        let Some(message) = update.message() else {
+            return false;
+        };
+        let Some(text) = message.text_or_caption() else {
+            return false;
+        };
+        let Some(command) = CommandObject::extract(text) else {
+            return false;
+        };
+
+        match self.command.validate_command_object(&command, bot).await {
+            Ok(true) => {}
+            Ok(false) => return false,
+            Err(err) => {
+                event!(Level::ERROR, error = %err, "Failed to validate command object");
+
+                return false;
+            }
+        }
+
+        let Some(payload) = self.validate_payload(&command) else {
+            return false;
+        };
+
+        if let Some(payload) = payload {
+            context.insert("start_payload", Box::new(payload));
+        }
+        context.insert("command", Box::new(command));
+
+        true
+    }
+
+    fn menu_commands(&self) -> Vec<MenuCommand> {
+        self.command.menu_commands()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::deep_linking::encode_payload;
+
+    #[test]
+    fn test_validate_payload() {
+        let command = CommandObject::extract("/start").unwrap();
+
+        assert_eq!(CommandStart::new().validate_payload(&command), Some(None));
+        assert_eq!(CommandStart::deep_link().validate_payload(&command), None);
+
+        let command = CommandObject::extract("/start ref_42").unwrap();
+        let payload = Some(Some(StartPayload {
+            payload: "ref_42".into(),
+        }));
+
+        assert_eq!(CommandStart::new().validate_payload(&command), payload);
+        assert_eq!(
+            CommandStart::deep_link().validate_payload(&command),
+            payload
+        );
+        assert_eq!(
+            CommandStart::deep_link_encoded().validate_payload(&command),
+            None
+        );
+
+        let command = CommandObject::extract(&format!("/start {}", encode_payload("a b"))).unwrap();
+
+        assert_eq!(
+            CommandStart::deep_link_encoded().validate_payload(&command),
+            Some(Some(StartPayload {
+                payload: "a b".into()
+            }))
+        );
+    }
+}
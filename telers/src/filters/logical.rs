@@ -9,7 +9,7 @@
 //! [`Filter`] trait has methods that allow you to combine filters in a more convenient way,
 //! see [`Filter::and`], [`Filter::or`] and [`Filter::invert`] methods.
 
-use super::base::Filter;
+use super::{base::Filter, MenuCommand};
 
 use crate::{client::Bot, context::Context, types::Update};
 
@@ -104,6 +104,37 @@ where
     }
 }
 
+impl<Client> And<Client> {
+    /// Commands of the bot menu from all filters
+    #[must_use]
+    pub fn menu_commands(&self) -> Vec<MenuCommand> {
+        self.filters
+            .iter()
+            .flat_map(|filter| filter.menu_commands())
+            .collect()
+    }
+}
+
+impl<Client> Or<Client> {
+    /// Commands of the bot menu from all filters
+    #[must_use]
+    pub fn menu_commands(&self) -> Vec<MenuCommand> {
+        self.filters
+            .iter()
+            .flat_map(|filter| filter.menu_commands())
+            .collect()
+    }
+}
+
+impl<Client> Invert<Client> {
+    /// Inverted filter doesn't handle commands of the inner filter, so it returns nothing
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn menu_commands(&self) -> Vec<MenuCommand> {
+        vec![]
+    }
+}
+
 /// A macro to implement [`Filter`] for [`And`], [`Or`] and [`Invert`] filters, because they have the same implementation
 macro_rules! impl_filter {
     ($name:ident) => {
@@ -115,6 +146,10 @@ macro_rules! impl_filter {
             async fn check(&self, bot: &Bot<Client>, update: &Update, context: &Context) -> bool {
                 self.validate(bot, update, context).await
             }
+
+            fn menu_commands(&self) -> Vec<MenuCommand> {
+                self.menu_commands()
+            }
         }
     };
 }
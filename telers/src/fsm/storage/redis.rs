@@ -144,6 +144,33 @@ impl KeyBuilder for KeyBuilderImpl {
     }
 }
 
+/// Create a pool of redis connections.
+/// It's shared by redis storages of the library
+/// # Errors
+/// If config is invalid
+pub(crate) fn create_pool<T>(connection_info: T) -> Result<Pool, RedisError>
+where
+    T: IntoConnectionInfo,
+{
+    let config = Config::from_connection_info(connection_info.into_connection_info()?);
+    match config.create_pool(Some(Runtime::Tokio1)) {
+        Ok(pool) => Ok(pool),
+        Err(err) => match err {
+            CreatePoolError::Config(err) => match err {
+                ConfigError::UrlAndConnectionSpecified => unreachable!(
+                    "This error should not be occurred because we use `IntoConnectionInfo` where it will use only one of them.\
+                    If you see this error, then report it to the library maintainer."
+                ),
+                ConfigError::Redis(err) => Err(err),
+            },
+            CreatePoolError::Build(_) => unreachable!(
+                "This error should not be occurred because we specify runtime in `create_pool` method.\
+                If you see this error, then report it to the library maintainer."
+            ),
+        },
+    }
+}
+
 /// This is a thread-safe storage implementation for redis
 #[derive(Clone)]
 pub struct Redis<K = KeyBuilderImpl> {
@@ -161,25 +188,10 @@ impl<K: KeyBuilder> Redis<K> {
     where
         T: IntoConnectionInfo,
     {
-        let config = Config::from_connection_info(connection_info.into_connection_info()?);
-        let pool = match config.create_pool(Some(Runtime::Tokio1)) {
-            Ok(pool) => pool,
-            Err(err) => match err {
-                CreatePoolError::Config(err) => match err {
-                    ConfigError::UrlAndConnectionSpecified => unreachable!(
-                        "This error should not be occurred because we use `IntoConnectionInfo` where it will use only one of them.\
-                        If you see this error, then report it to the library maintainer."
-                    ),
-                    ConfigError::Redis(err) => return Err(err),
-                },
-                CreatePoolError::Build(_) => unreachable!(
-                    "This error should not be occurred because we specify runtime in `create_pool` method.\
-                    If you see this error, then report it to the library maintainer."
-                ),
-            },
-        };
-
-        Ok(Self { pool, key_builder })
+        Ok(Self {
+            pool: create_pool(connection_info)?,
+            key_builder,
+        })
     }
 
     #[must_use]
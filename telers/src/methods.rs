@@ -20,6 +20,8 @@
 //! }
 //! ```
 //!
+//! Methods which aren't supported by the library yet can be called by [`Raw`] method with a caller-chosen payload and result types.
+//!
 //! You can check more examples of usage methods in the [`examples`] directory.
 //!
 //! [`examples`]: https://github.com/Desiders/telers/tree/dev-1.x/examples
@@ -31,18 +33,23 @@ pub mod answer_pre_checkout_query;
 pub mod answer_shipping_query;
 pub mod answer_web_app_query;
 pub mod approve_chat_join_request;
+pub mod approve_suggested_post;
 pub mod ban_chat_member;
 pub mod ban_chat_sender_chat;
 pub mod base;
 pub mod close_forum_topic;
 pub mod close_general_forum_topic;
+pub mod convert_gift_to_stars;
 pub mod copy_message;
 pub mod copy_messages;
 pub mod create_chat_invite_link;
+pub mod create_chat_subscription_invite_link;
 pub mod create_forum_topic;
 pub mod create_invoice_link;
 pub mod create_new_sticker_set;
 pub mod decline_chat_join_request;
+pub mod decline_suggested_post;
+pub mod delete_business_messages;
 pub mod delete_chat_photo;
 pub mod delete_chat_sticker_set;
 pub mod delete_forum_topic;
@@ -51,17 +58,25 @@ pub mod delete_messages;
 pub mod delete_my_commands;
 pub mod delete_sticker_from_set;
 pub mod delete_sticker_set;
+pub mod delete_story;
 pub mod edit_chat_invite_link;
+pub mod edit_chat_subscription_invite_link;
 pub mod edit_forum_topic;
 pub mod edit_general_forum_topic;
 pub mod edit_message_caption;
+pub mod edit_message_checklist;
 pub mod edit_message_live_location;
 pub mod edit_message_media;
 pub mod edit_message_reply_markup;
 pub mod edit_message_text;
+pub mod edit_story;
+pub mod edit_user_star_subscription;
 pub mod export_chat_invite_link;
 pub mod forward_message;
 pub mod forward_messages;
+pub mod get_available_gifts;
+pub mod get_business_account_gifts;
+pub mod get_business_account_star_balance;
 pub mod get_business_connection;
 pub mod get_chat;
 pub mod get_chat_administrators;
@@ -78,6 +93,7 @@ pub mod get_my_default_administrator_rights;
 pub mod get_my_description;
 pub mod get_my_name;
 pub mod get_my_short_description;
+pub mod get_star_transactions;
 pub mod get_sticker_set;
 pub mod get_updates;
 pub mod get_user_chat_boosts;
@@ -86,23 +102,33 @@ pub mod hide_general_forum_topic;
 pub mod leave_chat;
 pub mod log_out;
 pub mod pin_chat_message;
+pub mod post_story;
 pub mod promote_chat_member;
+pub mod raw;
+pub mod read_business_message;
+pub mod refund_star_payment;
+pub mod remove_chat_verification;
+pub mod remove_user_verification;
 pub mod reopen_forum_topic;
 pub mod reopen_general_forum_topic;
 pub mod replace_sticker_in_set;
 pub mod restrict_chat_member;
 pub mod revoke_chat_invite_link;
+pub mod save_prepared_inline_message;
 pub mod send_animation;
 pub mod send_audio;
 pub mod send_chat_action;
+pub mod send_checklist;
 pub mod send_contact;
 pub mod send_dice;
 pub mod send_document;
 pub mod send_game;
+pub mod send_gift;
 pub mod send_invoice;
 pub mod send_location;
 pub mod send_media_group;
 pub mod send_message;
+pub mod send_paid_media;
 pub mod send_photo;
 pub mod send_poll;
 pub mod send_sticker;
@@ -110,6 +136,10 @@ pub mod send_venue;
 pub mod send_video;
 pub mod send_video_note;
 pub mod send_voice;
+pub mod set_business_account_bio;
+pub mod set_business_account_name;
+pub mod set_business_account_profile_photo;
+pub mod set_business_account_username;
 pub mod set_chat_administrator_custom_title;
 pub mod set_chat_description;
 pub mod set_chat_menu_button;
@@ -131,8 +161,10 @@ pub mod set_sticker_mask_position;
 pub mod set_sticker_position_in_set;
 pub mod set_sticker_set_thumbnail;
 pub mod set_sticker_set_title;
+pub mod set_user_emoji_status;
 pub mod stop_message_live_location;
 pub mod stop_poll;
+pub mod transfer_business_account_stars;
 pub mod unban_chat_member;
 pub mod unban_chat_sender_chat;
 pub mod unhide_general_forum_topic;
@@ -141,6 +173,8 @@ pub mod unpin_all_forum_topic_messages;
 pub mod unpin_all_general_forum_topic_messages;
 pub mod unpin_chat_message;
 pub mod upload_sticker_file;
+pub mod verify_chat;
+pub mod verify_user;
 
 pub use add_sticker_to_set::AddStickerToSet;
 pub use answer_callback_query::AnswerCallbackQuery;
@@ -149,18 +183,23 @@ pub use answer_pre_checkout_query::AnswerPreCheckoutQuery;
 pub use answer_shipping_query::AnswerShippingQuery;
 pub use answer_web_app_query::AnswerWebAppQuery;
 pub use approve_chat_join_request::ApproveChatJoinRequest;
+pub use approve_suggested_post::ApproveSuggestedPost;
 pub use ban_chat_member::BanChatMember;
 pub use ban_chat_sender_chat::BanChatSenderChat;
 pub use base::{Request, Response, TelegramMethod};
 pub use close_forum_topic::CloseForumTopic;
 pub use close_general_forum_topic::CloseGeneralForumTopic;
+pub use convert_gift_to_stars::ConvertGiftToStars;
 pub use copy_message::CopyMessage;
 pub use copy_messages::CopyMessages;
 pub use create_chat_invite_link::CreateChatInviteLink;
+pub use create_chat_subscription_invite_link::CreateChatSubscriptionInviteLink;
 pub use create_forum_topic::CreateForumTopic;
 pub use create_invoice_link::CreateInvoiceLink;
 pub use create_new_sticker_set::CreateNewStickerSet;
 pub use decline_chat_join_request::DeclineChatJoinRequest;
+pub use decline_suggested_post::DeclineSuggestedPost;
+pub use delete_business_messages::DeleteBusinessMessages;
 pub use delete_chat_photo::DeleteChatPhoto;
 pub use delete_chat_sticker_set::DeleteChatStickerSet;
 pub use delete_forum_topic::DeleteForumTopic;
@@ -169,17 +208,25 @@ pub use delete_messages::DeleteMessages;
 pub use delete_my_commands::DeleteMyCommands;
 pub use delete_sticker_from_set::DeleteStickerFromSet;
 pub use delete_sticker_set::DeleteStickerSet;
+pub use delete_story::DeleteStory;
 pub use edit_chat_invite_link::EditChatInviteLink;
+pub use edit_chat_subscription_invite_link::EditChatSubscriptionInviteLink;
 pub use edit_forum_topic::EditForumTopic;
 pub use edit_general_forum_topic::EditGeneralForumTopic;
 pub use edit_message_caption::EditMessageCaption;
+pub use edit_message_checklist::EditMessageChecklist;
 pub use edit_message_live_location::EditMessageLiveLocation;
 pub use edit_message_media::EditMessageMedia;
 pub use edit_message_reply_markup::EditMessageReplyMarkup;
 pub use edit_message_text::EditMessageText;
+pub use edit_story::EditStory;
+pub use edit_user_star_subscription::EditUserStarSubscription;
 pub use export_chat_invite_link::ExportChatInviteLink;
 pub use forward_message::ForwardMessage;
 pub use forward_messages::ForwardMessages;
+pub use get_available_gifts::GetAvailableGifts;
+pub use get_business_account_gifts::GetBusinessAccountGifts;
+pub use get_business_account_star_balance::GetBusinessAccountStarBalance;
 pub use get_business_connection::GetBusinessConnection;
 pub use get_chat::GetChat;
 pub use get_chat_administrators::GetChatAdministrators;
@@ -196,6 +243,7 @@ pub use get_my_default_administrator_rights::GetMyDefaultAdministratorRights;
 pub use get_my_description::GetMyDescription;
 pub use get_my_name::GetMyName;
 pub use get_my_short_description::GetMyShortDescription;
+pub use get_star_transactions::GetStarTransactions;
 pub use get_sticker_set::GetStickerSet;
 pub use get_updates::GetUpdates;
 pub use get_user_chat_boosts::GetUserChatBoosts;
@@ -204,23 +252,33 @@ pub use hide_general_forum_topic::HideGeneralForumTopic;
 pub use leave_chat::LeaveChat;
 pub use log_out::LogOut;
 pub use pin_chat_message::PinChatMessage;
+pub use post_story::PostStory;
 pub use promote_chat_member::PromoteChatMember;
+pub use raw::Raw;
+pub use read_business_message::ReadBusinessMessage;
+pub use refund_star_payment::RefundStarPayment;
+pub use remove_chat_verification::RemoveChatVerification;
+pub use remove_user_verification::RemoveUserVerification;
 pub use reopen_forum_topic::ReopenForumTopic;
 pub use reopen_general_forum_topic::ReopenGeneralForumTopic;
 pub use replace_sticker_in_set::ReplaceStickerInSet;
 pub use restrict_chat_member::RestrictChatMember;
 pub use revoke_chat_invite_link::RevokeChatInviteLink;
+pub use save_prepared_inline_message::SavePreparedInlineMessage;
 pub use send_animation::SendAnimation;
 pub use send_audio::SendAudio;
 pub use send_chat_action::SendChatAction;
+pub use send_checklist::SendChecklist;
 pub use send_contact::SendContact;
 pub use send_dice::SendDice;
 pub use send_document::SendDocument;
 pub use send_game::SendGame;
+pub use send_gift::SendGift;
 pub use send_invoice::SendInvoice;
 pub use send_location::SendLocation;
 pub use send_media_group::SendMediaGroup;
 pub use send_message::SendMessage;
+pub use send_paid_media::SendPaidMedia;
 pub use send_photo::SendPhoto;
 pub use send_poll::SendPoll;
 pub use send_sticker::SendSticker;
@@ -228,6 +286,10 @@ pub use send_venue::SendVenue;
 pub use send_video::SendVideo;
 pub use send_video_note::SendVideoNote;
 pub use send_voice::SendVoice;
+pub use set_business_account_bio::SetBusinessAccountBio;
+pub use set_business_account_name::SetBusinessAccountName;
+pub use set_business_account_profile_photo::SetBusinessAccountProfilePhoto;
+pub use set_business_account_username::SetBusinessAccountUsername;
 pub use set_chat_administrator_custom_title::SetChatAdministratorCustomTitle;
 pub use set_chat_description::SetChatDescription;
 pub use set_chat_menu_button::SetChatMenuButton;
@@ -249,8 +311,10 @@ pub use set_sticker_mask_position::SetStickerMaskPosition;
 pub use set_sticker_position_in_set::SetStickerPositionInSet;
 pub use set_sticker_set_thumbnail::SetStickerSetThumbnail;
 pub use set_sticker_set_title::SetStickerSetTitle;
+pub use set_user_emoji_status::SetUserEmojiStatus;
 pub use stop_message_live_location::StopMessageLiveLocation;
 pub use stop_poll::StopPoll;
+pub use transfer_business_account_stars::TransferBusinessAccountStars;
 pub use unban_chat_member::UnbanChatMember;
 pub use unban_chat_sender_chat::UnbanChatSenderChat;
 pub use unhide_general_forum_topic::UnhideGeneralForumTopic;
@@ -259,3 +323,5 @@ pub use unpin_all_forum_topic_messages::UnpinAllForumTopicMessages;
 pub use unpin_all_general_forum_topic_messages::UnpinAllGeneralForumTopicMessages;
 pub use unpin_chat_message::UnpinChatMessage;
 pub use upload_sticker_file::UploadStickerFile;
+pub use verify_chat::VerifyChat;
+pub use verify_user::VerifyUser;
@@ -5,6 +5,11 @@ use crate::{client::Bot, types::ChatIdKind};
 use serde::Serialize;
 use serde_with::skip_serializing_none;
 
+#[cfg(feature = "chrono")]
+use crate::utils::date_time;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+
 /// Use this method to ban a user in a group, a supergroup or a channel. In the case of supergroups and channels, the user will not be able to return to the chat on their own using invite links, etc., unless [`unbanned`](crate::methods::UnbanChatMember) first. The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#banchatmember>
@@ -85,6 +90,15 @@ impl BanChatMember {
     }
 }
 
+#[cfg(feature = "chrono")]
+impl BanChatMember {
+    /// Date when the user will be unbanned. If user is banned for more than 366 days or less than 30 seconds from the current time they are considered to be banned forever
+    #[must_use]
+    pub fn until_date_utc(self, val: DateTime<Utc>) -> Self {
+        self.until_date(date_time::to_timestamp(val))
+    }
+}
+
 impl TelegramMethod for BanChatMember {
     type Method = Self;
     type Return = bool;
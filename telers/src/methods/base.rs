@@ -1,31 +1,43 @@
 use crate::{
-    client::Bot,
-    types::{InputFile, InputMedia, InputSticker, ResponseParameters},
+    client::{Bot, ParseModeField, ParseModeFieldRef},
+    errors::SessionErrorKind,
+    types::{
+        InputFile, InputMedia, InputPaidMedia, InputProfilePhoto, InputSticker, InputStoryContent,
+        ResponseParameters,
+    },
 };
 
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// This object represents a request to Telegram API
 pub struct Request<'a, T>
 where
-    T: Serialize + ?Sized,
+    T: Serialize + Clone,
 {
     /// Telegram API method name
-    pub method_name: &'static str,
-    /// Telegram API method data
-    pub data: &'a T,
+    pub method_name: &'a str,
+    /// Telegram API method data.
+    /// It's borrowed from the method, or owned if the method is changed while building request,
+    /// for example, filled with default bot properties.
+    pub data: Cow<'a, T>,
     /// Files to send
     pub files: Option<Box<[&'a InputFile<'a>]>>,
 }
 
 impl<'a, T> Request<'a, T>
 where
-    T: Serialize + ?Sized,
+    T: Serialize + Clone,
 {
     #[must_use]
-    pub fn new(
-        method_name: &'static str,
-        data: &'a T,
+    pub fn new(method_name: &'a str, data: &'a T, files: Option<Box<[&'a InputFile<'a>]>>) -> Self {
+        Self::new_cow(method_name, Cow::Borrowed(data), files)
+    }
+
+    #[must_use]
+    pub fn new_cow(
+        method_name: &'a str,
+        data: Cow<'a, T>,
         files: Option<Box<[&'a InputFile<'a>]>>,
     ) -> Self {
         Self {
@@ -54,15 +66,28 @@ pub struct Response<T> {
 
 pub trait TelegramMethod {
     /// This type represents a method to Telegram API with data (params)
-    type Method: Serialize;
+    type Method: Serialize + Clone;
     /// This type represents a response from Telegram API, which is returned by the method
     type Return: DeserializeOwned;
 
     /// This method is called when a request is sent to Telegram API.
     /// It's need for preparing a request to Telegram API.
+    /// # Notes
+    /// Methods which support default bot properties fill unset fields by them here,
+    /// see [`DefaultBotProperties`] for more information.
+    ///
+    /// [`DefaultBotProperties`]: crate::client::DefaultBotProperties
     #[must_use]
     fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<Self::Method>;
 
+    /// This method is called before a request is sent to Telegram API.
+    /// It's need for checking that required fields, which can be filled by the bot, are specified.
+    /// # Errors
+    /// If the method misses a required field and the bot doesn't fill it
+    fn validate<Client>(&self, _bot: &Bot<Client>) -> Result<(), SessionErrorKind> {
+        Ok(())
+    }
+
     /// This method is called when a response is received from Telegram API.
     /// It's need for parsing a response from Telegram API.
     /// # Errors
@@ -107,6 +132,52 @@ pub(super) fn prepare_input_media<'a>(
     }
 }
 
+/// Get `parse_mode` field of the media caption, which is filled by default `parse_mode`
+pub(super) fn input_media_parse_mode_field<'a>(
+    input_media: &'a mut InputMedia<'_>,
+) -> ParseModeField<'a> {
+    match input_media {
+        InputMedia::Animation(inner) => {
+            ParseModeField::new(&mut inner.parse_mode, inner.caption_entities.is_some())
+        }
+        InputMedia::Audio(inner) => {
+            ParseModeField::new(&mut inner.parse_mode, inner.caption_entities.is_some())
+        }
+        InputMedia::Document(inner) => {
+            ParseModeField::new(&mut inner.parse_mode, inner.caption_entities.is_some())
+        }
+        InputMedia::Photo(inner) => {
+            ParseModeField::new(&mut inner.parse_mode, inner.caption_entities.is_some())
+        }
+        InputMedia::Video(inner) => {
+            ParseModeField::new(&mut inner.parse_mode, inner.caption_entities.is_some())
+        }
+    }
+}
+
+/// Get read-only `parse_mode` field of the media caption, which is filled by default `parse_mode`
+pub(super) fn input_media_parse_mode_field_ref<'a>(
+    input_media: &'a InputMedia<'_>,
+) -> ParseModeFieldRef<'a> {
+    match input_media {
+        InputMedia::Animation(inner) => {
+            ParseModeFieldRef::new(&inner.parse_mode, inner.caption_entities.is_some())
+        }
+        InputMedia::Audio(inner) => {
+            ParseModeFieldRef::new(&inner.parse_mode, inner.caption_entities.is_some())
+        }
+        InputMedia::Document(inner) => {
+            ParseModeFieldRef::new(&inner.parse_mode, inner.caption_entities.is_some())
+        }
+        InputMedia::Photo(inner) => {
+            ParseModeFieldRef::new(&inner.parse_mode, inner.caption_entities.is_some())
+        }
+        InputMedia::Video(inner) => {
+            ParseModeFieldRef::new(&inner.parse_mode, inner.caption_entities.is_some())
+        }
+    }
+}
+
 pub(super) fn prepare_input_media_group<'a>(
     files: &mut Vec<&'a InputFile<'a>>,
     input_media_group: &'a [InputMedia<'a>],
@@ -116,6 +187,60 @@ pub(super) fn prepare_input_media_group<'a>(
     }
 }
 
+pub(super) fn prepare_input_paid_media<'a>(
+    files: &mut Vec<&'a InputFile<'a>>,
+    input_paid_media: &'a InputPaidMedia<'a>,
+) {
+    match input_paid_media {
+        InputPaidMedia::Photo(inner) => {
+            prepare_file(files, &inner.media);
+        }
+        InputPaidMedia::Video(inner) => {
+            prepare_file(files, &inner.media);
+            if let Some(thumbnail) = &inner.thumbnail {
+                prepare_file(files, thumbnail);
+            }
+        }
+    }
+}
+
+pub(super) fn prepare_input_paid_media_group<'a>(
+    files: &mut Vec<&'a InputFile<'a>>,
+    input_paid_media_group: &'a [InputPaidMedia<'a>],
+) {
+    for input_paid_media in input_paid_media_group {
+        prepare_input_paid_media(files, input_paid_media);
+    }
+}
+
+pub(super) fn prepare_input_profile_photo<'a>(
+    files: &mut Vec<&'a InputFile<'a>>,
+    input_profile_photo: &'a InputProfilePhoto<'a>,
+) {
+    match input_profile_photo {
+        InputProfilePhoto::Static(inner) => {
+            prepare_file(files, &inner.photo);
+        }
+        InputProfilePhoto::Animated(inner) => {
+            prepare_file(files, &inner.animation);
+        }
+    }
+}
+
+pub(super) fn prepare_input_story_content<'a>(
+    files: &mut Vec<&'a InputFile<'a>>,
+    input_story_content: &'a InputStoryContent<'a>,
+) {
+    match input_story_content {
+        InputStoryContent::Photo(inner) => {
+            prepare_file(files, &inner.photo);
+        }
+        InputStoryContent::Video(inner) => {
+            prepare_file(files, &inner.video);
+        }
+    }
+}
+
 pub(super) fn prepare_input_sticker<'a>(
     files: &mut Vec<&'a InputFile<'a>>,
     input_sticker: &'a InputSticker<'a>,
@@ -0,0 +1,93 @@
+use super::base::{Request, TelegramMethod};
+
+use crate::{
+    client::{Bot, WithBusinessConnectionId},
+    errors::SessionErrorKind,
+};
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+use std::borrow::Cow;
+
+/// Converts a given regular gift to Telegram Stars. Requires the `can_convert_gifts_to_stars` business bot right.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#convertgifttostars>
+/// # Returns
+/// Returns `true` on success
+#[skip_serializing_none]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize)]
+pub struct ConvertGiftToStars {
+    /// Unique identifier of the business connection. Can be omitted if the bot is scoped to a business connection, see [`Bot::with_business_connection_id`](crate::client::Bot::with_business_connection_id).
+    pub business_connection_id: Option<String>,
+    /// Unique identifier of the regular gift that should be converted to Telegram Stars
+    pub owned_gift_id: String,
+}
+
+impl ConvertGiftToStars {
+    #[must_use]
+    pub fn new(owned_gift_id: impl Into<String>) -> Self {
+        Self {
+            business_connection_id: None,
+            owned_gift_id: owned_gift_id.into(),
+        }
+    }
+
+    #[must_use]
+    pub fn business_connection_id(self, val: impl Into<String>) -> Self {
+        Self {
+            business_connection_id: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn owned_gift_id(self, val: impl Into<String>) -> Self {
+        Self {
+            owned_gift_id: val.into(),
+            ..self
+        }
+    }
+}
+
+impl ConvertGiftToStars {
+    #[must_use]
+    pub fn business_connection_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            business_connection_id: val.map(Into::into),
+            ..self
+        }
+    }
+}
+
+impl WithBusinessConnectionId for ConvertGiftToStars {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
+impl TelegramMethod for ConvertGiftToStars {
+    type Method = Self;
+    type Return = bool;
+
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<'_, Self::Method> {
+        let mut data = Cow::Borrowed(self);
+
+        bot.apply_business_connection_id(&mut data);
+
+        Request::new_cow("convertGiftToStars", data, None)
+    }
+
+    fn validate<Client>(&self, bot: &Bot<Client>) -> Result<(), SessionErrorKind> {
+        bot.require_business_connection_id(self)
+    }
+}
+
+impl AsRef<ConvertGiftToStars> for ConvertGiftToStars {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
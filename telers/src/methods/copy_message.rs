@@ -1,7 +1,10 @@
 use super::base::{Request, TelegramMethod};
 
 use crate::{
-    client::Bot,
+    client::{
+        Bot, DefaultFields, DefaultFieldsRef, ParseModeField, ParseModeFieldRef,
+        WithDefaultProperties,
+    },
     types::{ChatIdKind, MessageEntity, MessageId, ReplyMarkup, ReplyParameters},
 };
 
@@ -30,6 +33,8 @@ pub struct CopyMessage {
     pub parse_mode: Option<String>,
     /// A JSON-serialized list of special entities that appear in the new caption, which can be specified instead of `parse_mode`
     pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Pass `true`, if the caption must be shown above the message media
+    pub show_caption_above_media: Option<bool>,
     /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages). Users will receive a notification with no sound
     pub disable_notification: Option<bool>,
     /// Protects the contents of the sent message from forwarding and saving
@@ -55,6 +60,7 @@ impl CopyMessage {
             caption: None,
             parse_mode: None,
             caption_entities: None,
+            show_caption_above_media: None,
             disable_notification: None,
             protect_content: None,
             reply_parameters: None,
@@ -138,6 +144,14 @@ impl CopyMessage {
         }
     }
 
+    #[must_use]
+    pub fn show_caption_above_media(self, val: bool) -> Self {
+        Self {
+            show_caption_above_media: Some(val),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn disable_notification(self, val: bool) -> Self {
         Self {
@@ -213,6 +227,14 @@ impl CopyMessage {
         }
     }
 
+    #[must_use]
+    pub fn show_caption_above_media_option(self, val: Option<bool>) -> Self {
+        Self {
+            show_caption_above_media: val,
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn disable_notification_option(self, val: Option<bool>) -> Self {
         Self {
@@ -246,12 +268,40 @@ impl CopyMessage {
     }
 }
 
+impl WithDefaultProperties for CopyMessage {
+    fn default_fields(&mut self) -> DefaultFields<'_> {
+        DefaultFields {
+            parse_mode: vec![ParseModeField::new(
+                &mut self.parse_mode,
+                self.caption_entities.is_some(),
+            )],
+            disable_notification: Some(&mut self.disable_notification),
+            protect_content: Some(&mut self.protect_content),
+            ..Default::default()
+        }
+    }
+
+    fn default_fields_ref(&self) -> DefaultFieldsRef<'_> {
+        DefaultFieldsRef {
+            parse_mode: vec![ParseModeFieldRef::new(
+                &self.parse_mode,
+                self.caption_entities.is_some(),
+            )],
+            disable_notification: Some(&self.disable_notification),
+            protect_content: Some(&self.protect_content),
+            ..Default::default()
+        }
+    }
+}
+
 impl TelegramMethod for CopyMessage {
     type Method = Self;
     type Return = MessageId;
 
-    fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
-        Request::new("copyMessage", self, None)
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<Self::Method> {
+        let data = bot.default_properties().apply(self);
+
+        Request::new_cow("copyMessage", data, None)
     }
 }
 
@@ -8,6 +8,11 @@ use crate::{
 use serde::Serialize;
 use serde_with::skip_serializing_none;
 
+#[cfg(feature = "chrono")]
+use crate::utils::date_time;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+
 /// Use this method to create an additional invite link for a chat. The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights. The link can be revoked using the method [`RevokeChatInviteLink`](crate::methods::RevokeChatInviteLink).
 /// # Documentation
 /// <https://core.telegram.org/bots/api#createchatinvitelink>
@@ -115,6 +120,15 @@ impl CreateChatInviteLink {
     }
 }
 
+#[cfg(feature = "chrono")]
+impl CreateChatInviteLink {
+    /// Point in time when the link will expire
+    #[must_use]
+    pub fn expire_date_utc(self, val: DateTime<Utc>) -> Self {
+        self.expire_date(date_time::to_timestamp(val))
+    }
+}
+
 impl TelegramMethod for CreateChatInviteLink {
     type Method = Self;
     type Return = ChatInviteLink;
@@ -0,0 +1,101 @@
+use super::base::{Request, TelegramMethod};
+
+use crate::{
+    client::{Bot, WithBusinessConnectionId},
+    errors::SessionErrorKind,
+};
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+use std::borrow::Cow;
+
+/// Delete messages on behalf of a business account. Requires the `can_delete_sent_messages` business bot right to delete messages sent by the bot itself, or the `can_delete_all_messages` business bot right to delete any message.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#deletebusinessmessages>
+/// # Returns
+/// Returns `true` on success
+#[skip_serializing_none]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize)]
+pub struct DeleteBusinessMessages {
+    /// Unique identifier of the business connection on behalf of which to delete the messages. Can be omitted if the bot is scoped to a business connection, see [`Bot::with_business_connection_id`](crate::client::Bot::with_business_connection_id).
+    pub business_connection_id: Option<String>,
+    /// A JSON-serialized list of 1-100 identifiers of messages to delete. All messages must be from the same chat.
+    pub message_ids: Vec<i64>,
+}
+
+impl DeleteBusinessMessages {
+    #[must_use]
+    pub fn new(message_ids: impl IntoIterator<Item = i64>) -> Self {
+        Self {
+            business_connection_id: None,
+            message_ids: message_ids.into_iter().collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn business_connection_id(self, val: impl Into<String>) -> Self {
+        Self {
+            business_connection_id: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn message_id(self, val: i64) -> Self {
+        Self {
+            message_ids: self.message_ids.into_iter().chain(Some(val)).collect(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn message_ids(self, val: impl IntoIterator<Item = i64>) -> Self {
+        Self {
+            message_ids: self.message_ids.into_iter().chain(val).collect(),
+            ..self
+        }
+    }
+}
+
+impl DeleteBusinessMessages {
+    #[must_use]
+    pub fn business_connection_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            business_connection_id: val.map(Into::into),
+            ..self
+        }
+    }
+}
+
+impl WithBusinessConnectionId for DeleteBusinessMessages {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
+impl TelegramMethod for DeleteBusinessMessages {
+    type Method = Self;
+    type Return = bool;
+
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<'_, Self::Method> {
+        let mut data = Cow::Borrowed(self);
+
+        bot.apply_business_connection_id(&mut data);
+
+        Request::new_cow("deleteBusinessMessages", data, None)
+    }
+
+    fn validate<Client>(&self, bot: &Bot<Client>) -> Result<(), SessionErrorKind> {
+        bot.require_business_connection_id(self)
+    }
+}
+
+impl AsRef<DeleteBusinessMessages> for DeleteBusinessMessages {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
@@ -0,0 +1,93 @@
+use super::base::{Request, TelegramMethod};
+
+use crate::{
+    client::{Bot, WithBusinessConnectionId},
+    errors::SessionErrorKind,
+};
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+use std::borrow::Cow;
+
+/// Deletes a story previously posted by the bot on behalf of a managed business account. Requires the `can_manage_stories` business bot right.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#deletestory>
+/// # Returns
+/// Returns `true` on success
+#[skip_serializing_none]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize)]
+pub struct DeleteStory {
+    /// Unique identifier of the business connection. Can be omitted if the bot is scoped to a business connection, see [`Bot::with_business_connection_id`](crate::client::Bot::with_business_connection_id).
+    pub business_connection_id: Option<String>,
+    /// Unique identifier of the story to delete
+    pub story_id: i64,
+}
+
+impl DeleteStory {
+    #[must_use]
+    pub fn new(story_id: i64) -> Self {
+        Self {
+            business_connection_id: None,
+            story_id,
+        }
+    }
+
+    #[must_use]
+    pub fn business_connection_id(self, val: impl Into<String>) -> Self {
+        Self {
+            business_connection_id: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn story_id(self, val: i64) -> Self {
+        Self {
+            story_id: val,
+            ..self
+        }
+    }
+}
+
+impl DeleteStory {
+    #[must_use]
+    pub fn business_connection_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            business_connection_id: val.map(Into::into),
+            ..self
+        }
+    }
+}
+
+impl WithBusinessConnectionId for DeleteStory {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
+impl TelegramMethod for DeleteStory {
+    type Method = Self;
+    type Return = bool;
+
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<'_, Self::Method> {
+        let mut data = Cow::Borrowed(self);
+
+        bot.apply_business_connection_id(&mut data);
+
+        Request::new_cow("deleteStory", data, None)
+    }
+
+    fn validate<Client>(&self, bot: &Bot<Client>) -> Result<(), SessionErrorKind> {
+        bot.require_business_connection_id(self)
+    }
+}
+
+impl AsRef<DeleteStory> for DeleteStory {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
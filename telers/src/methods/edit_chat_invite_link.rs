@@ -8,6 +8,11 @@ use crate::{
 use serde::Serialize;
 use serde_with::skip_serializing_none;
 
+#[cfg(feature = "chrono")]
+use crate::utils::date_time;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+
 /// Use this method to edit a non-primary invite link created by the bot. The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#editchatinvitelink>
@@ -126,6 +131,15 @@ impl EditChatInviteLink {
     }
 }
 
+#[cfg(feature = "chrono")]
+impl EditChatInviteLink {
+    /// Point in time when the link will expire
+    #[must_use]
+    pub fn expire_date_utc(self, val: DateTime<Utc>) -> Self {
+        self.expire_date(date_time::to_timestamp(val))
+    }
+}
+
 impl TelegramMethod for EditChatInviteLink {
     type Method = Self;
     type Return = ChatInviteLink;
@@ -1,7 +1,10 @@
 use super::base::{Request, TelegramMethod};
 
 use crate::{
-    client::Bot,
+    client::{
+        Bot, DefaultFields, DefaultFieldsRef, ParseModeField, ParseModeFieldRef,
+        WithBusinessConnectionId, WithDefaultProperties,
+    },
     types::{ChatIdKind, InlineKeyboardMarkup, MessageEntity, MessageOrTrue},
 };
 
@@ -16,6 +19,8 @@ use serde_with::skip_serializing_none;
 #[skip_serializing_none]
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize)]
 pub struct EditMessageCaption {
+    /// Unique identifier of the business connection on behalf of which the message to be edited was sent
+    pub business_connection_id: Option<String>,
     /// Required if `inline_message_id` is not specified. Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
     pub chat_id: Option<ChatIdKind>,
     /// Required if `inline_message_id` is not specified. Identifier of the message to edit
@@ -28,6 +33,8 @@ pub struct EditMessageCaption {
     pub parse_mode: Option<String>,
     /// A JSON-serialized list of special entities that appear in the caption, which can be specified instead of `parse_mode`
     pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Pass `true`, if the caption must be shown above the message media
+    pub show_caption_above_media: Option<bool>,
     /// A JSON-serialized object for an [inline keyboard](https://core.telegram.org/bots/features#inline-keyboards).
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
@@ -36,16 +43,26 @@ impl EditMessageCaption {
     #[must_use]
     pub fn new(caption: impl Into<String>) -> Self {
         Self {
+            business_connection_id: None,
             chat_id: None,
             message_id: None,
             inline_message_id: None,
             caption: caption.into(),
             parse_mode: None,
             caption_entities: None,
+            show_caption_above_media: None,
             reply_markup: None,
         }
     }
 
+    #[must_use]
+    pub fn business_connection_id(self, val: impl Into<String>) -> Self {
+        Self {
+            business_connection_id: Some(val.into()),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn chat_id(self, val: impl Into<ChatIdKind>) -> Self {
         Self {
@@ -114,6 +131,14 @@ impl EditMessageCaption {
         }
     }
 
+    #[must_use]
+    pub fn show_caption_above_media(self, val: bool) -> Self {
+        Self {
+            show_caption_above_media: Some(val),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn reply_markup(self, val: impl Into<InlineKeyboardMarkup>) -> Self {
         Self {
@@ -124,6 +149,14 @@ impl EditMessageCaption {
 }
 
 impl EditMessageCaption {
+    #[must_use]
+    pub fn business_connection_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            business_connection_id: val.map(Into::into),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn chat_id_option(self, val: Option<impl Into<ChatIdKind>>) -> Self {
         Self {
@@ -173,6 +206,14 @@ impl EditMessageCaption {
         }
     }
 
+    #[must_use]
+    pub fn show_caption_above_media_option(self, val: Option<bool>) -> Self {
+        Self {
+            show_caption_above_media: val,
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn reply_markup_option(self, val: Option<impl Into<InlineKeyboardMarkup>>) -> Self {
         Self {
@@ -182,12 +223,48 @@ impl EditMessageCaption {
     }
 }
 
+impl WithDefaultProperties for EditMessageCaption {
+    fn default_fields(&mut self) -> DefaultFields<'_> {
+        DefaultFields {
+            parse_mode: vec![ParseModeField::new(
+                &mut self.parse_mode,
+                self.caption_entities.is_some(),
+            )],
+            ..Default::default()
+        }
+    }
+
+    fn default_fields_ref(&self) -> DefaultFieldsRef<'_> {
+        DefaultFieldsRef {
+            parse_mode: vec![ParseModeFieldRef::new(
+                &self.parse_mode,
+                self.caption_entities.is_some(),
+            )],
+            ..Default::default()
+        }
+    }
+}
+
+impl WithBusinessConnectionId for EditMessageCaption {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
 impl TelegramMethod for EditMessageCaption {
     type Method = Self;
     type Return = MessageOrTrue;
 
-    fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
-        Request::new("editMessageCaption", self, None)
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<Self::Method> {
+        let mut data = bot.default_properties().apply(self);
+
+        bot.apply_business_connection_id(&mut data);
+
+        Request::new_cow("editMessageCaption", data, None)
     }
 }
 
@@ -1,12 +1,13 @@
 use super::base::{Request, TelegramMethod};
 
 use crate::{
-    client::Bot,
+    client::{Bot, WithBusinessConnectionId},
     types::{ChatIdKind, InlineKeyboardMarkup, MessageOrTrue},
 };
 
 use serde::Serialize;
 use serde_with::skip_serializing_none;
+use std::borrow::Cow;
 
 /// Use this method to edit live location messages. A location can be edited until its `live_period` expires or editing is explicitly disabled by a call to [stopMessageLiveLocation](crate::methods::StopMessageLiveLocation).
 /// # Documentation
@@ -17,6 +18,8 @@ use serde_with::skip_serializing_none;
 #[skip_serializing_none]
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct EditMessageLiveLocation {
+    /// Unique identifier of the business connection on behalf of which the message to be edited was sent
+    pub business_connection_id: Option<String>,
     /// Required if `inline_message_id` is not specified. Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
     pub chat_id: Option<ChatIdKind>,
     /// Required if `inline_message_id` is not specified. Identifier of the message to edit
@@ -41,6 +44,7 @@ impl EditMessageLiveLocation {
     #[must_use]
     pub fn new(longitude: f64, latitude: f64) -> Self {
         Self {
+            business_connection_id: None,
             chat_id: None,
             message_id: None,
             inline_message_id: None,
@@ -53,6 +57,14 @@ impl EditMessageLiveLocation {
         }
     }
 
+    #[must_use]
+    pub fn business_connection_id(self, val: impl Into<String>) -> Self {
+        Self {
+            business_connection_id: Some(val.into()),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn chat_id(self, val: impl Into<ChatIdKind>) -> Self {
         Self {
@@ -127,6 +139,14 @@ impl EditMessageLiveLocation {
 }
 
 impl EditMessageLiveLocation {
+    #[must_use]
+    pub fn business_connection_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            business_connection_id: val.map(Into::into),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn chat_id_option(self, val: Option<impl Into<ChatIdKind>>) -> Self {
         Self {
@@ -184,12 +204,26 @@ impl EditMessageLiveLocation {
     }
 }
 
+impl WithBusinessConnectionId for EditMessageLiveLocation {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
 impl TelegramMethod for EditMessageLiveLocation {
     type Method = Self;
     type Return = MessageOrTrue;
 
-    fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
-        Request::new("editMessageLiveLocation", self, None)
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<Self::Method> {
+        let mut data = Cow::Borrowed(self);
+
+        bot.apply_business_connection_id(&mut data);
+
+        Request::new_cow("editMessageLiveLocation", data, None)
     }
 }
 
@@ -1,7 +1,12 @@
-use super::base::{prepare_input_media, Request, TelegramMethod};
+use super::base::{
+    input_media_parse_mode_field, input_media_parse_mode_field_ref, prepare_input_media, Request,
+    TelegramMethod,
+};
 
 use crate::{
-    client::Bot,
+    client::{
+        Bot, DefaultFields, DefaultFieldsRef, WithBusinessConnectionId, WithDefaultProperties,
+    },
     types::{ChatIdKind, InlineKeyboardMarkup, InputMedia, MessageOrTrue},
 };
 
@@ -16,6 +21,8 @@ use serde_with::skip_serializing_none;
 #[skip_serializing_none]
 #[derive(Debug, Clone, Hash, PartialEq, Serialize)]
 pub struct EditMessageMedia<'a> {
+    /// Unique identifier of the business connection on behalf of which the message to be edited was sent
+    pub business_connection_id: Option<String>,
     /// Required if `inline_message_id` is not specified. Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
     pub chat_id: Option<ChatIdKind>,
     /// Required if `inline_message_id` is not specified. Identifier of the message to edit
@@ -32,6 +39,7 @@ impl<'a> EditMessageMedia<'a> {
     #[must_use]
     pub fn new(media: impl Into<InputMedia<'a>>) -> Self {
         Self {
+            business_connection_id: None,
             chat_id: None,
             message_id: None,
             inline_message_id: None,
@@ -40,6 +48,14 @@ impl<'a> EditMessageMedia<'a> {
         }
     }
 
+    #[must_use]
+    pub fn business_connection_id(self, val: impl Into<String>) -> Self {
+        Self {
+            business_connection_id: Some(val.into()),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn chat_id(self, val: impl Into<ChatIdKind>) -> Self {
         Self {
@@ -82,6 +98,14 @@ impl<'a> EditMessageMedia<'a> {
 }
 
 impl<'a> EditMessageMedia<'a> {
+    #[must_use]
+    pub fn business_connection_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            business_connection_id: val.map(Into::into),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn chat_id_option(self, val: Option<impl Into<ChatIdKind>>) -> Self {
         Self {
@@ -115,15 +139,45 @@ impl<'a> EditMessageMedia<'a> {
     }
 }
 
+impl WithDefaultProperties for EditMessageMedia<'_> {
+    fn default_fields(&mut self) -> DefaultFields<'_> {
+        DefaultFields {
+            parse_mode: vec![input_media_parse_mode_field(&mut self.media)],
+            ..Default::default()
+        }
+    }
+
+    fn default_fields_ref(&self) -> DefaultFieldsRef<'_> {
+        DefaultFieldsRef {
+            parse_mode: vec![input_media_parse_mode_field_ref(&self.media)],
+            ..Default::default()
+        }
+    }
+}
+
+impl WithBusinessConnectionId for EditMessageMedia<'_> {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
 impl TelegramMethod for EditMessageMedia<'_> {
     type Method = Self;
     type Return = MessageOrTrue;
 
-    fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<Self::Method> {
+        let mut data = bot.default_properties().apply(self);
+
+        bot.apply_business_connection_id(&mut data);
+
         let mut files = vec![];
         prepare_input_media(&mut files, &self.media);
 
-        Request::new("editMessageMedia", self, Some(files.into()))
+        Request::new_cow("editMessageMedia", data, Some(files.into()))
     }
 }
 
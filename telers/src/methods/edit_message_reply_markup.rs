@@ -1,12 +1,13 @@
 use super::base::{Request, TelegramMethod};
 
 use crate::{
-    client::Bot,
+    client::{Bot, WithBusinessConnectionId},
     types::{ChatIdKind, InlineKeyboardMarkup, MessageOrTrue},
 };
 
 use serde::Serialize;
 use serde_with::skip_serializing_none;
+use std::borrow::Cow;
 
 /// Use this method to edit only the reply markup of messages.
 /// # Documentation
@@ -16,6 +17,8 @@ use serde_with::skip_serializing_none;
 #[skip_serializing_none]
 #[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Serialize)]
 pub struct EditMessageReplyMarkup {
+    /// Unique identifier of the business connection on behalf of which the message to be edited was sent
+    pub business_connection_id: Option<String>,
     /// Required if `inline_message_id` is not specified. Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
     pub chat_id: Option<ChatIdKind>,
     /// Required if `inline_message_id` is not specified. Identifier of the message to edit
@@ -32,6 +35,14 @@ impl EditMessageReplyMarkup {
         Self::default()
     }
 
+    #[must_use]
+    pub fn business_connection_id(self, val: impl Into<String>) -> Self {
+        Self {
+            business_connection_id: Some(val.into()),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn chat_id(self, val: impl Into<ChatIdKind>) -> Self {
         Self {
@@ -66,6 +77,14 @@ impl EditMessageReplyMarkup {
 }
 
 impl EditMessageReplyMarkup {
+    #[must_use]
+    pub fn business_connection_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            business_connection_id: val.map(Into::into),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn chat_id_option(self, val: Option<impl Into<ChatIdKind>>) -> Self {
         Self {
@@ -99,12 +118,26 @@ impl EditMessageReplyMarkup {
     }
 }
 
+impl WithBusinessConnectionId for EditMessageReplyMarkup {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
 impl TelegramMethod for EditMessageReplyMarkup {
     type Method = Self;
     type Return = MessageOrTrue;
 
-    fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
-        Request::new("editMessageReplyMarkup", self, None)
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<Self::Method> {
+        let mut data = Cow::Borrowed(self);
+
+        bot.apply_business_connection_id(&mut data);
+
+        Request::new_cow("editMessageReplyMarkup", data, None)
     }
 }
 
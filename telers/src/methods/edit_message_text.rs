@@ -1,7 +1,10 @@
 use super::base::{Request, TelegramMethod};
 
 use crate::{
-    client::Bot,
+    client::{
+        Bot, DefaultFields, DefaultFieldsRef, ParseModeField, ParseModeFieldRef,
+        WithBusinessConnectionId, WithDefaultProperties,
+    },
     types::{ChatIdKind, InlineKeyboardMarkup, LinkPreviewOptions, MessageEntity, MessageOrTrue},
 };
 
@@ -16,6 +19,8 @@ use serde_with::skip_serializing_none;
 #[skip_serializing_none]
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize)]
 pub struct EditMessageText {
+    /// Unique identifier of the business connection on behalf of which the message to be edited was sent
+    pub business_connection_id: Option<String>,
     /// Required if `inline_message_id` is not specified. Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
     pub chat_id: Option<ChatIdKind>,
     /// Required if `inline_message_id` is not specified. Identifier of the message to edit
@@ -38,6 +43,7 @@ impl EditMessageText {
     #[must_use]
     pub fn new(text: impl Into<String>) -> Self {
         Self {
+            business_connection_id: None,
             chat_id: None,
             message_id: None,
             inline_message_id: None,
@@ -49,6 +55,14 @@ impl EditMessageText {
         }
     }
 
+    #[must_use]
+    pub fn business_connection_id(self, val: impl Into<String>) -> Self {
+        Self {
+            business_connection_id: Some(val.into()),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn chat_id(self, val: impl Into<ChatIdKind>) -> Self {
         Self {
@@ -121,6 +135,14 @@ impl EditMessageText {
 }
 
 impl EditMessageText {
+    #[must_use]
+    pub fn business_connection_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            business_connection_id: val.map(Into::into),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn chat_id_option(self, val: Option<impl Into<ChatIdKind>>) -> Self {
         Self {
@@ -184,12 +206,50 @@ impl EditMessageText {
     }
 }
 
+impl WithDefaultProperties for EditMessageText {
+    fn default_fields(&mut self) -> DefaultFields<'_> {
+        DefaultFields {
+            parse_mode: vec![ParseModeField::new(
+                &mut self.parse_mode,
+                self.entities.is_some(),
+            )],
+            link_preview_options: Some(&mut self.link_preview_options),
+            ..Default::default()
+        }
+    }
+
+    fn default_fields_ref(&self) -> DefaultFieldsRef<'_> {
+        DefaultFieldsRef {
+            parse_mode: vec![ParseModeFieldRef::new(
+                &self.parse_mode,
+                self.entities.is_some(),
+            )],
+            link_preview_options: Some(&self.link_preview_options),
+            ..Default::default()
+        }
+    }
+}
+
+impl WithBusinessConnectionId for EditMessageText {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
 impl TelegramMethod for EditMessageText {
     type Method = Self;
     type Return = MessageOrTrue;
 
-    fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
-        Request::new("editMessageText", self, None)
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<Self::Method> {
+        let mut data = bot.default_properties().apply(self);
+
+        bot.apply_business_connection_id(&mut data);
+
+        Request::new_cow("editMessageText", data, None)
     }
 }
 
@@ -1,7 +1,7 @@
 use super::base::{Request, TelegramMethod};
 
 use crate::{
-    client::Bot,
+    client::{Bot, DefaultFields, DefaultFieldsRef, WithDefaultProperties},
     types::{ChatIdKind, MessageId},
 };
 
@@ -130,12 +130,32 @@ impl ForwardMessages {
     }
 }
 
+impl WithDefaultProperties for ForwardMessages {
+    fn default_fields(&mut self) -> DefaultFields<'_> {
+        DefaultFields {
+            disable_notification: Some(&mut self.disable_notification),
+            protect_content: Some(&mut self.protect_content),
+            ..Default::default()
+        }
+    }
+
+    fn default_fields_ref(&self) -> DefaultFieldsRef<'_> {
+        DefaultFieldsRef {
+            disable_notification: Some(&self.disable_notification),
+            protect_content: Some(&self.protect_content),
+            ..Default::default()
+        }
+    }
+}
+
 impl TelegramMethod for ForwardMessages {
     type Method = Self;
     type Return = Vec<MessageId>;
 
-    fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
-        Request::new("forwardMessages", self, None)
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<Self::Method> {
+        let data = bot.default_properties().apply(self);
+
+        Request::new_cow("forwardMessages", data, None)
     }
 }
 
@@ -0,0 +1,79 @@
+use super::base::{Request, TelegramMethod};
+
+use crate::{
+    client::{Bot, WithBusinessConnectionId},
+    errors::SessionErrorKind,
+    types::StarAmount,
+};
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+use std::borrow::Cow;
+
+/// Returns the amount of Telegram Stars owned by a managed business account. Requires the `can_view_gifts_and_stars` business bot right.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#getbusinessaccountstarbalance>
+/// # Returns
+/// Returns [`StarAmount`] on success
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Serialize)]
+pub struct GetBusinessAccountStarBalance {
+    /// Unique identifier of the business connection. Can be omitted if the bot is scoped to a business connection, see [`Bot::with_business_connection_id`](crate::client::Bot::with_business_connection_id).
+    pub business_connection_id: Option<String>,
+}
+
+impl GetBusinessAccountStarBalance {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn business_connection_id(self, val: impl Into<String>) -> Self {
+        Self {
+            business_connection_id: Some(val.into()),
+        }
+    }
+}
+
+impl GetBusinessAccountStarBalance {
+    #[must_use]
+    pub fn business_connection_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            business_connection_id: val.map(Into::into),
+        }
+    }
+}
+
+impl WithBusinessConnectionId for GetBusinessAccountStarBalance {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
+impl TelegramMethod for GetBusinessAccountStarBalance {
+    type Method = Self;
+    type Return = StarAmount;
+
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<'_, Self::Method> {
+        let mut data = Cow::Borrowed(self);
+
+        bot.apply_business_connection_id(&mut data);
+
+        Request::new_cow("getBusinessAccountStarBalance", data, None)
+    }
+
+    fn validate<Client>(&self, bot: &Bot<Client>) -> Result<(), SessionErrorKind> {
+        bot.require_business_connection_id(self)
+    }
+}
+
+impl AsRef<GetBusinessAccountStarBalance> for GetBusinessAccountStarBalance {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
@@ -2,7 +2,7 @@ use super::base::{Request, TelegramMethod};
 
 use crate::{
     client::Bot,
-    types::{Chat, ChatIdKind},
+    types::{ChatFullInfo, ChatIdKind},
 };
 
 use serde::Serialize;
@@ -11,7 +11,7 @@ use serde::Serialize;
 /// # Documentation
 /// <https://core.telegram.org/bots/api#getchat>
 /// # Returns
-/// Returns a [`Chat`] object on success
+/// Returns a [`ChatFullInfo`] object on success
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize)]
 pub struct GetChat {
     /// Unique identifier for the target chat or username of the target supergroup or channel (in the format `@channelusername`)
@@ -36,7 +36,7 @@ impl GetChat {
 
 impl TelegramMethod for GetChat {
     type Method = Self;
-    type Return = Chat;
+    type Return = ChatFullInfo;
 
     fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
         Request::new("getChat", self, None)
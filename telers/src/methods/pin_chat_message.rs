@@ -1,6 +1,9 @@
 use super::base::{Request, TelegramMethod};
 
-use crate::{client::Bot, types::ChatIdKind};
+use crate::{
+    client::{Bot, DefaultFields, DefaultFieldsRef, WithDefaultProperties},
+    types::ChatIdKind,
+};
 
 use serde::Serialize;
 use serde_with::skip_serializing_none;
@@ -66,12 +69,30 @@ impl PinChatMessage {
     }
 }
 
+impl WithDefaultProperties for PinChatMessage {
+    fn default_fields(&mut self) -> DefaultFields<'_> {
+        DefaultFields {
+            disable_notification: Some(&mut self.disable_notification),
+            ..Default::default()
+        }
+    }
+
+    fn default_fields_ref(&self) -> DefaultFieldsRef<'_> {
+        DefaultFieldsRef {
+            disable_notification: Some(&self.disable_notification),
+            ..Default::default()
+        }
+    }
+}
+
 impl TelegramMethod for PinChatMessage {
     type Method = Self;
     type Return = bool;
 
-    fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
-        Request::new("pinChatMessage", self, None)
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<Self::Method> {
+        let data = bot.default_properties().apply(self);
+
+        Request::new_cow("pinChatMessage", data, None)
     }
 }
 
@@ -0,0 +1,179 @@
+use super::base::{prepare_file, Request, TelegramMethod};
+
+use crate::{client::Bot, types::InputFile};
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::{borrow::Cow, fmt, marker::PhantomData};
+
+/// Use this method to call arbitrary Telegram Bot API method, for example, a method that isn't supported by the library yet.
+/// # Notes
+/// - `T` is a type of the method payload, it can be any serializable type, by default it's [`serde_json::Value`].
+/// - `R` is a type of the method result, it can be any deserializable type, by default it's [`serde_json::Value`].
+/// - Files to upload must be passed by [`Raw::file`] or [`Raw::files`] and referenced in the payload,
+///   [`InputFile`] is serialized to `attach://{id}` string for this. Files which don't require uploading
+///   (file IDs and URLs) can be passed only in the payload.
+/// # Examples
+/// ```rust
+/// use serde_json::json;
+/// use telers::{methods::Raw, types::InputFile, Bot};
+///
+/// async fn call_method(bot: Bot) -> Result<(), Box<dyn std::error::Error>> {
+///     // Method without payload with a caller-chosen result type
+///     let result: bool = bot.send(Raw::new("close", json!({}))).await?;
+///
+///     // Method with a file to upload
+///     let photo = InputFile::fs("photo.jpg");
+///     let message: serde_json::Value = bot
+///         .send(Raw::new("sendPhoto", json!({"chat_id": 1, "photo": &photo})).file(photo))
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+pub struct Raw<'a, T = Value, R = Value> {
+    /// Telegram Bot API method name, for example, `sendMessage`
+    pub method_name: Cow<'a, str>,
+    /// Method payload
+    pub data: T,
+    /// Files to upload
+    pub files: Vec<InputFile<'a>>,
+    result: PhantomData<fn() -> R>,
+}
+
+impl<'a, T, R> Raw<'a, T, R> {
+    #[must_use]
+    pub fn new(method_name: impl Into<Cow<'a, str>>, data: T) -> Self {
+        Self {
+            method_name: method_name.into(),
+            data,
+            files: vec![],
+            result: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub fn method_name(self, val: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            method_name: val.into(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn data(self, val: T) -> Self {
+        Self { data: val, ..self }
+    }
+
+    #[must_use]
+    pub fn file(self, val: impl Into<InputFile<'a>>) -> Self {
+        Self {
+            files: self.files.into_iter().chain(Some(val.into())).collect(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn files<F, I>(self, val: I) -> Self
+    where
+        F: Into<InputFile<'a>>,
+        I: IntoIterator<Item = F>,
+    {
+        Self {
+            files: self
+                .files
+                .into_iter()
+                .chain(val.into_iter().map(Into::into))
+                .collect(),
+            ..self
+        }
+    }
+}
+
+impl<T, R> fmt::Debug for Raw<'_, T, R>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Raw")
+            .field("method_name", &self.method_name)
+            .field("data", &self.data)
+            .field("files", &self.files)
+            .finish()
+    }
+}
+
+impl<T, R> Clone for Raw<'_, T, R>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            method_name: self.method_name.clone(),
+            data: self.data.clone(),
+            files: self.files.clone(),
+            result: PhantomData,
+        }
+    }
+}
+
+impl<T, R> TelegramMethod for Raw<'_, T, R>
+where
+    T: Serialize + Clone,
+    R: DeserializeOwned,
+{
+    type Method = T;
+    type Return = R;
+
+    fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<'_, Self::Method> {
+        let mut files = vec![];
+        for file in &self.files {
+            prepare_file(&mut files, file);
+        }
+
+        Request::new(&self.method_name, &self.data, Some(files.into()))
+    }
+}
+
+impl<'a, T, R> AsRef<Raw<'a, T, R>> for Raw<'a, T, R> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Reqwest;
+
+    use serde_json::json;
+
+    #[test]
+    fn test_build_request() {
+        let bot = Bot::<Reqwest>::default();
+
+        let photo = InputFile::buffered(b"photo".to_vec());
+        let method = Raw::<_, bool>::new(
+            "sendPhoto",
+            json!({"chat_id": 1, "photo": &photo, "caption": "test"}),
+        )
+        .file(photo.clone())
+        .file(InputFile::id("file_id"));
+
+        let request = method.build_request(&bot);
+        assert_eq!(request.method_name, "sendPhoto");
+        assert_eq!(request.data["photo"], photo.str_to_file());
+        // Only files which require uploading are passed to the request files
+        assert_eq!(request.files.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_build_response() {
+        let method = Raw::<_, Vec<i64>>::new("getNumbers", json!({}));
+
+        let response = method
+            .build_response(r#"{"ok":true,"result":[1,2,3]}"#)
+            .unwrap();
+        assert_eq!(response.result, Some(vec![1, 2, 3]));
+    }
+}
@@ -0,0 +1,104 @@
+use super::base::{Request, TelegramMethod};
+
+use crate::{
+    client::{Bot, WithBusinessConnectionId},
+    errors::SessionErrorKind,
+};
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+use std::borrow::Cow;
+
+/// Marks incoming message as read on behalf of a business account. Requires the `can_read_messages` business bot right.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#readbusinessmessage>
+/// # Returns
+/// Returns `true` on success
+#[skip_serializing_none]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize)]
+pub struct ReadBusinessMessage {
+    /// Unique identifier of the business connection on behalf of which to read the message. Can be omitted if the bot is scoped to a business connection, see [`Bot::with_business_connection_id`](crate::client::Bot::with_business_connection_id).
+    pub business_connection_id: Option<String>,
+    /// Unique identifier of the chat in which the message was received. The chat must have been active in the last 24 hours.
+    pub chat_id: i64,
+    /// Unique identifier of the message to mark as read
+    pub message_id: i64,
+}
+
+impl ReadBusinessMessage {
+    #[must_use]
+    pub fn new(chat_id: i64, message_id: i64) -> Self {
+        Self {
+            business_connection_id: None,
+            chat_id,
+            message_id,
+        }
+    }
+
+    #[must_use]
+    pub fn business_connection_id(self, val: impl Into<String>) -> Self {
+        Self {
+            business_connection_id: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn chat_id(self, val: i64) -> Self {
+        Self {
+            chat_id: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn message_id(self, val: i64) -> Self {
+        Self {
+            message_id: val,
+            ..self
+        }
+    }
+}
+
+impl ReadBusinessMessage {
+    #[must_use]
+    pub fn business_connection_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            business_connection_id: val.map(Into::into),
+            ..self
+        }
+    }
+}
+
+impl WithBusinessConnectionId for ReadBusinessMessage {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
+impl TelegramMethod for ReadBusinessMessage {
+    type Method = Self;
+    type Return = bool;
+
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<'_, Self::Method> {
+        let mut data = Cow::Borrowed(self);
+
+        bot.apply_business_connection_id(&mut data);
+
+        Request::new_cow("readBusinessMessage", data, None)
+    }
+
+    fn validate<Client>(&self, bot: &Bot<Client>) -> Result<(), SessionErrorKind> {
+        bot.require_business_connection_id(self)
+    }
+}
+
+impl AsRef<ReadBusinessMessage> for ReadBusinessMessage {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
@@ -8,6 +8,11 @@ use crate::{
 use serde::Serialize;
 use serde_with::skip_serializing_none;
 
+#[cfg(feature = "chrono")]
+use crate::utils::date_time;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+
 /// Use this method to restrict a user in a supergroup. The bot must be an administrator in the supergroup for this to work and must have the appropriate administrator rights. Pass `true` for all permissions to lift restrictions from a user.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#restrictchatmember>
@@ -99,6 +104,15 @@ impl RestrictChatMember {
     }
 }
 
+#[cfg(feature = "chrono")]
+impl RestrictChatMember {
+    /// Date when restrictions will be lifted for the user. If user is restricted for more than 366 days or less than 30 seconds from the current time, they are considered to be restricted forever
+    #[must_use]
+    pub fn until_date_utc(self, val: DateTime<Utc>) -> Self {
+        self.until_date(date_time::to_timestamp(val))
+    }
+}
+
 impl TelegramMethod for RestrictChatMember {
     type Method = Self;
     type Return = bool;
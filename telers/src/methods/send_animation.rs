@@ -1,7 +1,10 @@
 use super::base::{prepare_file, Request, TelegramMethod};
 
 use crate::{
-    client::Bot,
+    client::{
+        Bot, DefaultFields, DefaultFieldsRef, ParseModeField, ParseModeFieldRef,
+        WithBusinessConnectionId, WithDefaultProperties,
+    },
     types::{ChatIdKind, InputFile, Message, MessageEntity, ReplyMarkup, ReplyParameters},
 };
 
@@ -38,6 +41,8 @@ pub struct SendAnimation<'a> {
     pub parse_mode: Option<String>,
     /// A JSON-serialized list of special entities that appear in the caption, which can be specified instead of `parse_mode`
     pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Pass `true`, if the caption must be shown above the message media
+    pub show_caption_above_media: Option<bool>,
     /// Pass `true` if the animation needs to be covered with a spoiler animation
     pub has_spoiler: Option<bool>,
     /// Pass `true`, if the uploaded animation is suitable for streaming
@@ -46,6 +51,8 @@ pub struct SendAnimation<'a> {
     pub disable_notification: Option<bool>,
     /// Protects the contents of the sent message from forwarding and saving
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    pub message_effect_id: Option<String>,
     /// Description of the message to reply to
     pub reply_parameters: Option<ReplyParameters>,
     /// Additional interface options. A JSON-serialized object for an [inline keyboard](https://core.telegram.org/bots/features#inline-keyboards), [custom reply keyboard](https://core.telegram.org/bots/features#keyboards), instructions to remove reply keyboard or to force a reply from the user.
@@ -67,10 +74,12 @@ impl<'a> SendAnimation<'a> {
             caption: None,
             parse_mode: None,
             caption_entities: None,
+            show_caption_above_media: None,
             has_spoiler: None,
             supports_streaming: None,
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_parameters: None,
             reply_markup: None,
         }
@@ -184,6 +193,14 @@ impl<'a> SendAnimation<'a> {
         }
     }
 
+    #[must_use]
+    pub fn show_caption_above_media(self, val: bool) -> Self {
+        Self {
+            show_caption_above_media: Some(val),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn has_spoiler(self, val: bool) -> Self {
         Self {
@@ -216,6 +233,14 @@ impl<'a> SendAnimation<'a> {
         }
     }
 
+    #[must_use]
+    pub fn message_effect_id(self, val: impl Into<String>) -> Self {
+        Self {
+            message_effect_id: Some(val.into()),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn reply_parameters(self, val: ReplyParameters) -> Self {
         Self {
@@ -312,6 +337,14 @@ impl<'a> SendAnimation<'a> {
         }
     }
 
+    #[must_use]
+    pub fn show_caption_above_media_option(self, val: Option<bool>) -> Self {
+        Self {
+            show_caption_above_media: val,
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn has_spoiler_option(self, val: Option<bool>) -> Self {
         Self {
@@ -344,6 +377,14 @@ impl<'a> SendAnimation<'a> {
         }
     }
 
+    #[must_use]
+    pub fn message_effect_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            message_effect_id: val.map(Into::into),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn reply_parameters_option(self, val: Option<ReplyParameters>) -> Self {
         Self {
@@ -361,11 +402,51 @@ impl<'a> SendAnimation<'a> {
     }
 }
 
+impl<'a> WithDefaultProperties for SendAnimation<'a> {
+    fn default_fields(&mut self) -> DefaultFields<'_> {
+        DefaultFields {
+            parse_mode: vec![ParseModeField::new(
+                &mut self.parse_mode,
+                self.caption_entities.is_some(),
+            )],
+            disable_notification: Some(&mut self.disable_notification),
+            protect_content: Some(&mut self.protect_content),
+            ..Default::default()
+        }
+    }
+
+    fn default_fields_ref(&self) -> DefaultFieldsRef<'_> {
+        DefaultFieldsRef {
+            parse_mode: vec![ParseModeFieldRef::new(
+                &self.parse_mode,
+                self.caption_entities.is_some(),
+            )],
+            disable_notification: Some(&self.disable_notification),
+            protect_content: Some(&self.protect_content),
+            ..Default::default()
+        }
+    }
+}
+
+impl<'a> WithBusinessConnectionId for SendAnimation<'a> {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
 impl<'a> TelegramMethod for SendAnimation<'a> {
     type Method = Self;
     type Return = Message;
 
-    fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<Self::Method> {
+        let mut data = bot.default_properties().apply(self);
+
+        bot.apply_business_connection_id(&mut data);
+
         let mut files = vec![];
         prepare_file(&mut files, &self.animation);
 
@@ -373,7 +454,7 @@ impl<'a> TelegramMethod for SendAnimation<'a> {
             prepare_file(&mut files, file);
         }
 
-        Request::new("sendAnimation", self, Some(files.into()))
+        Request::new_cow("sendAnimation", data, Some(files.into()))
     }
 }
 
@@ -1,7 +1,10 @@
 use super::base::{prepare_file, Request, TelegramMethod};
 
 use crate::{
-    client::Bot,
+    client::{
+        Bot, DefaultFields, DefaultFieldsRef, ParseModeField, ParseModeFieldRef,
+        WithBusinessConnectionId, WithDefaultProperties,
+    },
     types::{ChatIdKind, InputFile, Message, MessageEntity, ReplyMarkup, ReplyParameters},
 };
 
@@ -42,6 +45,8 @@ pub struct SendAudio<'a> {
     pub disable_notification: Option<bool>,
     /// Protects the contents of the sent message from forwarding and saving
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    pub message_effect_id: Option<String>,
     /// Description of the message to reply to
     pub reply_parameters: Option<ReplyParameters>,
     /// Additional interface options. A JSON-serialized object for an [inline keyboard](https://core.telegram.org/bots/features#inline-keyboards), [custom reply keyboard](https://core.telegram.org/bots/features#keyboards), instructions to remove reply keyboard or to force a reply from the user.
@@ -65,6 +70,7 @@ impl<'a> SendAudio<'a> {
             thumbnail: None,
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_parameters: None,
             reply_markup: None,
         }
@@ -194,6 +200,14 @@ impl<'a> SendAudio<'a> {
         }
     }
 
+    #[must_use]
+    pub fn message_effect_id(self, val: impl Into<String>) -> Self {
+        Self {
+            message_effect_id: Some(val.into()),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn reply_parameters(self, val: ReplyParameters) -> Self {
         Self {
@@ -309,6 +323,14 @@ impl<'a> SendAudio<'a> {
         }
     }
 
+    #[must_use]
+    pub fn message_effect_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            message_effect_id: val.map(Into::into),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn reply_parameters_option(self, val: Option<ReplyParameters>) -> Self {
         Self {
@@ -326,11 +348,51 @@ impl<'a> SendAudio<'a> {
     }
 }
 
+impl<'a> WithDefaultProperties for SendAudio<'a> {
+    fn default_fields(&mut self) -> DefaultFields<'_> {
+        DefaultFields {
+            parse_mode: vec![ParseModeField::new(
+                &mut self.parse_mode,
+                self.caption_entities.is_some(),
+            )],
+            disable_notification: Some(&mut self.disable_notification),
+            protect_content: Some(&mut self.protect_content),
+            ..Default::default()
+        }
+    }
+
+    fn default_fields_ref(&self) -> DefaultFieldsRef<'_> {
+        DefaultFieldsRef {
+            parse_mode: vec![ParseModeFieldRef::new(
+                &self.parse_mode,
+                self.caption_entities.is_some(),
+            )],
+            disable_notification: Some(&self.disable_notification),
+            protect_content: Some(&self.protect_content),
+            ..Default::default()
+        }
+    }
+}
+
+impl<'a> WithBusinessConnectionId for SendAudio<'a> {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
 impl<'a> TelegramMethod for SendAudio<'a> {
     type Method = Self;
     type Return = Message;
 
-    fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<Self::Method> {
+        let mut data = bot.default_properties().apply(self);
+
+        bot.apply_business_connection_id(&mut data);
+
         let mut files = vec![];
         prepare_file(&mut files, &self.audio);
 
@@ -338,7 +400,7 @@ impl<'a> TelegramMethod for SendAudio<'a> {
             prepare_file(&mut files, file);
         }
 
-        Request::new("sendAudio", self, Some(files.into()))
+        Request::new_cow("sendAudio", data, Some(files.into()))
     }
 }
 
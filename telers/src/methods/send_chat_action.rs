@@ -1,9 +1,13 @@
 use super::base::{Request, TelegramMethod};
 
-use crate::{client::Bot, types::ChatIdKind};
+use crate::{
+    client::{Bot, WithBusinessConnectionId},
+    types::ChatIdKind,
+};
 
 use serde::Serialize;
 use serde_with::skip_serializing_none;
+use std::borrow::Cow;
 
 /// Use this method when you need to tell the user that something is happening on the bot's side. The status is set for 5 seconds or less (when a message arrives from your bot, Telegram clients clear its typing status).
 /// # Documentation
@@ -89,12 +93,26 @@ impl SendChatAction {
     }
 }
 
+impl WithBusinessConnectionId for SendChatAction {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
 impl TelegramMethod for SendChatAction {
     type Method = Self;
     type Return = bool;
 
-    fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
-        Request::new("sendChatAction", self, None)
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<Self::Method> {
+        let mut data = Cow::Borrowed(self);
+
+        bot.apply_business_connection_id(&mut data);
+
+        Request::new_cow("sendChatAction", data, None)
     }
 }
 
@@ -0,0 +1,244 @@
+use super::base::{Request, TelegramMethod};
+
+use crate::{
+    client::{
+        Bot, DefaultFields, DefaultFieldsRef, ParseModeField, ParseModeFieldRef,
+        WithBusinessConnectionId, WithDefaultProperties,
+    },
+    errors::SessionErrorKind,
+    types::{InlineKeyboardMarkup, InputChecklist, Message, ReplyParameters},
+};
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// Use this method to send a checklist on behalf of a connected business account
+/// # Documentation
+/// <https://core.telegram.org/bots/api#sendchecklist>
+/// # Returns
+/// On success, the sent [`Message`] is returned
+#[skip_serializing_none]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize)]
+pub struct SendChecklist {
+    /// Unique identifier of the business connection on behalf of which the message will be sent. Can be omitted if the bot is scoped to a business connection, see [`Bot::with_business_connection_id`](crate::client::Bot::with_business_connection_id).
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat
+    pub chat_id: i64,
+    /// The checklist to send
+    pub checklist: InputChecklist,
+    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages). Users will receive a notification with no sound.
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message
+    pub message_effect_id: Option<String>,
+    /// Description of the message to reply to
+    pub reply_parameters: Option<ReplyParameters>,
+    /// A JSON-serialized object for an [inline keyboard](https://core.telegram.org/bots/features#inline-keyboards)
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+impl SendChecklist {
+    #[must_use]
+    pub fn new(chat_id: i64, checklist: InputChecklist) -> Self {
+        Self {
+            business_connection_id: None,
+            chat_id,
+            checklist,
+            disable_notification: None,
+            protect_content: None,
+            message_effect_id: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    #[must_use]
+    pub fn business_connection_id(self, val: impl Into<String>) -> Self {
+        Self {
+            business_connection_id: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn chat_id(self, val: i64) -> Self {
+        Self {
+            chat_id: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn checklist(self, val: InputChecklist) -> Self {
+        Self {
+            checklist: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn disable_notification(self, val: bool) -> Self {
+        Self {
+            disable_notification: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn protect_content(self, val: bool) -> Self {
+        Self {
+            protect_content: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn message_effect_id(self, val: impl Into<String>) -> Self {
+        Self {
+            message_effect_id: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn reply_parameters(self, val: ReplyParameters) -> Self {
+        Self {
+            reply_parameters: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn reply_markup(self, val: impl Into<InlineKeyboardMarkup>) -> Self {
+        Self {
+            reply_markup: Some(val.into()),
+            ..self
+        }
+    }
+}
+
+impl SendChecklist {
+    #[must_use]
+    pub fn business_connection_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            business_connection_id: val.map(Into::into),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn disable_notification_option(self, val: Option<bool>) -> Self {
+        Self {
+            disable_notification: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn protect_content_option(self, val: Option<bool>) -> Self {
+        Self {
+            protect_content: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn message_effect_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            message_effect_id: val.map(Into::into),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn reply_parameters_option(self, val: Option<ReplyParameters>) -> Self {
+        Self {
+            reply_parameters: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn reply_markup_option(self, val: Option<impl Into<InlineKeyboardMarkup>>) -> Self {
+        Self {
+            reply_markup: val.map(Into::into),
+            ..self
+        }
+    }
+}
+
+impl WithDefaultProperties for SendChecklist {
+    fn default_fields(&mut self) -> DefaultFields<'_> {
+        let checklist = &mut self.checklist;
+
+        DefaultFields {
+            parse_mode: [ParseModeField::new(
+                &mut checklist.parse_mode,
+                checklist.title_entities.is_some(),
+            )]
+            .into_iter()
+            .chain(checklist.tasks.iter_mut().map(|task| {
+                ParseModeField::new(&mut task.parse_mode, task.text_entities.is_some())
+            }))
+            .collect(),
+            disable_notification: Some(&mut self.disable_notification),
+            protect_content: Some(&mut self.protect_content),
+            ..Default::default()
+        }
+    }
+
+    fn default_fields_ref(&self) -> DefaultFieldsRef<'_> {
+        let checklist = &self.checklist;
+
+        DefaultFieldsRef {
+            parse_mode: [ParseModeFieldRef::new(
+                &checklist.parse_mode,
+                checklist.title_entities.is_some(),
+            )]
+            .into_iter()
+            .chain(
+                checklist.tasks.iter().map(|task| {
+                    ParseModeFieldRef::new(&task.parse_mode, task.text_entities.is_some())
+                }),
+            )
+            .collect(),
+            disable_notification: Some(&self.disable_notification),
+            protect_content: Some(&self.protect_content),
+            ..Default::default()
+        }
+    }
+}
+
+impl WithBusinessConnectionId for SendChecklist {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
+impl TelegramMethod for SendChecklist {
+    type Method = Self;
+    type Return = Message;
+
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<'_, Self::Method> {
+        let mut data = bot.default_properties().apply(self);
+
+        bot.apply_business_connection_id(&mut data);
+
+        Request::new_cow("sendChecklist", data, None)
+    }
+
+    fn validate<Client>(&self, bot: &Bot<Client>) -> Result<(), SessionErrorKind> {
+        bot.require_business_connection_id(self)
+    }
+}
+
+impl AsRef<SendChecklist> for SendChecklist {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
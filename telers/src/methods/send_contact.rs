@@ -1,7 +1,9 @@
 use super::base::{Request, TelegramMethod};
 
 use crate::{
-    client::Bot,
+    client::{
+        Bot, DefaultFields, DefaultFieldsRef, WithBusinessConnectionId, WithDefaultProperties,
+    },
     types::{ChatIdKind, Message, ReplyMarkup, ReplyParameters},
 };
 
@@ -34,6 +36,8 @@ pub struct SendContact {
     pub disable_notification: Option<bool>,
     /// Protects the contents of the sent message from forwarding and saving
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    pub message_effect_id: Option<String>,
     /// Description of the message to reply to
     pub reply_parameters: Option<ReplyParameters>,
     /// Additional interface options. A JSON-serialized object for an [inline keyboard](https://core.telegram.org/bots/features#inline-keyboards), [custom reply keyboard](https://core.telegram.org/bots/features#keyboards), instructions to remove reply keyboard or to force a reply from the user.
@@ -57,6 +61,7 @@ impl SendContact {
             vcard: None,
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_parameters: None,
             reply_markup: None,
         }
@@ -134,6 +139,14 @@ impl SendContact {
         }
     }
 
+    #[must_use]
+    pub fn message_effect_id(self, val: impl Into<String>) -> Self {
+        Self {
+            message_effect_id: Some(val.into()),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn reply_parameters(self, val: ReplyParameters) -> Self {
         Self {
@@ -200,6 +213,14 @@ impl SendContact {
         }
     }
 
+    #[must_use]
+    pub fn message_effect_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            message_effect_id: val.map(Into::into),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn reply_parameters_option(self, val: Option<ReplyParameters>) -> Self {
         Self {
@@ -217,12 +238,44 @@ impl SendContact {
     }
 }
 
+impl WithDefaultProperties for SendContact {
+    fn default_fields(&mut self) -> DefaultFields<'_> {
+        DefaultFields {
+            disable_notification: Some(&mut self.disable_notification),
+            protect_content: Some(&mut self.protect_content),
+            ..Default::default()
+        }
+    }
+
+    fn default_fields_ref(&self) -> DefaultFieldsRef<'_> {
+        DefaultFieldsRef {
+            disable_notification: Some(&self.disable_notification),
+            protect_content: Some(&self.protect_content),
+            ..Default::default()
+        }
+    }
+}
+
+impl WithBusinessConnectionId for SendContact {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
 impl TelegramMethod for SendContact {
     type Method = Self;
     type Return = Message;
 
-    fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
-        Request::new("sendContact", self, None)
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<Self::Method> {
+        let mut data = bot.default_properties().apply(self);
+
+        bot.apply_business_connection_id(&mut data);
+
+        Request::new_cow("sendContact", data, None)
     }
 }
 
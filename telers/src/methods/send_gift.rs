@@ -0,0 +1,258 @@
+use super::base::{Request, TelegramMethod};
+
+use crate::{
+    client::{
+        Bot, DefaultFields, DefaultFieldsRef, ParseModeField, ParseModeFieldRef,
+        WithDefaultProperties,
+    },
+    errors::SessionErrorKind,
+    types::{ChatIdKind, MessageEntity},
+};
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// Sends a gift to the given user or channel chat. The gift can't be converted to Telegram Stars by the receiver.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#sendgift>
+/// # Returns
+/// Returns `true` on success
+#[skip_serializing_none]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize)]
+pub struct SendGift {
+    /// Required if `chat_id` is not specified. Unique identifier of the target user who will receive the gift.
+    pub user_id: Option<i64>,
+    /// Required if `user_id` is not specified. Unique identifier for the chat or username of the channel (in the format `@channelusername`) that will receive the gift.
+    pub chat_id: Option<ChatIdKind>,
+    /// Identifier of the gift
+    pub gift_id: String,
+    /// Pass `true` to pay for the gift upgrade from the bot's balance, thereby making the upgrade free for the receiver
+    pub pay_for_upgrade: Option<bool>,
+    /// Text that will be shown along with the gift; 0-128 characters
+    pub text: Option<String>,
+    /// Mode for parsing entities in the text. See [`formatting options`](https://core.telegram.org/bots/api#formatting-options) for more details. Entities other than `bold`, `italic`, `underline`, `strikethrough`, `spoiler`, and `custom_emoji` are ignored.
+    pub text_parse_mode: Option<String>,
+    /// A JSON-serialized list of special entities that appear in the gift text. It can be specified instead of `text_parse_mode`. Entities other than `bold`, `italic`, `underline`, `strikethrough`, `spoiler`, and `custom_emoji` are ignored.
+    pub text_entities: Option<Vec<MessageEntity>>,
+}
+
+impl SendGift {
+    #[must_use]
+    pub fn new(gift_id: impl Into<String>) -> Self {
+        Self {
+            user_id: None,
+            chat_id: None,
+            gift_id: gift_id.into(),
+            pay_for_upgrade: None,
+            text: None,
+            text_parse_mode: None,
+            text_entities: None,
+        }
+    }
+
+    #[must_use]
+    pub fn user_id(self, val: i64) -> Self {
+        Self {
+            user_id: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn chat_id(self, val: impl Into<ChatIdKind>) -> Self {
+        Self {
+            chat_id: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn gift_id(self, val: impl Into<String>) -> Self {
+        Self {
+            gift_id: val.into(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn pay_for_upgrade(self, val: bool) -> Self {
+        Self {
+            pay_for_upgrade: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn text(self, val: impl Into<String>) -> Self {
+        Self {
+            text: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn text_parse_mode(self, val: impl Into<String>) -> Self {
+        Self {
+            text_parse_mode: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn text_entity(self, val: MessageEntity) -> Self {
+        Self {
+            text_entities: Some(
+                self.text_entities
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(Some(val))
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn text_entities(self, val: impl IntoIterator<Item = MessageEntity>) -> Self {
+        Self {
+            text_entities: Some(
+                self.text_entities
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(val)
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+}
+
+impl SendGift {
+    #[must_use]
+    pub fn user_id_option(self, val: Option<i64>) -> Self {
+        Self {
+            user_id: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn chat_id_option(self, val: Option<impl Into<ChatIdKind>>) -> Self {
+        Self {
+            chat_id: val.map(Into::into),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn pay_for_upgrade_option(self, val: Option<bool>) -> Self {
+        Self {
+            pay_for_upgrade: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn text_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            text: val.map(Into::into),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn text_parse_mode_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            text_parse_mode: val.map(Into::into),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn text_entities_option(
+        self,
+        val: Option<impl IntoIterator<Item = MessageEntity>>,
+    ) -> Self {
+        Self {
+            text_entities: val.map(|val| {
+                self.text_entities
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(val)
+                    .collect()
+            }),
+            ..self
+        }
+    }
+}
+
+impl WithDefaultProperties for SendGift {
+    fn default_fields(&mut self) -> DefaultFields<'_> {
+        DefaultFields {
+            parse_mode: vec![
+                // Text isn't parsed, if it isn't specified
+                ParseModeField::new(
+                    &mut self.text_parse_mode,
+                    self.text.is_none() || self.text_entities.is_some(),
+                ),
+            ],
+            ..Default::default()
+        }
+    }
+
+    fn default_fields_ref(&self) -> DefaultFieldsRef<'_> {
+        DefaultFieldsRef {
+            parse_mode: vec![
+                // Text isn't parsed, if it isn't specified
+                ParseModeFieldRef::new(
+                    &self.text_parse_mode,
+                    self.text.is_none() || self.text_entities.is_some(),
+                ),
+            ],
+            ..Default::default()
+        }
+    }
+}
+
+impl TelegramMethod for SendGift {
+    type Method = Self;
+    type Return = bool;
+
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<'_, Self::Method> {
+        let data = bot.default_properties().apply(self);
+
+        Request::new_cow("sendGift", data, None)
+    }
+
+    fn validate<Client>(&self, _bot: &Bot<Client>) -> Result<(), SessionErrorKind> {
+        if self.user_id.is_none() && self.chat_id.is_none() {
+            return Err(SessionErrorKind::MissingField("user_id` or `chat_id"));
+        }
+
+        Ok(())
+    }
+}
+
+impl AsRef<SendGift> for SendGift {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Reqwest;
+
+    #[test]
+    fn test_validate() {
+        let bot = Bot::<Reqwest>::default();
+
+        assert!(matches!(
+            SendGift::new("gift_id").validate(&bot),
+            Err(SessionErrorKind::MissingField(_))
+        ));
+        assert!(SendGift::new("gift_id").user_id(1).validate(&bot).is_ok());
+        assert!(SendGift::new("gift_id").chat_id(1).validate(&bot).is_ok());
+    }
+}
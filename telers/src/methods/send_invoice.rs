@@ -1,7 +1,7 @@
 use super::base::{Request, TelegramMethod};
 
 use crate::{
-    client::Bot,
+    client::{Bot, DefaultFields, DefaultFieldsRef, WithDefaultProperties},
     types::{ChatIdKind, InlineKeyboardMarkup, LabeledPrice, Message, ReplyParameters},
 };
 
@@ -26,13 +26,13 @@ pub struct SendInvoice {
     pub description: String,
     /// Bot-defined invoice payload, 1-128 bytes. This will not be displayed to the user, use for your internal processes.
     pub payload: String,
-    /// Payments provider token, obtained via [`Botfather`](https://t.me/botfather)
-    pub provider_token: String,
-    /// Three-letter ISO 4217 currency code, see [`more on currencies`](https://core.telegram.org/bots/payments#supported-currencies)
+    /// Payment provider token, obtained via [`Botfather`](https://t.me/botfather). Pass `None` for payments in [Telegram Stars](https://t.me/BotNews/90).
+    pub provider_token: Option<String>,
+    /// Three-letter ISO 4217 currency code, see [`more on currencies`](https://core.telegram.org/bots/payments#supported-currencies). Pass `XTR` for payments in [Telegram Stars](https://t.me/BotNews/90).
     pub currency: String,
-    /// Price breakdown, a list of components (e.g. product price, tax, discount, delivery cost, delivery tax, bonus, etc.)
+    /// Price breakdown, a list of components (e.g. product price, tax, discount, delivery cost, delivery tax, bonus, etc.). Must contain exactly one item for payments in [Telegram Stars](https://t.me/BotNews/90).
     pub prices: Vec<LabeledPrice>,
-    /// The maximum accepted amount for tips in the *smallest units* of the currency (integer, **not** float/double). For example, for a maximum tip of `US$ 1.45` pass `max_tip_amount = 145`. See the *exp* parameter in [`currencies.json`](https://core.telegram.org/bots/payments/currencies.json), it shows the number of digits past the decimal point for each currency (2 for the majority of currencies). Defaults to 0
+    /// The maximum accepted amount for tips in the *smallest units* of the currency (integer, **not** float/double). For example, for a maximum tip of `US$ 1.45` pass `max_tip_amount = 145`. See the *exp* parameter in [`currencies.json`](https://core.telegram.org/bots/payments/currencies.json), it shows the number of digits past the decimal point for each currency (2 for the majority of currencies). Defaults to 0. Not supported for payments in [Telegram Stars](https://t.me/BotNews/90).
     pub max_tip_amount: Option<i64>,
     /// A JSON-serialized array of suggested amounts of tip in the *smallest units* of the currency (integer, **not** float/double). At most 4 suggested tip amounts can be specified. The suggested tip amounts must be positive, passed in a strictly increased order and must not exceed `max_tip_amount`.
     pub suggested_tip_amounts: Option<Vec<i64>>,
@@ -66,6 +66,8 @@ pub struct SendInvoice {
     pub disable_notification: Option<bool>,
     /// Protects the contents of the sent message from forwarding and saving
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    pub message_effect_id: Option<String>,
     /// Description of the message to reply to
     pub reply_parameters: Option<ReplyParameters>,
     /// A JSON-serialized object for an [inline keyboard](https://core.telegram.org/bots/features#inline-keyboards). If empty, one 'Pay `total price`' button will be shown. If not empty, the first button must be a Pay button.
@@ -79,7 +81,6 @@ impl SendInvoice {
         title: impl Into<String>,
         description: impl Into<String>,
         payload: impl Into<String>,
-        provider_token: impl Into<String>,
         currency: impl Into<String>,
         prices: impl IntoIterator<Item = LabeledPrice>,
     ) -> Self {
@@ -89,7 +90,7 @@ impl SendInvoice {
             title: title.into(),
             description: description.into(),
             payload: payload.into(),
-            provider_token: provider_token.into(),
+            provider_token: None,
             currency: currency.into(),
             prices: prices.into_iter().collect(),
             max_tip_amount: None,
@@ -109,6 +110,7 @@ impl SendInvoice {
             is_flexible: None,
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_parameters: None,
             reply_markup: None,
         }
@@ -157,7 +159,7 @@ impl SendInvoice {
     #[must_use]
     pub fn provider_token(self, val: impl Into<String>) -> Self {
         Self {
-            provider_token: val.into(),
+            provider_token: Some(val.into()),
             ..self
         }
     }
@@ -334,6 +336,14 @@ impl SendInvoice {
         }
     }
 
+    #[must_use]
+    pub fn message_effect_id(self, val: impl Into<String>) -> Self {
+        Self {
+            message_effect_id: Some(val.into()),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn reply_parameters(self, val: ReplyParameters) -> Self {
         Self {
@@ -352,6 +362,14 @@ impl SendInvoice {
 }
 
 impl SendInvoice {
+    #[must_use]
+    pub fn provider_token_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            provider_token: val.map(Into::into),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn max_tip_amount_option(self, val: Option<i64>) -> Self {
         Self {
@@ -488,6 +506,14 @@ impl SendInvoice {
         }
     }
 
+    #[must_use]
+    pub fn message_effect_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            message_effect_id: val.map(Into::into),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn reply_parameters_option(self, val: Option<ReplyParameters>) -> Self {
         Self {
@@ -505,12 +531,32 @@ impl SendInvoice {
     }
 }
 
+impl WithDefaultProperties for SendInvoice {
+    fn default_fields(&mut self) -> DefaultFields<'_> {
+        DefaultFields {
+            disable_notification: Some(&mut self.disable_notification),
+            protect_content: Some(&mut self.protect_content),
+            ..Default::default()
+        }
+    }
+
+    fn default_fields_ref(&self) -> DefaultFieldsRef<'_> {
+        DefaultFieldsRef {
+            disable_notification: Some(&self.disable_notification),
+            protect_content: Some(&self.protect_content),
+            ..Default::default()
+        }
+    }
+}
+
 impl TelegramMethod for SendInvoice {
     type Method = Self;
     type Return = Message;
 
-    fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
-        Request::new("sendInvoice", self, None)
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<Self::Method> {
+        let data = bot.default_properties().apply(self);
+
+        Request::new_cow("sendInvoice", data, None)
     }
 }
 
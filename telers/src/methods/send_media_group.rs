@@ -1,7 +1,12 @@
-use super::base::{prepare_input_media_group, Request, TelegramMethod};
+use super::base::{
+    input_media_parse_mode_field, input_media_parse_mode_field_ref, prepare_input_media_group,
+    Request, TelegramMethod,
+};
 
 use crate::{
-    client::Bot,
+    client::{
+        Bot, DefaultFields, DefaultFieldsRef, WithBusinessConnectionId, WithDefaultProperties,
+    },
     types::{ChatIdKind, InputMedia, Message, ReplyParameters},
 };
 
@@ -28,6 +33,8 @@ pub struct SendMediaGroup<'a> {
     pub disable_notification: Option<bool>,
     /// Protects the contents of the sent message from forwarding and saving
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    pub message_effect_id: Option<String>,
     /// Description of the message to reply to
     pub reply_parameters: Option<ReplyParameters>,
 }
@@ -46,6 +53,7 @@ impl<'a> SendMediaGroup<'a> {
             media: media.into_iter().map(Into::into).collect(),
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_parameters: None,
         }
     }
@@ -114,6 +122,14 @@ impl<'a> SendMediaGroup<'a> {
         }
     }
 
+    #[must_use]
+    pub fn message_effect_id(self, val: impl Into<String>) -> Self {
+        Self {
+            message_effect_id: Some(val.into()),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn reply_parameters(self, val: ReplyParameters) -> Self {
         Self {
@@ -156,6 +172,14 @@ impl<'a> SendMediaGroup<'a> {
         }
     }
 
+    #[must_use]
+    pub fn message_effect_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            message_effect_id: val.map(Into::into),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn reply_parameters_option(self, val: Option<ReplyParameters>) -> Self {
         Self {
@@ -165,15 +189,57 @@ impl<'a> SendMediaGroup<'a> {
     }
 }
 
+impl<'a> WithDefaultProperties for SendMediaGroup<'a> {
+    fn default_fields(&mut self) -> DefaultFields<'_> {
+        DefaultFields {
+            parse_mode: self
+                .media
+                .iter_mut()
+                .map(input_media_parse_mode_field)
+                .collect(),
+            disable_notification: Some(&mut self.disable_notification),
+            protect_content: Some(&mut self.protect_content),
+            ..Default::default()
+        }
+    }
+
+    fn default_fields_ref(&self) -> DefaultFieldsRef<'_> {
+        DefaultFieldsRef {
+            parse_mode: self
+                .media
+                .iter()
+                .map(input_media_parse_mode_field_ref)
+                .collect(),
+            disable_notification: Some(&self.disable_notification),
+            protect_content: Some(&self.protect_content),
+            ..Default::default()
+        }
+    }
+}
+
+impl<'a> WithBusinessConnectionId for SendMediaGroup<'a> {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
 impl<'a> TelegramMethod for SendMediaGroup<'a> {
     type Method = Self;
     type Return = Vec<Message>;
 
-    fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<Self::Method> {
+        let mut data = bot.default_properties().apply(self);
+
+        bot.apply_business_connection_id(&mut data);
+
         let mut files = vec![];
         prepare_input_media_group(&mut files, &self.media);
 
-        Request::new("sendMediaGroup", self, Some(files.into()))
+        Request::new_cow("sendMediaGroup", data, Some(files.into()))
     }
 }
 
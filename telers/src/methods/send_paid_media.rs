@@ -0,0 +1,449 @@
+use super::base::{prepare_input_paid_media_group, Request, TelegramMethod};
+
+use crate::{
+    client::{
+        Bot, DefaultFields, DefaultFieldsRef, ParseModeField, ParseModeFieldRef,
+        WithBusinessConnectionId, WithDefaultProperties,
+    },
+    types::{ChatIdKind, InputPaidMedia, Message, MessageEntity, ReplyMarkup, ReplyParameters},
+};
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// Use this method to send paid media.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#sendpaidmedia>
+/// # Returns
+/// On success, the sent [`Message`] is returned
+#[skip_serializing_none]
+#[derive(Debug, Clone, Hash, PartialEq, Serialize)]
+pub struct SendPaidMedia<'a> {
+    /// Unique identifier of the business connection on behalf of which the message will be sent
+    pub business_connection_id: Option<String>,
+    /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`). If the chat is a channel, all Telegram Star proceeds from this media will be credited to the chat's balance. Otherwise, they will be credited to the bot's balance.
+    pub chat_id: ChatIdKind,
+    /// The number of Telegram Stars that must be paid to buy access to the media; 1-2500
+    pub star_count: i64,
+    /// A JSON-serialized array describing the media to be sent; up to 10 items
+    pub media: Vec<InputPaidMedia<'a>>,
+    /// Bot-defined paid media payload, 0-128 bytes. This will not be displayed to the user, use it for your internal processes.
+    pub payload: Option<String>,
+    /// Media caption, 0-1024 characters after entities parsing
+    pub caption: Option<String>,
+    /// Mode for parsing entities in the media caption. See [`formatting options`](https://core.telegram.org/bots/api#formatting-options) for more details.
+    pub parse_mode: Option<String>,
+    /// A JSON-serialized list of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Pass `true`, if the caption must be shown above the message media
+    pub show_caption_above_media: Option<bool>,
+    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages). Users will receive a notification with no sound
+    pub disable_notification: Option<bool>,
+    /// Protects the contents of the sent message from forwarding and saving
+    pub protect_content: Option<bool>,
+    /// Description of the message to reply to
+    pub reply_parameters: Option<ReplyParameters>,
+    /// Additional interface options. A JSON-serialized object for an [inline keyboard](https://core.telegram.org/bots/features#inline-keyboards), [custom reply keyboard](https://core.telegram.org/bots/features#keyboards), instructions to remove reply keyboard or to force a reply from the user.
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl<'a> SendPaidMedia<'a> {
+    #[must_use]
+    pub fn new<T, I>(chat_id: impl Into<ChatIdKind>, star_count: i64, media: I) -> Self
+    where
+        T: Into<InputPaidMedia<'a>>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            business_connection_id: None,
+            chat_id: chat_id.into(),
+            star_count,
+            media: media.into_iter().map(Into::into).collect(),
+            payload: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: None,
+            disable_notification: None,
+            protect_content: None,
+            reply_parameters: None,
+            reply_markup: None,
+        }
+    }
+
+    #[must_use]
+    pub fn business_connection_id(self, val: impl Into<String>) -> Self {
+        Self {
+            business_connection_id: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn chat_id(self, val: impl Into<ChatIdKind>) -> Self {
+        Self {
+            chat_id: val.into(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn star_count(self, val: i64) -> Self {
+        Self {
+            star_count: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn single_media(self, val: impl Into<InputPaidMedia<'a>>) -> Self {
+        Self {
+            media: self.media.into_iter().chain(Some(val.into())).collect(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn media<T, I>(self, val: I) -> Self
+    where
+        T: Into<InputPaidMedia<'a>>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            media: self
+                .media
+                .into_iter()
+                .chain(val.into_iter().map(Into::into))
+                .collect(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn payload(self, val: impl Into<String>) -> Self {
+        Self {
+            payload: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn caption(self, val: impl Into<String>) -> Self {
+        Self {
+            caption: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn parse_mode(self, val: impl Into<String>) -> Self {
+        Self {
+            parse_mode: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn caption_entity(self, val: MessageEntity) -> Self {
+        Self {
+            caption_entities: Some(
+                self.caption_entities
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(Some(val))
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn caption_entities(self, val: impl IntoIterator<Item = MessageEntity>) -> Self {
+        Self {
+            caption_entities: Some(
+                self.caption_entities
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(val)
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn show_caption_above_media(self, val: bool) -> Self {
+        Self {
+            show_caption_above_media: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn disable_notification(self, val: bool) -> Self {
+        Self {
+            disable_notification: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn protect_content(self, val: bool) -> Self {
+        Self {
+            protect_content: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn reply_parameters(self, val: ReplyParameters) -> Self {
+        Self {
+            reply_parameters: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn reply_markup(self, val: impl Into<ReplyMarkup>) -> Self {
+        Self {
+            reply_markup: Some(val.into()),
+            ..self
+        }
+    }
+}
+
+impl<'a> SendPaidMedia<'a> {
+    #[must_use]
+    pub fn business_connection_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            business_connection_id: val.map(Into::into),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn payload_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            payload: val.map(Into::into),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn caption_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            caption: val.map(Into::into),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn parse_mode_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            parse_mode: val.map(Into::into),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn caption_entities_option(
+        self,
+        val: Option<impl IntoIterator<Item = MessageEntity>>,
+    ) -> Self {
+        Self {
+            caption_entities: val.map(|val| {
+                self.caption_entities
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(val)
+                    .collect()
+            }),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn show_caption_above_media_option(self, val: Option<bool>) -> Self {
+        Self {
+            show_caption_above_media: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn disable_notification_option(self, val: Option<bool>) -> Self {
+        Self {
+            disable_notification: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn protect_content_option(self, val: Option<bool>) -> Self {
+        Self {
+            protect_content: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn reply_parameters_option(self, val: Option<ReplyParameters>) -> Self {
+        Self {
+            reply_parameters: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn reply_markup_option(self, val: Option<impl Into<ReplyMarkup>>) -> Self {
+        Self {
+            reply_markup: val.map(Into::into),
+            ..self
+        }
+    }
+}
+
+impl<'a> WithDefaultProperties for SendPaidMedia<'a> {
+    fn default_fields(&mut self) -> DefaultFields<'_> {
+        DefaultFields {
+            parse_mode: vec![ParseModeField::new(
+                &mut self.parse_mode,
+                self.caption_entities.is_some(),
+            )],
+            disable_notification: Some(&mut self.disable_notification),
+            protect_content: Some(&mut self.protect_content),
+            ..Default::default()
+        }
+    }
+
+    fn default_fields_ref(&self) -> DefaultFieldsRef<'_> {
+        DefaultFieldsRef {
+            parse_mode: vec![ParseModeFieldRef::new(
+                &self.parse_mode,
+                self.caption_entities.is_some(),
+            )],
+            disable_notification: Some(&self.disable_notification),
+            protect_content: Some(&self.protect_content),
+            ..Default::default()
+        }
+    }
+}
+
+impl<'a> WithBusinessConnectionId for SendPaidMedia<'a> {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
+impl<'a> TelegramMethod for SendPaidMedia<'a> {
+    type Method = Self;
+    type Return = Message;
+
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<'_, Self::Method> {
+        let mut data = bot.default_properties().apply(self);
+
+        bot.apply_business_connection_id(&mut data);
+
+        let mut files = vec![];
+        prepare_input_paid_media_group(&mut files, &self.media);
+
+        Request::new_cow("sendPaidMedia", data, Some(files.into()))
+    }
+}
+
+impl<'a> AsRef<SendPaidMedia<'a>> for SendPaidMedia<'a> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{DefaultBotProperties, Reqwest},
+        enums::ParseMode,
+        types::{InputFile, InputPaidMediaPhoto, InputPaidMediaVideo},
+    };
+
+    use bytes::Bytes;
+    use futures::stream;
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_build_request() {
+        let bot = Bot::<Reqwest>::default();
+
+        let photo = InputFile::buffered(b"photo".to_vec());
+        let video = InputFile::buffered(b"video".to_vec());
+        let thumbnail = InputFile::buffered(b"thumbnail".to_vec());
+        let method = SendPaidMedia::new(
+            1,
+            100,
+            [
+                InputPaidMedia::from(InputPaidMediaPhoto::new(photo.clone())),
+                InputPaidMediaPhoto::new(InputFile::id("file_id")).into(),
+                InputPaidMediaVideo::new(video.clone())
+                    .thumbnail(thumbnail.clone())
+                    .into(),
+            ],
+        );
+
+        let request = method.build_request(&bot);
+        assert_eq!(request.method_name, "sendPaidMedia");
+        // Only files which require uploading are passed to the request files
+        let files = request.files.unwrap();
+        assert_eq!(files.len(), 3);
+        assert_eq!(*files[0], photo);
+        assert_eq!(*files[1], video);
+        assert_eq!(*files[2], thumbnail);
+    }
+
+    #[test]
+    fn test_build_request_with_stream_files() {
+        let bot = Bot::<Reqwest>::default()
+            .with_default_properties(DefaultBotProperties::new().parse_mode(ParseMode::HTML))
+            .with_business_connection_id("bot_connection");
+
+        let stream_file = || InputFile::stream(stream::iter([Ok(Bytes::from_static(b"test"))]));
+        let method = SendPaidMedia::new(
+            1,
+            100,
+            [
+                InputPaidMedia::from(InputPaidMediaPhoto::new(stream_file())),
+                InputPaidMediaVideo::new(stream_file())
+                    .thumbnail(stream_file())
+                    .into(),
+            ],
+        )
+        .caption("<b>test</b>");
+
+        let request = method.build_request(&bot);
+        assert!(matches!(request.data, Cow::Owned(_)));
+
+        // Multipart parts of the files are named by their IDs, so the data must refer to the same IDs
+        let files = request
+            .files
+            .as_deref()
+            .unwrap()
+            .iter()
+            .map(|file| match file {
+                InputFile::Stream(file) => format!("attach://{}", file.id()),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        let data = serde_json::to_value(request.data.as_ref()).unwrap();
+        assert_eq!(
+            [
+                &data["media"][0]["media"],
+                &data["media"][1]["media"],
+                &data["media"][1]["thumbnail"],
+            ],
+            [&files[0], &files[1], &files[2]],
+        );
+    }
+}
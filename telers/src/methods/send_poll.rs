@@ -1,13 +1,21 @@
 use super::base::{Request, TelegramMethod};
 
 use crate::{
-    client::Bot,
-    types::{ChatIdKind, Message, MessageEntity, ReplyMarkup, ReplyParameters},
+    client::{
+        Bot, DefaultFields, DefaultFieldsRef, ParseModeField, ParseModeFieldRef,
+        WithBusinessConnectionId, WithDefaultProperties,
+    },
+    types::{ChatIdKind, InputPollOption, Message, MessageEntity, ReplyMarkup, ReplyParameters},
 };
 
 use serde::Serialize;
 use serde_with::skip_serializing_none;
 
+#[cfg(feature = "chrono")]
+use crate::utils::date_time;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, TimeDelta, Utc};
+
 /// Use this method to send a native poll.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#sendpoll>
@@ -24,8 +32,12 @@ pub struct SendPoll {
     pub message_thread_id: Option<i64>,
     /// Poll question, 1-300 characters
     pub question: String,
-    /// A JSON-serialized list of answer options, 2-10 strings 1-100 characters each
-    pub options: Vec<String>,
+    /// Mode for parsing entities in the question. See [formatting options](https://core.telegram.org/bots/api#formatting-options) for more details. Currently, only custom emoji entities are allowed
+    pub question_parse_mode: Option<String>,
+    /// A JSON-serialized list of special entities that appear in the poll question. It can be specified instead of `question_parse_mode`
+    pub question_entities: Option<Vec<MessageEntity>>,
+    /// A JSON-serialized list of answer options, 2-10 answer options
+    pub options: Vec<InputPollOption>,
     /// `true`, if the poll needs to be anonymous, defaults to `true`
     pub is_anonymous: Option<bool>,
     /// Poll type, `quiz` or `regular`, defaults to `regular`
@@ -51,6 +63,8 @@ pub struct SendPoll {
     pub disable_notification: Option<bool>,
     /// Protects the contents of the sent message from forwarding and saving
     pub protect_content: Option<bool>,
+    /// Unique identifier of the message effect to be added to the message; for private chats only
+    pub message_effect_id: Option<String>,
     /// Description of the message to reply to
     pub reply_parameters: Option<ReplyParameters>,
     /// Additional interface options. A JSON-serialized object for an [inline keyboard](https://core.telegram.org/bots/features#inline-keyboards), [custom reply keyboard](https://core.telegram.org/bots/features#keyboards), instructions to remove reply keyboard or to force a reply from the user.
@@ -59,9 +73,13 @@ pub struct SendPoll {
 
 impl SendPoll {
     #[must_use]
-    pub fn new<T, I>(chat_id: impl Into<ChatIdKind>, question: T, options: I) -> Self
+    pub fn new<T, I>(
+        chat_id: impl Into<ChatIdKind>,
+        question: impl Into<String>,
+        options: I,
+    ) -> Self
     where
-        T: Into<String>,
+        T: Into<InputPollOption>,
         I: IntoIterator<Item = T>,
     {
         Self {
@@ -69,6 +87,8 @@ impl SendPoll {
             chat_id: chat_id.into(),
             message_thread_id: None,
             question: question.into(),
+            question_parse_mode: None,
+            question_entities: None,
             options: options.into_iter().map(Into::into).collect(),
             is_anonymous: None,
             poll_type: None,
@@ -82,6 +102,7 @@ impl SendPoll {
             is_closed: None,
             disable_notification: None,
             protect_content: None,
+            message_effect_id: None,
             reply_parameters: None,
             reply_markup: None,
         }
@@ -120,7 +141,43 @@ impl SendPoll {
     }
 
     #[must_use]
-    pub fn option(self, val: impl Into<String>) -> Self {
+    pub fn question_parse_mode(self, val: impl Into<String>) -> Self {
+        Self {
+            question_parse_mode: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn question_entity(self, val: MessageEntity) -> Self {
+        Self {
+            question_entities: Some(
+                self.question_entities
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(Some(val))
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn question_entities(self, val: impl IntoIterator<Item = MessageEntity>) -> Self {
+        Self {
+            question_entities: Some(
+                self.question_entities
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(val)
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn option(self, val: impl Into<InputPollOption>) -> Self {
         Self {
             options: self.options.into_iter().chain(Some(val.into())).collect(),
             ..self
@@ -130,7 +187,7 @@ impl SendPoll {
     #[must_use]
     pub fn options<T, I>(self, val: I) -> Self
     where
-        T: Into<String>,
+        T: Into<InputPollOption>,
         I: IntoIterator<Item = T>,
     {
         Self {
@@ -259,6 +316,14 @@ impl SendPoll {
         }
     }
 
+    #[must_use]
+    pub fn message_effect_id(self, val: impl Into<String>) -> Self {
+        Self {
+            message_effect_id: Some(val.into()),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn reply_parameters(self, val: ReplyParameters) -> Self {
         Self {
@@ -293,6 +358,25 @@ impl SendPoll {
         }
     }
 
+    #[must_use]
+    pub fn question_parse_mode_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            question_parse_mode: val.map(Into::into),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn question_entities_option(
+        self,
+        val: Option<impl IntoIterator<Item = MessageEntity>>,
+    ) -> Self {
+        Self {
+            question_entities: val.map(|val| val.into_iter().collect()),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn is_anonymous_option(self, val: Option<bool>) -> Self {
         Self {
@@ -392,6 +476,14 @@ impl SendPoll {
         }
     }
 
+    #[must_use]
+    pub fn message_effect_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            message_effect_id: val.map(Into::into),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn reply_parameters_option(self, val: Option<ReplyParameters>) -> Self {
         Self {
@@ -409,12 +501,85 @@ impl SendPoll {
     }
 }
 
+#[cfg(feature = "chrono")]
+impl SendPoll {
+    /// Amount of time the poll will be active after creation, 5-600 seconds. Can't be used together with `close_date`.
+    #[must_use]
+    pub fn open_period_delta(self, val: TimeDelta) -> Self {
+        self.open_period(date_time::to_seconds(val))
+    }
+
+    /// Point in time when the poll will be automatically closed. Must be at least 5 and no more than 600 seconds in the future. Can't be used together with `open_period`.
+    #[must_use]
+    pub fn close_date_utc(self, val: DateTime<Utc>) -> Self {
+        self.close_date(date_time::to_timestamp(val))
+    }
+}
+
+impl WithDefaultProperties for SendPoll {
+    fn default_fields(&mut self) -> DefaultFields<'_> {
+        DefaultFields {
+            parse_mode: [ParseModeField::new(
+                &mut self.question_parse_mode,
+                self.question_entities.is_some(),
+            )]
+            .into_iter()
+            .chain(self.options.iter_mut().map(|option| {
+                ParseModeField::new(&mut option.text_parse_mode, option.text_entities.is_some())
+            }))
+            .chain([ParseModeField::new(
+                &mut self.explanation_parse_mode,
+                self.explanation_entities.is_some(),
+            )])
+            .collect(),
+            disable_notification: Some(&mut self.disable_notification),
+            protect_content: Some(&mut self.protect_content),
+            ..Default::default()
+        }
+    }
+
+    fn default_fields_ref(&self) -> DefaultFieldsRef<'_> {
+        DefaultFieldsRef {
+            parse_mode: [ParseModeFieldRef::new(
+                &self.question_parse_mode,
+                self.question_entities.is_some(),
+            )]
+            .into_iter()
+            .chain(self.options.iter().map(|option| {
+                ParseModeFieldRef::new(&option.text_parse_mode, option.text_entities.is_some())
+            }))
+            .chain([ParseModeFieldRef::new(
+                &self.explanation_parse_mode,
+                self.explanation_entities.is_some(),
+            )])
+            .collect(),
+            disable_notification: Some(&self.disable_notification),
+            protect_content: Some(&self.protect_content),
+            ..Default::default()
+        }
+    }
+}
+
+impl WithBusinessConnectionId for SendPoll {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
 impl TelegramMethod for SendPoll {
     type Method = Self;
     type Return = Message;
 
-    fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
-        Request::new("sendPoll", self, None)
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<Self::Method> {
+        let mut data = bot.default_properties().apply(self);
+
+        bot.apply_business_connection_id(&mut data);
+
+        Request::new_cow("sendPoll", data, None)
     }
 }
 
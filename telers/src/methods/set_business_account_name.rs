@@ -0,0 +1,112 @@
+use super::base::{Request, TelegramMethod};
+
+use crate::{
+    client::{Bot, WithBusinessConnectionId},
+    errors::SessionErrorKind,
+};
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+use std::borrow::Cow;
+
+/// Changes the first and last name of a managed business account. Requires the `can_change_name` business bot right.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#setbusinessaccountname>
+/// # Returns
+/// Returns `true` on success
+#[skip_serializing_none]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize)]
+pub struct SetBusinessAccountName {
+    /// Unique identifier of the business connection. Can be omitted if the bot is scoped to a business connection, see [`Bot::with_business_connection_id`](crate::client::Bot::with_business_connection_id).
+    pub business_connection_id: Option<String>,
+    /// The new value of the first name for the business account; 1-64 characters
+    pub first_name: String,
+    /// The new value of the last name for the business account; 0-64 characters
+    pub last_name: Option<String>,
+}
+
+impl SetBusinessAccountName {
+    #[must_use]
+    pub fn new(first_name: impl Into<String>) -> Self {
+        Self {
+            business_connection_id: None,
+            first_name: first_name.into(),
+            last_name: None,
+        }
+    }
+
+    #[must_use]
+    pub fn business_connection_id(self, val: impl Into<String>) -> Self {
+        Self {
+            business_connection_id: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn first_name(self, val: impl Into<String>) -> Self {
+        Self {
+            first_name: val.into(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn last_name(self, val: impl Into<String>) -> Self {
+        Self {
+            last_name: Some(val.into()),
+            ..self
+        }
+    }
+}
+
+impl SetBusinessAccountName {
+    #[must_use]
+    pub fn business_connection_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            business_connection_id: val.map(Into::into),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn last_name_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            last_name: val.map(Into::into),
+            ..self
+        }
+    }
+}
+
+impl WithBusinessConnectionId for SetBusinessAccountName {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
+impl TelegramMethod for SetBusinessAccountName {
+    type Method = Self;
+    type Return = bool;
+
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<'_, Self::Method> {
+        let mut data = Cow::Borrowed(self);
+
+        bot.apply_business_connection_id(&mut data);
+
+        Request::new_cow("setBusinessAccountName", data, None)
+    }
+
+    fn validate<Client>(&self, bot: &Bot<Client>) -> Result<(), SessionErrorKind> {
+        bot.require_business_connection_id(self)
+    }
+}
+
+impl AsRef<SetBusinessAccountName> for SetBusinessAccountName {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
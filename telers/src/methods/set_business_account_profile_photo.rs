@@ -0,0 +1,116 @@
+use super::base::{prepare_input_profile_photo, Request, TelegramMethod};
+
+use crate::{
+    client::{Bot, WithBusinessConnectionId},
+    errors::SessionErrorKind,
+    types::InputProfilePhoto,
+};
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+use std::borrow::Cow;
+
+/// Changes the profile photo of a managed business account. Requires the `can_edit_profile_photo` business bot right.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#setbusinessaccountprofilephoto>
+/// # Returns
+/// Returns `true` on success
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SetBusinessAccountProfilePhoto<'a> {
+    /// Unique identifier of the business connection. Can be omitted if the bot is scoped to a business connection, see [`Bot::with_business_connection_id`](crate::client::Bot::with_business_connection_id).
+    pub business_connection_id: Option<String>,
+    /// The new profile photo to set
+    pub photo: InputProfilePhoto<'a>,
+    /// Pass `true` to set the public photo, which will be visible even if the main photo is hidden by the business account's privacy settings. An account can have only one public photo.
+    pub is_public: Option<bool>,
+}
+
+impl<'a> SetBusinessAccountProfilePhoto<'a> {
+    #[must_use]
+    pub fn new(photo: impl Into<InputProfilePhoto<'a>>) -> Self {
+        Self {
+            business_connection_id: None,
+            photo: photo.into(),
+            is_public: None,
+        }
+    }
+
+    #[must_use]
+    pub fn business_connection_id(self, val: impl Into<String>) -> Self {
+        Self {
+            business_connection_id: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn photo(self, val: impl Into<InputProfilePhoto<'a>>) -> Self {
+        Self {
+            photo: val.into(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn is_public(self, val: bool) -> Self {
+        Self {
+            is_public: Some(val),
+            ..self
+        }
+    }
+}
+
+impl<'a> SetBusinessAccountProfilePhoto<'a> {
+    #[must_use]
+    pub fn business_connection_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            business_connection_id: val.map(Into::into),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn is_public_option(self, val: Option<bool>) -> Self {
+        Self {
+            is_public: val,
+            ..self
+        }
+    }
+}
+
+impl WithBusinessConnectionId for SetBusinessAccountProfilePhoto<'_> {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
+impl TelegramMethod for SetBusinessAccountProfilePhoto<'_> {
+    type Method = Self;
+    type Return = bool;
+
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<'_, Self::Method> {
+        let mut data = Cow::Borrowed(self);
+
+        bot.apply_business_connection_id(&mut data);
+
+        let mut files = vec![];
+        prepare_input_profile_photo(&mut files, &self.photo);
+
+        Request::new_cow("setBusinessAccountProfilePhoto", data, Some(files.into()))
+    }
+
+    fn validate<Client>(&self, bot: &Bot<Client>) -> Result<(), SessionErrorKind> {
+        bot.require_business_connection_id(self)
+    }
+}
+
+impl<'a> AsRef<SetBusinessAccountProfilePhoto<'a>> for SetBusinessAccountProfilePhoto<'a> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
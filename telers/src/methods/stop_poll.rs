@@ -1,12 +1,13 @@
 use super::base::{Request, TelegramMethod};
 
 use crate::{
-    client::Bot,
+    client::{Bot, WithBusinessConnectionId},
     types::{ChatIdKind, InlineKeyboardMarkup, Poll},
 };
 
 use serde::Serialize;
 use serde_with::skip_serializing_none;
+use std::borrow::Cow;
 
 /// Use this method to stop a poll which was sent by the bot.
 /// # Documentation
@@ -16,6 +17,8 @@ use serde_with::skip_serializing_none;
 #[skip_serializing_none]
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize)]
 pub struct StopPoll {
+    /// Unique identifier of the business connection on behalf of which the message to be edited was sent
+    pub business_connection_id: Option<String>,
     /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
     pub chat_id: ChatIdKind,
     /// Identifier of the original message with the poll
@@ -28,12 +31,21 @@ impl StopPoll {
     #[must_use]
     pub fn new(chat_id: impl Into<ChatIdKind>, message_id: i64) -> Self {
         Self {
+            business_connection_id: None,
             chat_id: chat_id.into(),
             message_id,
             reply_markup: None,
         }
     }
 
+    #[must_use]
+    pub fn business_connection_id(self, val: impl Into<String>) -> Self {
+        Self {
+            business_connection_id: Some(val.into()),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn chat_id(self, val: impl Into<ChatIdKind>) -> Self {
         Self {
@@ -60,6 +72,14 @@ impl StopPoll {
 }
 
 impl StopPoll {
+    #[must_use]
+    pub fn business_connection_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            business_connection_id: val.map(Into::into),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn reply_markup_option(self, val: Option<impl Into<InlineKeyboardMarkup>>) -> Self {
         Self {
@@ -69,12 +89,26 @@ impl StopPoll {
     }
 }
 
+impl WithBusinessConnectionId for StopPoll {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
 impl TelegramMethod for StopPoll {
     type Method = Self;
     type Return = Poll;
 
-    fn build_request<Client>(&self, _bot: &Bot<Client>) -> Request<Self::Method> {
-        Request::new("stopPoll", self, None)
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<Self::Method> {
+        let mut data = Cow::Borrowed(self);
+
+        bot.apply_business_connection_id(&mut data);
+
+        Request::new_cow("stopPoll", data, None)
     }
 }
 
@@ -0,0 +1,93 @@
+use super::base::{Request, TelegramMethod};
+
+use crate::{
+    client::{Bot, WithBusinessConnectionId},
+    errors::SessionErrorKind,
+};
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+use std::borrow::Cow;
+
+/// Transfers Telegram Stars from the business account balance to the bot's balance. Requires the `can_transfer_stars` business bot right.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#transferbusinessaccountstars>
+/// # Returns
+/// Returns `true` on success
+#[skip_serializing_none]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize)]
+pub struct TransferBusinessAccountStars {
+    /// Unique identifier of the business connection. Can be omitted if the bot is scoped to a business connection, see [`Bot::with_business_connection_id`](crate::client::Bot::with_business_connection_id).
+    pub business_connection_id: Option<String>,
+    /// Number of Telegram Stars to transfer; 1-10000
+    pub star_count: i64,
+}
+
+impl TransferBusinessAccountStars {
+    #[must_use]
+    pub fn new(star_count: i64) -> Self {
+        Self {
+            business_connection_id: None,
+            star_count,
+        }
+    }
+
+    #[must_use]
+    pub fn business_connection_id(self, val: impl Into<String>) -> Self {
+        Self {
+            business_connection_id: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn star_count(self, val: i64) -> Self {
+        Self {
+            star_count: val,
+            ..self
+        }
+    }
+}
+
+impl TransferBusinessAccountStars {
+    #[must_use]
+    pub fn business_connection_id_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            business_connection_id: val.map(Into::into),
+            ..self
+        }
+    }
+}
+
+impl WithBusinessConnectionId for TransferBusinessAccountStars {
+    fn business_connection_id_ref(&self) -> Option<&str> {
+        self.business_connection_id.as_deref()
+    }
+
+    fn business_connection_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.business_connection_id
+    }
+}
+
+impl TelegramMethod for TransferBusinessAccountStars {
+    type Method = Self;
+    type Return = bool;
+
+    fn build_request<Client>(&self, bot: &Bot<Client>) -> Request<'_, Self::Method> {
+        let mut data = Cow::Borrowed(self);
+
+        bot.apply_business_connection_id(&mut data);
+
+        Request::new_cow("transferBusinessAccountStars", data, None)
+    }
+
+    fn validate<Client>(&self, bot: &Bot<Client>) -> Result<(), SessionErrorKind> {
+        bot.require_business_connection_id(self)
+    }
+}
+
+impl AsRef<TransferBusinessAccountStars> for TransferBusinessAccountStars {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
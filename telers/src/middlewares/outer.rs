@@ -18,9 +18,11 @@
 pub mod base;
 pub mod fsm_context;
 pub mod manager;
+pub mod media_group;
 pub mod user_context;
 
 pub use base::{Middleware, MiddlewareResponse};
 pub use fsm_context::FSMContext;
 pub use manager::Manager;
+pub use media_group::{MediaGroup, MediaGroupCollector};
 pub use user_context::UserContext;
@@ -0,0 +1,262 @@
+//! Media groups (albums) are received as several separate messages with the same `media_group_id`,
+//! so handlers are called once per each message of the media group.
+//!
+//! [`MediaGroupCollector`] middleware buffers messages of the same media group for some time,
+//! and then propagates only the first message with all messages of the media group in the [`MediaGroup`] extractor.
+//! The rest messages of the media group are cancelled.
+//!
+//! Storages to buffer messages:
+//! * [`Memory`]:
+//!   In-memory storage, works only within one process.
+//! * [`Redis`] (feature: `redis-storage`):
+//!   Redis storage, use it if you run several instances of the bot.
+//!
+//! ```no_run
+//! use telers::{
+//!     client::Reqwest,
+//!     event::{telegram::HandlerResult, EventReturn},
+//!     middlewares::outer::{media_group::Memory, MediaGroup, MediaGroupCollector},
+//!     Router,
+//! };
+//!
+//! async fn handler(media_group: Option<MediaGroup>) -> HandlerResult {
+//!     // `None` for messages without media group
+//!     Ok(EventReturn::Finish)
+//! }
+//!
+//! let mut router = Router::<Reqwest>::new("main");
+//! router.message.outer_middlewares.register(MediaGroupCollector::new(Memory::new()));
+//! router.message.register(handler);
+//! ```
+
+pub mod base;
+pub mod memory;
+#[cfg(feature = "redis-storage")]
+pub mod redis;
+
+#[cfg(feature = "redis-storage")]
+pub use self::redis::Redis;
+pub use base::{MediaGroupKey, Storage};
+pub use memory::Memory;
+
+use super::{Middleware, MiddlewareResponse};
+
+use crate::{
+    errors::{EventErrorKind, MiddlewareError},
+    event::EventReturn,
+    extractors::FromContext,
+    router::Request,
+    types::Message,
+};
+
+use async_trait::async_trait;
+use std::time::Duration;
+use tracing::{event, instrument, Level};
+
+/// Default time to wait for the rest messages of the media group after the first one is received
+pub const DEFAULT_WINDOW: Duration = Duration::from_millis(500);
+
+/// Represents all messages of the media group (album) sorted by their IDs
+#[derive(Debug, Clone, PartialEq, FromContext)]
+#[context(
+    key = "media_group",
+    description = "Messages of the media group. This type is available only if the media group collector middleware is used and the message is part of the media group."
+)]
+pub struct MediaGroup {
+    pub messages: Box<[Message]>,
+}
+
+/// Middleware for collecting messages of media groups (albums),
+/// so handlers are called once per media group with [`MediaGroup`] in context.
+/// Messages without media group are passed as is
+#[derive(Debug, Clone)]
+pub struct MediaGroupCollector<S = Memory> {
+    storage: S,
+    window: Duration,
+}
+
+impl<S> MediaGroupCollector<S> {
+    #[must_use]
+    pub fn new(storage: S) -> Self {
+        Self {
+            storage,
+            window: DEFAULT_WINDOW,
+        }
+    }
+
+    /// Time to wait for the rest messages of the media group after the first one is received
+    #[must_use]
+    pub fn window(self, val: Duration) -> Self {
+        Self {
+            window: val,
+            ..self
+        }
+    }
+}
+
+impl<S> Default for MediaGroupCollector<S>
+where
+    S: Default,
+{
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+#[async_trait]
+impl<Client, S> Middleware<Client> for MediaGroupCollector<S>
+where
+    Client: Send + Sync + 'static,
+    S: Storage + 'static,
+{
+    #[instrument(skip(self, request))]
+    async fn call(
+        &self,
+        request: Request<Client>,
+    ) -> Result<MiddlewareResponse<Client>, EventErrorKind> {
+        let Some(message) = request.update.message() else {
+            return Ok((request, EventReturn::default()));
+        };
+        let Some(media_group_id) = message.media_group_id() else {
+            return Ok((request, EventReturn::default()));
+        };
+
+        let key = MediaGroupKey::new(request.bot.bot_id, message.chat().id(), media_group_id);
+
+        if !self
+            .storage
+            .push(&key, message)
+            .await
+            .map_err(MiddlewareError::new)?
+        {
+            event!(
+                Level::TRACE,
+                "Message is added to the media group, cancel its propagation"
+            );
+
+            return Ok((request, EventReturn::Cancel));
+        }
+
+        tokio::time::sleep(self.window).await;
+
+        let mut messages = self
+            .storage
+            .take(&key)
+            .await
+            .map_err(MiddlewareError::new)?;
+        messages.sort_by_key(Message::id);
+
+        request.context.insert(
+            "media_group",
+            Box::new(MediaGroup {
+                messages: messages.into(),
+            }),
+        );
+
+        Ok((request, EventReturn::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{Bot, Reqwest},
+        context::Context,
+        enums::UpdateType,
+        event::ToServiceProvider as _,
+        router::{PropagateEvent as _, Router},
+        types::{Update, UpdateKind},
+    };
+
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    fn photo(id: i64, media_group_id: &str) -> Arc<Update> {
+        let message = serde_json::from_value(serde_json::json!({
+            "message_id": id,
+            "date": 0,
+            "chat": {
+                "id": 1,
+                "type": "private",
+            },
+            "media_group_id": media_group_id,
+            "photo": [{
+                "file_id": "test",
+                "file_unique_id": "test",
+                "width": 1,
+                "height": 1,
+            }],
+        }))
+        .unwrap();
+
+        Arc::new(Update {
+            kind: UpdateKind::Message(message),
+            ..Default::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_media_group_collector() {
+        let bot = Arc::new(Bot::<Reqwest>::default());
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let mut router = Router::new("main");
+        router
+            .message
+            .outer_middlewares
+            .register(MediaGroupCollector::<Memory>::default().window(Duration::from_millis(50)));
+        router.message.register({
+            let calls = Arc::clone(&calls);
+
+            move |media_group: Option<MediaGroup>| {
+                let calls = Arc::clone(&calls);
+
+                async move {
+                    calls.fetch_add(1, Ordering::SeqCst);
+
+                    if let Some(media_group) = media_group {
+                        let ids = media_group
+                            .messages
+                            .iter()
+                            .map(Message::id)
+                            .collect::<Vec<_>>();
+
+                        assert_eq!(ids, [1, 2, 3]);
+                    }
+
+                    Ok(EventReturn::default())
+                }
+            }
+        });
+
+        let router_service = router.to_service_provider_default().unwrap();
+
+        let updates = [
+            photo(2, "group"),
+            photo(1, "group"),
+            photo(3, "group"),
+            Arc::new(Update {
+                kind: UpdateKind::Message(Message::Text(Box::default())),
+                ..Default::default()
+            }),
+        ];
+
+        futures::future::join_all(updates.into_iter().map(|update| {
+            router_service.propagate_event(
+                UpdateType::Message,
+                Request::new(Arc::clone(&bot), update, Arc::new(Context::new())),
+            )
+        }))
+        .await
+        .into_iter()
+        .for_each(|result| {
+            result.unwrap();
+        });
+
+        // Once for the media group and once for the message without media group
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{fsm::storage::Error, types::Message};
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Key of a media group in the storage
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct MediaGroupKey {
+    pub bot_id: i64,
+    pub chat_id: i64,
+    pub media_group_id: Box<str>,
+}
+
+impl MediaGroupKey {
+    #[must_use]
+    pub fn new(bot_id: i64, chat_id: i64, media_group_id: impl Into<Box<str>>) -> Self {
+        Self {
+            bot_id,
+            chat_id,
+            media_group_id: media_group_id.into(),
+        }
+    }
+}
+
+/// Storage is used to buffer messages of media groups until all of them are received
+#[async_trait]
+pub trait Storage: Send + Sync {
+    /// Add the message to the media group
+    /// # Arguments
+    /// * `key` - Key of the media group
+    /// * `message` - Message of the media group
+    /// # Returns
+    /// `true` if the message is the first one of the media group,
+    /// so the caller is responsible for taking the media group after the rest messages are received
+    /// # Errors
+    /// If storage error occurs, when add the message
+    async fn push(&self, key: &MediaGroupKey, message: &Message) -> Result<bool, Error>;
+
+    /// Take all messages of the media group and remove the media group from the storage
+    /// # Arguments
+    /// * `key` - Key of the media group
+    /// # Returns
+    /// Messages of the media group in order of adding, if the media group doesn't exist, then empty list will be return
+    /// # Errors
+    /// If storage error occurs, when take the messages
+    async fn take(&self, key: &MediaGroupKey) -> Result<Vec<Message>, Error>;
+}
+
+#[async_trait]
+impl<T: ?Sized> Storage for Arc<T>
+where
+    T: Storage,
+{
+    async fn push(&self, key: &MediaGroupKey, message: &Message) -> Result<bool, Error> {
+        T::push(self, key, message).await
+    }
+
+    async fn take(&self, key: &MediaGroupKey) -> Result<Vec<Message>, Error> {
+        T::take(self, key).await
+    }
+}
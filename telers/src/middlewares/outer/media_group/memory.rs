@@ -0,0 +1,79 @@
+use super::{MediaGroupKey, Storage};
+
+use crate::{fsm::storage::Error, types::Message};
+
+use async_trait::async_trait;
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    sync::Arc,
+};
+use tokio::sync::Mutex;
+
+/// This is a simple thread-safe in-memory storage of media groups
+/// # Warning
+/// This storage works only within one process,
+/// if you run several instances of the bot, use [`super::Redis`] storage instead
+#[derive(Debug, Default, Clone)]
+pub struct Memory {
+    storage: Arc<Mutex<HashMap<MediaGroupKey, Vec<Message>>>>,
+}
+
+impl Memory {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl Storage for Memory {
+    async fn push(&self, key: &MediaGroupKey, message: &Message) -> Result<bool, Error> {
+        match self.storage.lock().await.entry(key.clone()) {
+            Entry::Occupied(mut entry) => {
+                entry.get_mut().push(message.clone());
+
+                Ok(false)
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(vec![message.clone()]);
+
+                Ok(true)
+            }
+        }
+    }
+
+    async fn take(&self, key: &MediaGroupKey) -> Result<Vec<Message>, Error> {
+        Ok(self.storage.lock().await.remove(key).unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::MessageText;
+
+    #[tokio::test]
+    async fn test_push_and_take() {
+        let storage = Memory::new();
+        let key = MediaGroupKey::new(0, 1, "group");
+        let first = Message::Text(Box::new(MessageText {
+            id: 1,
+            ..Default::default()
+        }));
+        let second = Message::Text(Box::new(MessageText {
+            id: 2,
+            ..Default::default()
+        }));
+
+        assert!(storage.push(&key, &first).await.unwrap());
+        assert!(!storage.push(&key, &second).await.unwrap());
+        assert!(storage
+            .push(&MediaGroupKey::new(0, 2, "group"), &first)
+            .await
+            .unwrap());
+
+        assert_eq!(storage.take(&key).await.unwrap(), [first.clone(), second]);
+        assert!(storage.take(&key).await.unwrap().is_empty());
+        assert!(storage.push(&key, &first).await.unwrap());
+    }
+}
@@ -0,0 +1,248 @@
+use super::{MediaGroupKey, Storage};
+
+use crate::{
+    fsm::storage::{redis::create_pool, Error},
+    types::Message,
+};
+
+use async_trait::async_trait;
+use deadpool_redis::{Connection, Pool, PoolError};
+use redis::{IntoConnectionInfo, RedisError};
+use std::time::Duration;
+use tracing::{event, instrument, Level, Span};
+
+const DEFAULT_PREFIX: &str = "media_group";
+const DEFAULT_SEPARATOR: &str = ":";
+/// Time to live of a media group in the storage, if it isn't taken, for example, because the bot instance is stopped
+const DEFAULT_TTL: Duration = Duration::from_secs(60);
+
+/// This is a thread-safe storage of media groups for redis.
+/// Use it instead of [`super::Memory`] storage, if you run several instances of the bot,
+/// so messages of one media group received by different instances are collected together
+#[derive(Clone)]
+pub struct Redis {
+    pool: Pool,
+    prefix: &'static str,
+    separator: &'static str,
+    ttl: Duration,
+}
+
+impl Redis {
+    /// # Errors
+    /// This method will return error if config is invalid
+    pub fn new<T>(connection_info: T) -> Result<Self, RedisError>
+    where
+        T: IntoConnectionInfo,
+    {
+        Ok(Self {
+            pool: create_pool(connection_info)?,
+            prefix: DEFAULT_PREFIX,
+            separator: DEFAULT_SEPARATOR,
+            ttl: DEFAULT_TTL,
+        })
+    }
+
+    #[must_use]
+    pub fn prefix(self, val: &'static str) -> Self {
+        Self {
+            prefix: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn separator(self, val: &'static str) -> Self {
+        Self {
+            separator: val,
+            ..self
+        }
+    }
+
+    /// Time to live of a media group in the storage, if it isn't taken.
+    /// It should be greater than the waiting time of the rest messages
+    #[must_use]
+    pub fn ttl(self, val: Duration) -> Self {
+        Self { ttl: val, ..self }
+    }
+
+    #[must_use]
+    fn build_key(&self, key: &MediaGroupKey) -> Box<str> {
+        [
+            self.prefix,
+            &key.bot_id.to_string(),
+            &key.chat_id.to_string(),
+            &key.media_group_id,
+        ]
+        .join(self.separator)
+        .into_boxed_str()
+    }
+
+    async fn get_connection(&self) -> Result<Connection, PoolError> {
+        self.pool.get().await
+    }
+}
+
+#[async_trait]
+impl Storage for Redis {
+    #[instrument(skip(self, key, message), fields(key))]
+    async fn push(&self, key: &MediaGroupKey, message: &Message) -> Result<bool, Error> {
+        let key = self.build_key(key);
+        let key_ref = key.as_ref();
+
+        Span::current().record("key", key_ref);
+
+        let plain_json = serde_json::to_string(message).map_err(|err| {
+            event!(Level::ERROR, error = %err, "Failed to serialize message");
+
+            Error::new(
+                format!("Failed to serialize message. Storage key: {key}"),
+                err,
+            )
+        })?;
+
+        let mut connection = self.get_connection().await.map_err(|err| {
+            event!(Level::ERROR, error = %err, "Failed to get redis connection");
+
+            Error::new(
+                format!("Failed to get redis connection. Storage key: {key}"),
+                err,
+            )
+        })?;
+
+        let (len,) = redis::pipe()
+            .atomic()
+            .cmd("RPUSH")
+            .arg(key_ref)
+            .arg(plain_json)
+            .cmd("PEXPIRE")
+            .arg(key_ref)
+            .arg(u64::try_from(self.ttl.as_millis()).unwrap_or(u64::MAX))
+            .ignore()
+            .query_async::<_, (usize,)>(&mut connection)
+            .await
+            .map_err(|err| {
+                event!(Level::ERROR, error = %err, "Failed to push message");
+
+                Error::new(format!("Failed to push message. Storage key: {key}"), err)
+            })?;
+
+        Ok(len == 1)
+    }
+
+    #[instrument(skip(self, key), fields(key))]
+    async fn take(&self, key: &MediaGroupKey) -> Result<Vec<Message>, Error> {
+        let key = self.build_key(key);
+        let key_ref = key.as_ref();
+
+        Span::current().record("key", key_ref);
+
+        let mut connection = self.get_connection().await.map_err(|err| {
+            event!(Level::ERROR, error = %err, "Failed to get redis connection");
+
+            Error::new(
+                format!("Failed to get redis connection. Storage key: {key}"),
+                err,
+            )
+        })?;
+
+        let (plain_jsons,) = redis::pipe()
+            .atomic()
+            .cmd("LRANGE")
+            .arg(key_ref)
+            .arg(0)
+            .arg(-1)
+            .cmd("DEL")
+            .arg(key_ref)
+            .ignore()
+            .query_async::<_, (Vec<String>,)>(&mut connection)
+            .await
+            .map_err(|err| {
+                event!(Level::ERROR, error = %err, "Failed to take messages");
+
+                Error::new(format!("Failed to take messages. Storage key: {key}"), err)
+            })?;
+
+        plain_jsons
+            .iter()
+            .map(|plain_json| {
+                serde_json::from_str(plain_json).map_err(|err| {
+                    event!(Level::ERROR, error = %err, "Failed to deserialize message");
+
+                    Error::new(
+                        format!("Failed to deserialize message. Storage key: {key}"),
+                        err,
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::MessageText;
+
+    #[test]
+    fn test_build_key() {
+        let storage = Redis::new("redis://127.0.0.1:6379")
+            .unwrap()
+            .prefix("album")
+            .separator("/");
+
+        assert_eq!(
+            &*storage.build_key(&MediaGroupKey::new(0, 1, "group")),
+            "album/0/1/group"
+        );
+    }
+
+    #[ignore = "requires a running redis server"]
+    #[tokio::test]
+    async fn test_push_and_take() {
+        let storage = Redis::new("redis://127.0.0.1:6379")
+            .unwrap()
+            .prefix("telers_test_media_group");
+        let key = MediaGroupKey::new(0, 1, "group");
+        let first = Message::Text(Box::new(MessageText {
+            id: 1,
+            ..Default::default()
+        }));
+        let second = Message::Text(Box::new(MessageText {
+            id: 2,
+            ..Default::default()
+        }));
+
+        // Clear messages left by previous runs
+        storage.take(&key).await.unwrap();
+
+        assert!(storage.push(&key, &first).await.unwrap());
+        assert!(!storage.push(&key, &second).await.unwrap());
+
+        assert_eq!(storage.take(&key).await.unwrap(), [first.clone(), second]);
+        assert!(storage.take(&key).await.unwrap().is_empty());
+        assert!(storage.push(&key, &first).await.unwrap());
+
+        storage.take(&key).await.unwrap();
+    }
+
+    #[ignore = "requires a running redis server"]
+    #[tokio::test]
+    async fn test_ttl() {
+        let storage = Redis::new("redis://127.0.0.1:6379")
+            .unwrap()
+            .prefix("telers_test_media_group")
+            .ttl(Duration::from_millis(100));
+        let key = MediaGroupKey::new(0, 2, "group");
+        let message = Message::Text(Box::new(MessageText {
+            id: 1,
+            ..Default::default()
+        }));
+
+        assert!(storage.push(&key, &message).await.unwrap());
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        // Messages are expired, so the media group starts again
+        assert!(storage.take(&key).await.unwrap().is_empty());
+    }
+}
@@ -136,6 +136,7 @@ use crate::{
             Observer as TelegramObserver,
         },
     },
+    filters::MenuCommand,
     middlewares::{
         inner::Logging as LoggingMiddleware, outer::UserContext as UserContextMiddleware,
         InnerMiddleware, OuterMiddleware,
@@ -151,7 +152,7 @@ use std::{
     iter::once,
     sync::Arc,
 };
-use tracing::{event, instrument, Level};
+use tracing::{event, instrument, span, Instrument as _, Level};
 
 pub struct Request<Client = Reqwest> {
     pub bot: Arc<Bot<Client>>,
@@ -267,6 +268,13 @@ pub trait PropagateEvent<Client>: Send + Sync {
     /// # Errors
     /// If any shutdown observer returns error
     async fn emit_shutdown(&self) -> SimpleHandlerResult;
+
+    /// Commands of the bot menu from filters of all handlers
+    /// # Notes
+    /// By default it returns nothing, [`Router`] service collects them from its observers and sub routers
+    fn menu_commands(&self) -> Vec<MenuCommand> {
+        vec![]
+    }
 }
 
 #[async_trait]
@@ -302,6 +310,10 @@ where
     async fn emit_shutdown(&self) -> SimpleHandlerResult {
         P::emit_shutdown(self).await
     }
+
+    fn menu_commands(&self) -> Vec<MenuCommand> {
+        P::menu_commands(self)
+    }
 }
 
 /// Router combines all event observers.
@@ -386,6 +398,7 @@ pub struct Router<Client> {
     pub chat_join_request: TelegramObserver<Client>,
     pub chat_boost: TelegramObserver<Client>,
     pub removed_chat_boost: TelegramObserver<Client>,
+    pub purchased_paid_media: TelegramObserver<Client>,
     /// This special event observer is used to handle all telegram events.
     /// It's called for router and its sub routers and before other telegram observers.
     /// This observer is useful for register important middlewares (often libraries) like `FSMContext` and `UserContext`,
@@ -430,6 +443,7 @@ where
             chat_join_request: TelegramObserver::new(TelegramObserverName::ChatJoinRequest),
             chat_boost: TelegramObserver::new(TelegramObserverName::ChatBoost),
             removed_chat_boost: TelegramObserver::new(TelegramObserverName::RemovedChatBoost),
+            purchased_paid_media: TelegramObserver::new(TelegramObserverName::PurchasedPaidMedia),
             update: TelegramObserver::new(TelegramObserverName::Update),
             startup: SimpleObserver::new(SimpleObserverName::Startup),
             shutdown: SimpleObserver::new(SimpleObserverName::Shutdown),
@@ -461,7 +475,7 @@ where
 impl<Client> Router<Client> {
     /// Get all telegram event observers
     #[must_use]
-    pub const fn telegram_observers(&self) -> [&TelegramObserver<Client>; 23] {
+    pub const fn telegram_observers(&self) -> [&TelegramObserver<Client>; 24] {
         [
             &self.message,
             &self.edited_message,
@@ -485,6 +499,7 @@ impl<Client> Router<Client> {
             &self.chat_join_request,
             &self.chat_boost,
             &self.removed_chat_boost,
+            &self.purchased_paid_media,
             &self.update,
         ]
     }
@@ -494,7 +509,7 @@ impl<Client> Router<Client> {
     /// This method is useful for registering middlewares to the many observers without code duplication and macros
     #[must_use]
     pub fn telegram_observers_mut(&mut self) -> Vec<&mut TelegramObserver<Client>> {
-        let mut observers = Vec::with_capacity(23);
+        let mut observers = Vec::with_capacity(24);
 
         observers.extend([
             &mut self.message,
@@ -519,6 +534,7 @@ impl<Client> Router<Client> {
             &mut self.chat_join_request,
             &mut self.chat_boost,
             &mut self.removed_chat_boost,
+            &mut self.purchased_paid_media,
             &mut self.update,
         ]);
 
@@ -657,6 +673,7 @@ where
             chat_join_request,
             chat_boost,
             removed_chat_boost,
+            purchased_paid_media,
             update
         );
 
@@ -703,6 +720,7 @@ where
             chat_join_request,
             chat_boost,
             removed_chat_boost,
+            purchased_paid_media,
             update
         );
 
@@ -740,6 +758,7 @@ where
             chat_join_request: self.chat_join_request.to_service_provider_default()?,
             chat_boost: self.chat_boost.to_service_provider_default()?,
             removed_chat_boost: self.removed_chat_boost.to_service_provider_default()?,
+            purchased_paid_media: self.purchased_paid_media.to_service_provider_default()?,
             update: self.update.to_service_provider_default()?,
             startup: self.startup.to_service_provider_default()?,
             shutdown: self.shutdown.to_service_provider_default()?,
@@ -773,6 +792,7 @@ pub struct Service<Client> {
     chat_join_request: TelegramObserverService<Client>,
     chat_boost: TelegramObserverService<Client>,
     removed_chat_boost: TelegramObserverService<Client>,
+    purchased_paid_media: TelegramObserverService<Client>,
 
     update: TelegramObserverService<Client>,
 
@@ -827,7 +847,14 @@ impl<Client> PropagateEvent<Client> for Service<Client> {
 
         let mut request = request;
         for middleware in observer.outer_middlewares() {
-            let (updated_request, event_return) = middleware.call(request.clone()).await?;
+            let (updated_request, event_return) = middleware
+                .call(request.clone())
+                .instrument(span!(
+                    Level::INFO,
+                    "outer_middleware",
+                    observer = %observer.event_name
+                ))
+                .await?;
 
             match event_return {
                 // If middleware returns finish then update request because the middleware could have changed it
@@ -927,7 +954,14 @@ impl<Client> PropagateEvent<Client> for Service<Client> {
 
         let mut request = request;
         for middleware in self.update.outer_middlewares() {
-            let (updated_request, event_return) = middleware.call(request.clone()).await?;
+            let (updated_request, event_return) = middleware
+                .call(request.clone())
+                .instrument(span!(
+                    Level::INFO,
+                    "outer_middleware",
+                    observer = %self.update.event_name
+                ))
+                .await?;
 
             match event_return {
                 // If middleware returns finish, then update request because the middleware could have changed it
@@ -1008,11 +1042,23 @@ impl<Client> PropagateEvent<Client> for Service<Client> {
         }
         Ok(())
     }
+
+    fn menu_commands(&self) -> Vec<MenuCommand> {
+        self.telegram_observers()
+            .into_iter()
+            .flat_map(TelegramObserverService::menu_commands)
+            .chain(
+                self.sub_routers
+                    .iter()
+                    .flat_map(PropagateEvent::<Client>::menu_commands),
+            )
+            .collect()
+    }
 }
 
 impl<Client> Service<Client> {
     #[must_use]
-    pub const fn telegram_observers(&self) -> [&TelegramObserverService<Client>; 23] {
+    pub const fn telegram_observers(&self) -> [&TelegramObserverService<Client>; 24] {
         [
             &self.message,
             &self.edited_message,
@@ -1036,6 +1082,7 @@ impl<Client> Service<Client> {
             &self.chat_join_request,
             &self.chat_boost,
             &self.removed_chat_boost,
+            &self.purchased_paid_media,
             &self.update,
         ]
     }
@@ -1073,6 +1120,7 @@ impl<Client> Service<Client> {
             UpdateType::ChatJoinRequest => &self.chat_join_request,
             UpdateType::ChatBoost => &self.chat_boost,
             UpdateType::RemovedChatBoost => &self.removed_chat_boost,
+            UpdateType::PurchasedPaidMedia => &self.purchased_paid_media,
         }
     }
 }
@@ -1149,6 +1197,7 @@ pub struct OuterMiddlewaresConfig<Client> {
     pub chat_join_request: Box<[Arc<dyn OuterMiddleware<Client>>]>,
     pub chat_boost: Box<[Arc<dyn OuterMiddleware<Client>>]>,
     pub removed_chat_boost: Box<[Arc<dyn OuterMiddleware<Client>>]>,
+    pub purchased_paid_media: Box<[Arc<dyn OuterMiddleware<Client>>]>,
     pub update: Box<[Arc<dyn OuterMiddleware<Client>>]>,
 }
 
@@ -1199,6 +1248,7 @@ impl<Client> Clone for OuterMiddlewaresConfig<Client> {
             chat_join_request: self.chat_join_request.clone(),
             chat_boost: self.chat_boost.clone(),
             removed_chat_boost: self.removed_chat_boost.clone(),
+            purchased_paid_media: self.purchased_paid_media.clone(),
             update: self.update.clone(),
         }
     }
@@ -1227,6 +1277,7 @@ pub struct OuterMiddlewaresConfigBuilder<Client> {
     pub chat_join_request: Vec<Arc<dyn OuterMiddleware<Client>>>,
     pub chat_boost: Vec<Arc<dyn OuterMiddleware<Client>>>,
     pub removed_chat_boost: Vec<Arc<dyn OuterMiddleware<Client>>>,
+    pub purchased_paid_media: Vec<Arc<dyn OuterMiddleware<Client>>>,
     pub update: Vec<Arc<dyn OuterMiddleware<Client>>>,
 }
 
@@ -1366,6 +1417,12 @@ impl<Client> OuterMiddlewaresConfigBuilder<Client> {
         self
     }
 
+    #[must_use]
+    pub fn purchased_paid_media(mut self, val: impl OuterMiddleware<Client> + 'static) -> Self {
+        self.purchased_paid_media.push(Arc::new(val));
+        self
+    }
+
     #[must_use]
     pub fn update(mut self, val: impl OuterMiddleware<Client> + 'static) -> Self {
         self.update.push(Arc::new(val));
@@ -1397,6 +1454,7 @@ impl<Client> OuterMiddlewaresConfigBuilder<Client> {
             chat_join_request: self.chat_join_request.into(),
             chat_boost: self.chat_boost.into(),
             removed_chat_boost: self.removed_chat_boost.into(),
+            purchased_paid_media: self.purchased_paid_media.into(),
             update: self.update.into(),
         }
     }
@@ -1428,6 +1486,7 @@ impl<Client> Default for OuterMiddlewaresConfigBuilder<Client> {
             chat_join_request: vec![],
             chat_boost: vec![],
             removed_chat_boost: vec![],
+            purchased_paid_media: vec![],
             update: vec![],
         }
     }
@@ -1456,6 +1515,7 @@ pub struct InnerMiddlewaresConfig<Client> {
     pub chat_join_request: Box<[Arc<dyn InnerMiddleware<Client>>]>,
     pub chat_boost: Box<[Arc<dyn InnerMiddleware<Client>>]>,
     pub removed_chat_boost: Box<[Arc<dyn InnerMiddleware<Client>>]>,
+    pub purchased_paid_media: Box<[Arc<dyn InnerMiddleware<Client>>]>,
     pub update: Box<[Arc<dyn InnerMiddleware<Client>>]>,
 }
 
@@ -1502,6 +1562,7 @@ where
             .chat_join_request(logging_middleware.clone())
             .chat_boost(logging_middleware.clone())
             .removed_chat_boost(logging_middleware.clone())
+            .purchased_paid_media(logging_middleware.clone())
             .update(logging_middleware)
             .build()
     }
@@ -1532,6 +1593,7 @@ impl<Client> Clone for InnerMiddlewaresConfig<Client> {
             chat_join_request: self.chat_join_request.clone(),
             chat_boost: self.chat_boost.clone(),
             removed_chat_boost: self.removed_chat_boost.clone(),
+            purchased_paid_media: self.purchased_paid_media.clone(),
             update: self.update.clone(),
         }
     }
@@ -1560,6 +1622,7 @@ pub struct InnerMiddlewaresConfigBuilder<Client> {
     pub chat_join_request: Vec<Arc<dyn InnerMiddleware<Client>>>,
     pub chat_boost: Vec<Arc<dyn InnerMiddleware<Client>>>,
     pub removed_chat_boost: Vec<Arc<dyn InnerMiddleware<Client>>>,
+    pub purchased_paid_media: Vec<Arc<dyn InnerMiddleware<Client>>>,
     pub update: Vec<Arc<dyn InnerMiddleware<Client>>>,
 }
 
@@ -1699,6 +1762,12 @@ impl<Client> InnerMiddlewaresConfigBuilder<Client> {
         self
     }
 
+    #[must_use]
+    pub fn purchased_paid_media(mut self, val: impl InnerMiddleware<Client> + 'static) -> Self {
+        self.purchased_paid_media.push(Arc::new(val));
+        self
+    }
+
     #[must_use]
     pub fn update(mut self, val: impl InnerMiddleware<Client> + 'static) -> Self {
         self.update.push(Arc::new(val));
@@ -1730,6 +1799,7 @@ impl<Client> InnerMiddlewaresConfigBuilder<Client> {
             chat_join_request: self.chat_join_request.into(),
             chat_boost: self.chat_boost.into(),
             removed_chat_boost: self.removed_chat_boost.into(),
+            purchased_paid_media: self.purchased_paid_media.into(),
             update: self.update.into(),
         }
     }
@@ -1761,6 +1831,7 @@ impl<Client> Default for InnerMiddlewaresConfigBuilder<Client> {
             chat_join_request: vec![],
             chat_boost: vec![],
             removed_chat_boost: vec![],
+            purchased_paid_media: vec![],
             update: vec![],
         }
     }
@@ -1898,6 +1969,7 @@ mod tests {
         router.chat_join_request.register(telegram_handler);
         router.chat_boost.register(telegram_handler);
         router.removed_chat_boost.register(telegram_handler);
+        router.purchased_paid_media.register(telegram_handler);
         router.update.register(telegram_handler);
         // Event observers
         router.startup.register(simple_handler, ());
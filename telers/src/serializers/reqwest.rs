@@ -6,7 +6,7 @@
 
 use reqwest::multipart::{Form, Part};
 use serde::{
-    ser::{Error as SerError, Impossible, SerializeSeq, SerializeStruct},
+    ser::{Error as SerError, Impossible, SerializeMap, SerializeSeq, SerializeStruct},
     Serialize, Serializer,
 };
 use std::{
@@ -39,6 +39,8 @@ impl SerError for Error {
 
 pub(crate) struct MultipartSerializer {
     form: RefCell<Form>,
+    /// Key of the current map entry, which is used for serializing maps like [`serde_json::Value`]
+    key: Option<String>,
 }
 
 struct PartSerializer;
@@ -57,6 +59,7 @@ impl MultipartSerializer {
     pub(crate) fn new() -> Self {
         Self {
             form: RefCell::new(Form::new()),
+            key: None,
         }
     }
 }
@@ -70,7 +73,7 @@ impl Serializer for MultipartSerializer {
     type SerializeTuple = Impossible<Self::Ok, Self::Error>;
     type SerializeTupleStruct = Impossible<Self::Ok, Self::Error>;
     type SerializeTupleVariant = Impossible<Self::Ok, Self::Error>;
-    type SerializeMap = Impossible<Self::Ok, Self::Error>;
+    type SerializeMap = Self;
     type SerializeStructVariant = Impossible<Self::Ok, Self::Error>;
 
     fn serialize_struct(
@@ -225,8 +228,8 @@ impl Serializer for MultipartSerializer {
         )))
     }
 
-    fn serialize_map(self, val: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
-        Err(Error::top_level(val))
+    fn serialize_map(self, _val: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(self)
     }
 
     fn serialize_struct_variant(
@@ -261,6 +264,49 @@ impl SerializeStruct for MultipartSerializer {
     }
 }
 
+impl SerializeMap for MultipartSerializer {
+    type Ok = Form;
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let serde_json::Value::String(key) = serde_json::to_value(key)? else {
+            return Err(Error::Custom("Map key must be a string".into()));
+        };
+
+        self.key = Some(key);
+
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let Some(key) = self.key.take() else {
+            return Err(Error::Custom(
+                "Map value is serialized before its key".into(),
+            ));
+        };
+
+        // Null values are skipped as `None` fields of the methods, because multipart form can't contain them
+        if serde_json::to_value(value)?.is_null() {
+            return Ok(());
+        }
+
+        let part = value.serialize(PartSerializer {})?;
+        self.form.replace(self.form.take().part(key, part));
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.form.into_inner())
+    }
+}
+
 impl Serializer for PartSerializer {
     type Ok = Part;
     type Error = Error;
@@ -270,7 +316,7 @@ impl Serializer for PartSerializer {
     type SerializeTuple = Impossible<Self::Ok, Self::Error>;
     type SerializeTupleStruct = Impossible<Self::Ok, Self::Error>;
     type SerializeTupleVariant = Impossible<Self::Ok, Self::Error>;
-    type SerializeMap = Impossible<Self::Ok, Self::Error>;
+    type SerializeMap = JsonPartSerializer;
     type SerializeStructVariant = Impossible<Self::Ok, Self::Error>;
 
     fn serialize_bool(self, val: bool) -> Result<Self::Ok, Self::Error> {
@@ -364,11 +410,11 @@ impl Serializer for PartSerializer {
     }
 
     fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
+        Err(Error::Custom("Cannot serialize a null value".into()))
     }
 
     fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
+        Err(Error::Custom("Cannot serialize a unit value".into()))
     }
 
     fn serialize_unit_struct(self, _val: &'static str) -> Result<Self::Ok, Self::Error> {
@@ -422,7 +468,10 @@ impl Serializer for PartSerializer {
     }
 
     fn serialize_map(self, _val: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
-        unimplemented!()
+        Ok(JsonPartSerializer {
+            buf: String::new(),
+            state: PartSerializerStructState::Empty,
+        })
     }
 
     fn serialize_struct_variant(
@@ -501,3 +550,96 @@ impl SerializeSeq for JsonPartSerializer {
         }
     }
 }
+
+impl JsonPartSerializer {
+    /// Close the serialized JSON object of the map
+    fn end_object(mut self) -> String {
+        match self.state {
+            PartSerializerStructState::Empty => "{}".to_owned(),
+            PartSerializerStructState::Rest => {
+                self.buf += "}";
+                self.buf
+            }
+        }
+    }
+}
+
+impl SerializeMap for JsonPartSerializer {
+    type Ok = Part;
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = serde_json::to_string(key)?;
+        match self.state {
+            PartSerializerStructState::Empty => {
+                self.state = PartSerializerStructState::Rest;
+
+                write!(&mut self.buf, "{{{key}")?;
+            }
+            PartSerializerStructState::Rest => write!(&mut self.buf, ",{key}")?,
+        }
+
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let value = serde_json::to_string(value)?;
+        write!(&mut self.buf, ":{value}")?;
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Part::text(self.end_object()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    #[test]
+    fn test_serialize_map() {
+        let data = json!({
+            "chat_id": 1,
+            "photo": "attach://photo",
+            "reply_markup": {"inline_keyboard": [[{"text": "test", "callback_data": "test"}]]},
+        });
+
+        assert!(data.serialize(MultipartSerializer::new()).is_ok());
+        assert!(json!([1, 2]).serialize(MultipartSerializer::new()).is_err());
+        assert!(json!({"chat_id": 1, "caption": null})
+            .serialize(MultipartSerializer::new())
+            .is_ok());
+        assert!(json!(null).serialize(PartSerializer {}).is_err());
+    }
+
+    #[test]
+    fn test_serialize_json_part_map() {
+        let mut serializer = JsonPartSerializer {
+            buf: String::new(),
+            state: PartSerializerStructState::Empty,
+        };
+        SerializeMap::serialize_entry(&mut serializer, "text", "test").unwrap();
+        SerializeMap::serialize_entry(&mut serializer, "callback_data", &1).unwrap();
+
+        assert_eq!(
+            serializer.end_object(),
+            r#"{"text":"test","callback_data":1}"#
+        );
+
+        let serializer = JsonPartSerializer {
+            buf: String::new(),
+            state: PartSerializerStructState::Empty,
+        };
+        assert_eq!(serializer.end_object(), "{}");
+    }
+}
@@ -39,6 +39,15 @@
 
 pub mod animation;
 pub mod audio;
+pub mod background_fill;
+pub mod background_fill_freeform_gradient;
+pub mod background_fill_gradient;
+pub mod background_fill_solid;
+pub mod background_type;
+pub mod background_type_chat_theme;
+pub mod background_type_fill;
+pub mod background_type_pattern;
+pub mod background_type_wallpaper;
 pub mod birthdate;
 pub mod bot_command;
 pub mod bot_command_scope;
@@ -52,6 +61,7 @@ pub mod bot_command_scope_default;
 pub mod bot_description;
 pub mod bot_name;
 pub mod bot_short_description;
+pub mod business_bot_rights;
 pub mod business_connection;
 pub mod business_intro;
 pub mod business_location;
@@ -62,6 +72,7 @@ pub mod callback_game;
 pub mod callback_query;
 pub mod chat;
 pub mod chat_administrator_rights;
+pub mod chat_background;
 pub mod chat_boost;
 pub mod chat_boost_added;
 pub mod chat_boost_removed;
@@ -70,6 +81,7 @@ pub mod chat_boost_source_gift_code;
 pub mod chat_boost_source_giveaway;
 pub mod chat_boost_source_premium;
 pub mod chat_boost_updated;
+pub mod chat_full_info;
 pub mod chat_id_kind;
 pub mod chat_invite_link;
 pub mod chat_join_request;
@@ -85,6 +97,10 @@ pub mod chat_member_updated;
 pub mod chat_permissions;
 pub mod chat_photo;
 pub mod chat_shared;
+pub mod checklist;
+pub mod checklist_task;
+pub mod checklist_tasks_added;
+pub mod checklist_tasks_done;
 pub mod chosen_inline_result;
 pub mod contact;
 pub mod dice;
@@ -103,6 +119,8 @@ pub mod game;
 pub mod game_high_score;
 pub mod general_forum_topic_hidden;
 pub mod general_forum_topic_unhidden;
+pub mod gift;
+pub mod gifts;
 pub mod giveaway;
 pub mod giveaway_completed;
 pub mod giveaway_created;
@@ -133,6 +151,8 @@ pub mod inline_query_result_venue;
 pub mod inline_query_result_video;
 pub mod inline_query_result_voice;
 pub mod inline_query_results_button;
+pub mod input_checklist;
+pub mod input_checklist_task;
 pub mod input_contact_message_content;
 pub mod input_file;
 pub mod input_invoice_message_content;
@@ -144,7 +164,17 @@ pub mod input_media_document;
 pub mod input_media_photo;
 pub mod input_media_video;
 pub mod input_message_content;
+pub mod input_paid_media;
+pub mod input_paid_media_photo;
+pub mod input_paid_media_video;
+pub mod input_poll_option;
+pub mod input_profile_photo;
+pub mod input_profile_photo_animated;
+pub mod input_profile_photo_static;
 pub mod input_sticker;
+pub mod input_story_content;
+pub mod input_story_content_photo;
+pub mod input_story_content_video;
 pub mod input_text_message_content;
 pub mod input_venue_message_content;
 pub mod invoice;
@@ -155,6 +185,7 @@ pub mod keyboard_button_request_users;
 pub mod labeled_price;
 pub mod link_preview_options;
 pub mod location;
+pub mod location_address;
 pub mod login_url;
 pub mod mask_position;
 pub mod maybe_inaccessible_message;
@@ -175,6 +206,16 @@ pub mod message_origin_user;
 pub mod message_reaction_count_updated;
 pub mod message_reaction_updated;
 pub mod order_info;
+pub mod owned_gift;
+pub mod owned_gift_regular;
+pub mod owned_gift_unique;
+pub mod owned_gifts;
+pub mod paid_media;
+pub mod paid_media_info;
+pub mod paid_media_photo;
+pub mod paid_media_preview;
+pub mod paid_media_purchased;
+pub mod paid_media_video;
 pub mod passport_data;
 pub mod passport_element_error;
 pub mod passport_element_error_data_field;
@@ -192,27 +233,62 @@ pub mod poll;
 pub mod poll_answer;
 pub mod poll_option;
 pub mod pre_checkout_query;
+pub mod prepared_inline_message;
 pub mod proximity_alert_triggered;
 pub mod reaction_count;
 pub mod reaction_type;
 pub mod reaction_type_custom_emoji;
 pub mod reaction_type_emoji;
+pub mod refunded_payment;
 pub mod reply_keyboard_markup;
 pub mod reply_keyboard_remove;
 pub mod reply_markup;
 pub mod reply_parameters;
 pub mod response_parameters;
+pub mod revenue_withdrawal_state;
+pub mod revenue_withdrawal_state_failed;
+pub mod revenue_withdrawal_state_pending;
+pub mod revenue_withdrawal_state_succeeded;
 pub mod sent_web_app_message;
 pub mod shared_user;
 pub mod shipping_address;
 pub mod shipping_option;
 pub mod shipping_query;
+pub mod star_amount;
+pub mod star_transaction;
+pub mod star_transactions;
 pub mod sticker;
 pub mod sticker_set;
 pub mod story;
+pub mod story_area;
+pub mod story_area_position;
+pub mod story_area_type;
+pub mod story_area_type_link;
+pub mod story_area_type_location;
+pub mod story_area_type_suggested_reaction;
+pub mod story_area_type_unique_gift;
+pub mod story_area_type_weather;
 pub mod successful_payment;
+pub mod suggested_post_approval_failed;
+pub mod suggested_post_approved;
+pub mod suggested_post_declined;
+pub mod suggested_post_info;
+pub mod suggested_post_paid;
+pub mod suggested_post_parameters;
+pub mod suggested_post_price;
+pub mod suggested_post_refunded;
 pub mod switch_inline_query_chosen_chat;
 pub mod text_quote;
+pub mod transaction_partner;
+pub mod transaction_partner_fragment;
+pub mod transaction_partner_other;
+pub mod transaction_partner_telegram_ads;
+pub mod transaction_partner_user;
+pub mod unique_gift;
+pub mod unique_gift_backdrop;
+pub mod unique_gift_backdrop_colors;
+pub mod unique_gift_model;
+pub mod unique_gift_symbol;
 pub mod update;
 pub mod user;
 pub mod user_chat_boosts;
@@ -234,6 +310,15 @@ pub mod write_access_allowed;
 
 pub use animation::Animation;
 pub use audio::Audio;
+pub use background_fill::BackgroundFill;
+pub use background_fill_freeform_gradient::BackgroundFillFreeformGradient;
+pub use background_fill_gradient::BackgroundFillGradient;
+pub use background_fill_solid::BackgroundFillSolid;
+pub use background_type::BackgroundType;
+pub use background_type_chat_theme::BackgroundTypeChatTheme;
+pub use background_type_fill::BackgroundTypeFill;
+pub use background_type_pattern::BackgroundTypePattern;
+pub use background_type_wallpaper::BackgroundTypeWallpaper;
 pub use birthdate::Birthdate;
 pub use bot_command::BotCommand;
 pub use bot_command_scope::BotCommandScope;
@@ -247,6 +332,7 @@ pub use bot_command_scope_default::BotCommandScopeDefault;
 pub use bot_description::BotDescription;
 pub use bot_name::BotName;
 pub use bot_short_description::BotShortDescription;
+pub use business_bot_rights::BusinessBotRights;
 pub use business_connection::BusinessConnection;
 pub use business_intro::BusinessIntro;
 pub use business_location::BusinessLocation;
@@ -260,6 +346,7 @@ pub use chat::{
     Supergroup as ChatSupergroup,
 };
 pub use chat_administrator_rights::ChatAdministratorRights;
+pub use chat_background::ChatBackground;
 pub use chat_boost::ChatBoost;
 pub use chat_boost_added::ChatBoostAdded;
 pub use chat_boost_removed::ChatBoostRemoved;
@@ -268,6 +355,10 @@ pub use chat_boost_source_gift_code::ChatBoostSourceGiftCode;
 pub use chat_boost_source_giveaway::ChatBoostSourceGiveaway;
 pub use chat_boost_source_premium::ChatBoostSourcePremium;
 pub use chat_boost_updated::ChatBoostUpdated;
+pub use chat_full_info::{
+    Channel as ChatFullInfoChannel, ChatFullInfo, Group as ChatFullInfoGroup,
+    Private as ChatFullInfoPrivate, Supergroup as ChatFullInfoSupergroup,
+};
 pub use chat_id_kind::ChatIdKind;
 pub use chat_invite_link::ChatInviteLink;
 pub use chat_join_request::ChatJoinRequest;
@@ -283,6 +374,10 @@ pub use chat_member_updated::ChatMemberUpdated;
 pub use chat_permissions::ChatPermissions;
 pub use chat_photo::ChatPhoto;
 pub use chat_shared::ChatShared;
+pub use checklist::Checklist;
+pub use checklist_task::ChecklistTask;
+pub use checklist_tasks_added::ChecklistTasksAdded;
+pub use checklist_tasks_done::ChecklistTasksDone;
 pub use chosen_inline_result::ChosenInlineResult;
 pub use contact::Contact;
 pub use dice::Dice;
@@ -324,6 +419,8 @@ pub use game::Game;
 pub use game_high_score::GameHighScore;
 pub use general_forum_topic_hidden::GeneralForumTopicHidden;
 pub use general_forum_topic_unhidden::GeneralForumTopicUnhidden;
+pub use gift::Gift;
+pub use gifts::Gifts;
 pub use giveaway::Giveaway;
 pub use giveaway_completed::GiveawayCompleted;
 pub use giveaway_created::GiveawayCreated;
@@ -354,6 +451,8 @@ pub use inline_query_result_venue::InlineQueryResultVenue;
 pub use inline_query_result_video::InlineQueryResultVideo;
 pub use inline_query_result_voice::InlineQueryResultVoice;
 pub use inline_query_results_button::InlineQueryResultsButton;
+pub use input_checklist::InputChecklist;
+pub use input_checklist_task::InputChecklistTask;
 pub use input_contact_message_content::InputContactMessageContent;
 pub use input_file::{
     BufferedFile as InputBufferedFile, FSFile as InputFSFile, FileId as InputFileId, InputFile,
@@ -368,7 +467,17 @@ pub use input_media_document::InputMediaDocument;
 pub use input_media_photo::InputMediaPhoto;
 pub use input_media_video::InputMediaVideo;
 pub use input_message_content::InputMessageContent;
+pub use input_paid_media::InputPaidMedia;
+pub use input_paid_media_photo::InputPaidMediaPhoto;
+pub use input_paid_media_video::InputPaidMediaVideo;
+pub use input_poll_option::InputPollOption;
+pub use input_profile_photo::InputProfilePhoto;
+pub use input_profile_photo_animated::InputProfilePhotoAnimated;
+pub use input_profile_photo_static::InputProfilePhotoStatic;
 pub use input_sticker::InputSticker;
+pub use input_story_content::InputStoryContent;
+pub use input_story_content_photo::InputStoryContentPhoto;
+pub use input_story_content_video::InputStoryContentVideo;
 pub use input_text_message_content::InputTextMessageContent;
 pub use input_venue_message_content::InputVenueMessageContent;
 pub use invoice::Invoice;
@@ -379,6 +488,7 @@ pub use keyboard_button_request_users::KeyboardButtonRequestUsers;
 pub use labeled_price::LabeledPrice;
 pub use link_preview_options::LinkPreviewOptions;
 pub use location::Location;
+pub use location_address::LocationAddress;
 pub use login_url::LoginUrl;
 pub use mask_position::MaskPosition;
 pub use maybe_inaccessible_message::MaybeInaccessibleMessage;
@@ -388,12 +498,15 @@ pub use menu_button_default::MenuButtonDefault;
 pub use menu_button_web_app::MenuButtonWebApp;
 pub use message::{
     Animation as MessageAnimation, Audio as MessageAudio,
-    ChannelChatCreated as MessageChannelChatCreated, ChatShared as MessageChatShared,
-    ConnectedWebsite as MessageConnectedWebsite, Contact as MessageContact,
-    DeleteChatPhoto as MessageDeleteChatPhoto, Dice as MessageDice, Document as MessageDocument,
-    ForumTopicClosed as MessageForumTopicClosed, ForumTopicCreated as MessageForumTopicCreated,
-    ForumTopicEdited as MessageForumTopicEdited, ForumTopicReopened as MessageForumTopicReopened,
-    Game as MessageGame, GeneralForumTopicHidden as MessageGeneralForumTopicHidden,
+    ChannelChatCreated as MessageChannelChatCreated, ChatBackgroundSet as MessageChatBackgroundSet,
+    ChatShared as MessageChatShared, Checklist as MessageChecklist,
+    ChecklistTasksAdded as MessageChecklistTasksAdded,
+    ChecklistTasksDone as MessageChecklistTasksDone, ConnectedWebsite as MessageConnectedWebsite,
+    Contact as MessageContact, DeleteChatPhoto as MessageDeleteChatPhoto, Dice as MessageDice,
+    Document as MessageDocument, ForumTopicClosed as MessageForumTopicClosed,
+    ForumTopicCreated as MessageForumTopicCreated, ForumTopicEdited as MessageForumTopicEdited,
+    ForumTopicReopened as MessageForumTopicReopened, Game as MessageGame,
+    GeneralForumTopicHidden as MessageGeneralForumTopicHidden,
     GeneralForumTopicUnhidden as MessageGeneralForumTopicUnhidden, Giveaway as MessageGiveaway,
     GiveawayCompleted as MessageGiveawayCompleted, GiveawayCreated as MessageGiveawayCreated,
     GiveawayWinners as MessageGiveawayWinners, GroupChatCreated as MessageGroupChatCreated,
@@ -402,10 +515,16 @@ pub use message::{
     MessageAutoDeleteTimerChanged as MessageMessageAutoDeleteTimerChanged,
     MigrateFromChat as MessageMigrateFromChat, MigrateToChat as MessageMigrateToChat,
     NewChatMembers as MessageNewChatMembers, NewChatPhoto as MessageNewChatPhoto,
-    NewChatTitle as MessageNewChatTitle, PassportData as MessagePassportData,
-    Photo as MessagePhoto, Pinned as MessagePinned, Poll as MessagePoll,
-    ProximityAlertTriggered as MessageProximityAlertTriggered, Sticker as MessageSticker,
-    Story as MessageStory, SuccessfulPayment as MessageSuccessfulPayment,
+    NewChatTitle as MessageNewChatTitle, PaidMedia as MessagePaidMedia,
+    PassportData as MessagePassportData, Photo as MessagePhoto, Pinned as MessagePinned,
+    Poll as MessagePoll, ProximityAlertTriggered as MessageProximityAlertTriggered,
+    RefundedPayment as MessageRefundedPayment, Sticker as MessageSticker, Story as MessageStory,
+    SuccessfulPayment as MessageSuccessfulPayment,
+    SuggestedPostApprovalFailed as MessageSuggestedPostApprovalFailed,
+    SuggestedPostApproved as MessageSuggestedPostApproved,
+    SuggestedPostDeclined as MessageSuggestedPostDeclined,
+    SuggestedPostPaid as MessageSuggestedPostPaid,
+    SuggestedPostRefunded as MessageSuggestedPostRefunded,
     SupergroupChatCreated as MessageSupergroupChatCreated, Text as MessageText,
     UsersShared as MessageUsersShared, Venue as MessageVenue, Video as MessageVideo,
     VideoChatEnded as MessageVideoChatEnded,
@@ -430,6 +549,16 @@ pub use message_origin_user::MessageOriginUser;
 pub use message_reaction_count_updated::MessageReactionCountUpdated;
 pub use message_reaction_updated::MessageReactionUpdated;
 pub use order_info::OrderInfo;
+pub use owned_gift::OwnedGift;
+pub use owned_gift_regular::OwnedGiftRegular;
+pub use owned_gift_unique::OwnedGiftUnique;
+pub use owned_gifts::OwnedGifts;
+pub use paid_media::PaidMedia;
+pub use paid_media_info::PaidMediaInfo;
+pub use paid_media_photo::PaidMediaPhoto;
+pub use paid_media_preview::PaidMediaPreview;
+pub use paid_media_purchased::PaidMediaPurchased;
+pub use paid_media_video::PaidMediaVideo;
 pub use passport_data::PassportData;
 pub use passport_element_error::PassportElementError;
 pub use passport_element_error_data_field::{
@@ -463,27 +592,62 @@ pub use poll::{Poll, Quiz as PollQuiz, Regular as PollRegular};
 pub use poll_answer::PollAnswer;
 pub use poll_option::PollOption;
 pub use pre_checkout_query::PreCheckoutQuery;
+pub use prepared_inline_message::PreparedInlineMessage;
 pub use proximity_alert_triggered::ProximityAlertTriggered;
 pub use reaction_count::ReactionCount;
 pub use reaction_type::ReactionType;
 pub use reaction_type_custom_emoji::ReactionTypeCustomEmoji;
 pub use reaction_type_emoji::ReactionTypeEmoji;
+pub use refunded_payment::RefundedPayment;
 pub use reply_keyboard_markup::ReplyKeyboardMarkup;
 pub use reply_keyboard_remove::ReplyKeyboardRemove;
 pub use reply_markup::ReplyMarkup;
 pub use reply_parameters::ReplyParameters;
 pub use response_parameters::ResponseParameters;
+pub use revenue_withdrawal_state::RevenueWithdrawalState;
+pub use revenue_withdrawal_state_failed::RevenueWithdrawalStateFailed;
+pub use revenue_withdrawal_state_pending::RevenueWithdrawalStatePending;
+pub use revenue_withdrawal_state_succeeded::RevenueWithdrawalStateSucceeded;
 pub use sent_web_app_message::SentWebAppMessage;
 pub use shared_user::SharedUser;
 pub use shipping_address::ShippingAddress;
 pub use shipping_option::ShippingOption;
 pub use shipping_query::ShippingQuery;
+pub use star_amount::StarAmount;
+pub use star_transaction::StarTransaction;
+pub use star_transactions::StarTransactions;
 pub use sticker::Sticker;
 pub use sticker_set::StickerSet;
 pub use story::Story;
+pub use story_area::StoryArea;
+pub use story_area_position::StoryAreaPosition;
+pub use story_area_type::StoryAreaType;
+pub use story_area_type_link::StoryAreaTypeLink;
+pub use story_area_type_location::StoryAreaTypeLocation;
+pub use story_area_type_suggested_reaction::StoryAreaTypeSuggestedReaction;
+pub use story_area_type_unique_gift::StoryAreaTypeUniqueGift;
+pub use story_area_type_weather::StoryAreaTypeWeather;
 pub use successful_payment::SuccessfulPayment;
+pub use suggested_post_approval_failed::SuggestedPostApprovalFailed;
+pub use suggested_post_approved::SuggestedPostApproved;
+pub use suggested_post_declined::SuggestedPostDeclined;
+pub use suggested_post_info::SuggestedPostInfo;
+pub use suggested_post_paid::SuggestedPostPaid;
+pub use suggested_post_parameters::SuggestedPostParameters;
+pub use suggested_post_price::SuggestedPostPrice;
+pub use suggested_post_refunded::SuggestedPostRefunded;
 pub use switch_inline_query_chosen_chat::SwitchInlineQueryChosenChat;
 pub use text_quote::TextQuote;
+pub use transaction_partner::TransactionPartner;
+pub use transaction_partner_fragment::TransactionPartnerFragment;
+pub use transaction_partner_other::TransactionPartnerOther;
+pub use transaction_partner_telegram_ads::TransactionPartnerTelegramAds;
+pub use transaction_partner_user::TransactionPartnerUser;
+pub use unique_gift::UniqueGift;
+pub use unique_gift_backdrop::UniqueGiftBackdrop;
+pub use unique_gift_backdrop_colors::UniqueGiftBackdropColors;
+pub use unique_gift_model::UniqueGiftModel;
+pub use unique_gift_symbol::UniqueGiftSymbol;
 pub use update::{Kind as UpdateKind, Update};
 pub use user::User;
 pub use user_chat_boosts::UserChatBoosts;
@@ -1,11 +1,18 @@
 use super::PhotoSize;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[cfg(feature = "chrono")]
+use crate::utils::date_time;
+#[cfg(feature = "chrono")]
+use chrono::TimeDelta;
 
 /// This object represents an audio file to be treated as music by the Telegram clients.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#audio>
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Audio {
     /// Identifier for this file, which can be used to download or reuse the file
     pub file_id: Box<str>,
@@ -26,3 +33,12 @@ pub struct Audio {
     /// Thumbnail of the album cover to which the music file belongs
     pub thumbnail: Option<PhotoSize>,
 }
+
+#[cfg(feature = "chrono")]
+impl Audio {
+    /// Duration of the media as [`TimeDelta`]
+    #[must_use]
+    pub fn duration_delta(&self) -> TimeDelta {
+        date_time::from_seconds(self.duration)
+    }
+}
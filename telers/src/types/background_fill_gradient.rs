@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// The background is a gradient fill.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#backgroundfillgradient>
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BackgroundFillGradient {
+    /// Top color of the gradient in the RGB24 format
+    pub top_color: i64,
+    /// Bottom color of the gradient in the RGB24 format
+    pub bottom_color: i64,
+    /// Clockwise rotation angle of the background fill in degrees; 0-359
+    pub rotation_angle: i64,
+}
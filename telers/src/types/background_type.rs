@@ -0,0 +1,45 @@
+use super::{
+    BackgroundTypeChatTheme, BackgroundTypeFill, BackgroundTypePattern, BackgroundTypeWallpaper,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// This object describes the type of a background. Currently, it can be one of
+/// - [`BackgroundTypeFill`]
+/// - [`BackgroundTypeWallpaper`]
+/// - [`BackgroundTypePattern`]
+/// - [`BackgroundTypeChatTheme`]
+/// # Documentation
+/// <https://core.telegram.org/bots/api#backgroundtype>
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BackgroundType {
+    Fill(BackgroundTypeFill),
+    Wallpaper(BackgroundTypeWallpaper),
+    Pattern(BackgroundTypePattern),
+    ChatTheme(BackgroundTypeChatTheme),
+}
+
+impl From<BackgroundTypeFill> for BackgroundType {
+    fn from(background_type: BackgroundTypeFill) -> Self {
+        Self::Fill(background_type)
+    }
+}
+
+impl From<BackgroundTypeWallpaper> for BackgroundType {
+    fn from(background_type: BackgroundTypeWallpaper) -> Self {
+        Self::Wallpaper(background_type)
+    }
+}
+
+impl From<BackgroundTypePattern> for BackgroundType {
+    fn from(background_type: BackgroundTypePattern) -> Self {
+        Self::Pattern(background_type)
+    }
+}
+
+impl From<BackgroundTypeChatTheme> for BackgroundType {
+    fn from(background_type: BackgroundTypeChatTheme) -> Self {
+        Self::ChatTheme(background_type)
+    }
+}
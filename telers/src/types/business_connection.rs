@@ -1,13 +1,15 @@
-use super::{Update, UpdateKind, User};
+use super::{BusinessBotRights, Update, UpdateKind, User};
 
 use crate::{errors::ConvertToTypeError, FromEvent};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 /// Describes the connection of the bot with a business account.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#businessconnection>
-#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct BusinessConnection {
     /// Unique identifier of the business connection
@@ -18,8 +20,8 @@ pub struct BusinessConnection {
     pub user_chat_id: i64,
     /// Date the connection was established in Unix time
     pub date: i64,
-    /// `true`, if the bot can act on behalf of the business account in chats that were active in the last 24 hours
-    pub can_reply: bool,
+    /// Rights of the business bot
+    pub rights: Option<BusinessBotRights>,
     /// `true`, if the connection is active
     pub is_enabled: bool,
 }
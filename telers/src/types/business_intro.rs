@@ -1,10 +1,12 @@
 use super::Sticker;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 /// # Documentation
 /// <https://core.telegram.org/bots/api#businessintro>
-#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
 pub struct BusinessIntro {
     /// Title text of the business intro
     pub title: Option<Box<str>>,
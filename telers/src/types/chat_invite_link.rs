@@ -1,11 +1,18 @@
 use super::User;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[cfg(feature = "chrono")]
+use crate::utils::date_time;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, TimeDelta, Utc};
 
 /// Represents an invite link for a chat.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#chatinvitelink>
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
 pub struct ChatInviteLink {
     /// The invite link. If the link was created by another chat administrator, then the second part of the link will be replaced with '…'.
     pub invite_link: Box<str>,
@@ -25,4 +32,23 @@ pub struct ChatInviteLink {
     pub member_limit: Option<i64>,
     /// Number of pending join requests created using this link
     pub pending_join_request_count: Option<i64>,
+    /// The number of seconds the subscription will be active for before the next payment
+    pub subscription_period: Option<i64>,
+    /// The amount of Telegram Stars a user must pay initially and after each subsequent subscription period to be a member of the chat using the link
+    pub subscription_price: Option<i64>,
+}
+
+#[cfg(feature = "chrono")]
+impl ChatInviteLink {
+    /// Date when the link will expire or has been expired
+    #[must_use]
+    pub fn expire_date_utc(&self) -> Option<DateTime<Utc>> {
+        self.expire_date.and_then(date_time::from_timestamp)
+    }
+
+    /// Duration of the subscription before the next payment as [`TimeDelta`]
+    #[must_use]
+    pub fn subscription_period_delta(&self) -> Option<TimeDelta> {
+        self.subscription_period.map(date_time::from_seconds)
+    }
 }
@@ -1,14 +1,28 @@
 use super::User;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "chrono")]
+use crate::utils::date_time;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
 
 /// Represents a [`ChatMember`](crate::types::ChatMember) that was banned in the chat and can't return to the chat or view chat messages.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#chatmemberbanned>
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
 pub struct ChatMemberBanned {
     /// Information about the user
     pub user: User,
     /// Date when restrictions will be lifted for this user; Unix time. If 0, then the user is banned forever
     pub until_date: i64,
 }
+
+#[cfg(feature = "chrono")]
+impl ChatMemberBanned {
+    /// Date when restrictions will be lifted for this user. [`None`] if the user is banned forever
+    #[must_use]
+    pub fn until_date_utc(&self) -> Option<DateTime<Utc>> {
+        date_time::from_timestamp_or_forever(self.until_date)
+    }
+}
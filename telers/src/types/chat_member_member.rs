@@ -1,12 +1,31 @@
 use super::User;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[cfg(feature = "chrono")]
+use crate::utils::date_time;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
 
 /// Represents a [`ChatMember`](crate::types::ChatMember) that has no additional privileges or restrictions.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#chatmembermember>
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
 pub struct ChatMemberMember {
     /// Information about the user
     pub user: User,
+    /// Date when the user's subscription will expire; Unix time
+    pub until_date: Option<i64>,
+}
+
+#[cfg(feature = "chrono")]
+impl ChatMemberMember {
+    /// Date when the user's subscription will expire. [`None`] if the user isn't a subscription member
+    #[must_use]
+    pub fn until_date_utc(&self) -> Option<DateTime<Utc>> {
+        self.until_date
+            .and_then(date_time::from_timestamp_or_forever)
+    }
 }
@@ -1,11 +1,13 @@
 use super::User;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 /// Represents a [`ChatMember`](crate::types::ChatMember) that owns the chat and has all administrator privileges.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#chatmemberowner>
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
 pub struct ChatMemberOwner {
     /// Information about the user
     pub user: User,
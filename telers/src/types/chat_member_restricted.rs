@@ -1,12 +1,17 @@
 use super::User;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "chrono")]
+use crate::utils::date_time;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
 
 /// Represents a [`ChatMember`](crate::types::ChatMember) that is under certain restrictions in the chat. Supergroups only.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#chatmemberrestricted>
 #[allow(clippy::struct_excessive_bools)]
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
 pub struct ChatMemberRestricted {
     /// Information about the user
     pub user: User,
@@ -43,3 +48,12 @@ pub struct ChatMemberRestricted {
     /// Date when restrictions will be lifted for this user; Unix time. If 0, then the user is restricted forever
     pub until_date: i64,
 }
+
+#[cfg(feature = "chrono")]
+impl ChatMemberRestricted {
+    /// Date when restrictions will be lifted for this user. [`None`] if the user is restricted forever
+    #[must_use]
+    pub fn until_date_utc(&self) -> Option<DateTime<Utc>> {
+        date_time::from_timestamp_or_forever(self.until_date)
+    }
+}
@@ -1,13 +1,15 @@
-use super::{Chat, ChatInviteLink, ChatMember, Update, UpdateKind, User};
+use super::{Chat, ChatInviteLink, ChatMember, ChatMemberMember, Update, UpdateKind, User};
 
 use crate::{errors::ConvertToTypeError, FromEvent};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 /// This object represents changes in the status of a chat member.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#chatmemberupdated>
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct ChatMemberUpdated {
     /// Chat the user belongs to
@@ -26,6 +28,43 @@ pub struct ChatMemberUpdated {
     pub via_chat_folder_invite_link: Option<bool>,
 }
 
+impl ChatMemberUpdated {
+    /// Returns `true` if the user's paid subscription to the chat was renewed,
+    /// i.e. the user stays a member and the subscription `until_date` was moved forward
+    #[must_use]
+    pub const fn is_subscription_renewal(&self) -> bool {
+        match (&self.old_chat_member, &self.new_chat_member) {
+            (
+                ChatMember::Member(ChatMemberMember {
+                    until_date: Some(old_until_date),
+                    ..
+                }),
+                ChatMember::Member(ChatMemberMember {
+                    until_date: Some(new_until_date),
+                    ..
+                }),
+            ) => *new_until_date > *old_until_date,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the user's paid subscription to the chat expired,
+    /// i.e. the user with a subscription was removed from the chat not earlier than the subscription `until_date`
+    #[must_use]
+    pub const fn is_subscription_expiration(&self) -> bool {
+        match (&self.old_chat_member, &self.new_chat_member) {
+            (
+                ChatMember::Member(ChatMemberMember {
+                    until_date: Some(until_date),
+                    ..
+                }),
+                ChatMember::Left(_) | ChatMember::Banned(_),
+            ) => *until_date <= self.date,
+            _ => false,
+        }
+    }
+}
+
 impl TryFrom<Update> for ChatMemberUpdated {
     type Error = ConvertToTypeError;
 
@@ -36,3 +75,58 @@ impl TryFrom<Update> for ChatMemberUpdated {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chat_member_updated(old_chat_member: &str, new_chat_member: &str) -> ChatMemberUpdated {
+        serde_json::from_str(&format!(
+            r#"{{
+                "chat": {{"id": -1, "type": "channel", "title": "test"}},
+                "from": {{"id": 1, "is_bot": false, "first_name": "test"}},
+                "date": 100,
+                "old_chat_member": {old_chat_member},
+                "new_chat_member": {new_chat_member}
+            }}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_subscription_renewal() {
+        let user = r#"{"id": 1, "is_bot": false, "first_name": "test"}"#;
+
+        let update = chat_member_updated(
+            &format!(r#"{{"status": "member", "user": {user}, "until_date": 100}}"#),
+            &format!(r#"{{"status": "member", "user": {user}, "until_date": 200}}"#),
+        );
+        assert!(update.is_subscription_renewal());
+        assert!(!update.is_subscription_expiration());
+
+        let update = chat_member_updated(
+            &format!(r#"{{"status": "member", "user": {user}}}"#),
+            &format!(r#"{{"status": "member", "user": {user}}}"#),
+        );
+        assert!(!update.is_subscription_renewal());
+    }
+
+    #[test]
+    fn test_subscription_expiration() {
+        let user = r#"{"id": 1, "is_bot": false, "first_name": "test"}"#;
+
+        let update = chat_member_updated(
+            &format!(r#"{{"status": "member", "user": {user}, "until_date": 100}}"#),
+            &format!(r#"{{"status": "left", "user": {user}}}"#),
+        );
+        assert!(update.is_subscription_expiration());
+        assert!(!update.is_subscription_renewal());
+
+        // The user left the chat before the subscription expired
+        let update = chat_member_updated(
+            &format!(r#"{{"status": "member", "user": {user}, "until_date": 200}}"#),
+            &format!(r#"{{"status": "left", "user": {user}}}"#),
+        );
+        assert!(!update.is_subscription_expiration());
+    }
+}
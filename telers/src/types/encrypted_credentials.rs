@@ -1,9 +1,9 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Describes data required for decrypting and authenticating [`EncryptedPassportElement`](crate::types::EncryptedPassportElement). See the [`Telegram Passport Documentation`](https://core.telegram.org/passport#receiving-information) for a complete description of the data decryption and authentication processes.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#encryptedcredentials>
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
 pub struct EncryptedCredentials {
     /// Base64-encoded encrypted JSON-serialized data with unique user's payload, data hashes and secrets required for [`EncryptedPassportElement`](crate::types::EncryptedPassportElement) decryption and authentication
     pub data: Box<str>,
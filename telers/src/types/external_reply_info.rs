@@ -2,18 +2,20 @@ use super::{Chat, LinkPreviewOptions, MessageOrigin};
 
 use crate::types;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 /// This object contains information about a message that is being replied to, which may come from another chat or forum topic
 /// # Documentation
 /// <https://core.telegram.org/bots/api#externalreplyinfo>
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum ExternalReplyInfo {
     Animation(Box<Animation>),
     Audio(Box<Audio>),
     Document(Box<Document>),
     Photo(Box<Photo>),
+    PaidMedia(Box<PaidMedia>),
     Sticker(Box<Sticker>),
     Story(Box<Story>),
     Video(Box<Video>),
@@ -28,10 +30,12 @@ pub enum ExternalReplyInfo {
     Venue(Box<Venue>),
     Location(Box<Location>),
     Poll(Box<Poll>),
+    Checklist(Box<Checklist>),
     Text(Box<Text>),
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Text {
     /// Origin of the message replied to by the given message
     pub origin: MessageOrigin,
@@ -43,7 +47,8 @@ pub struct Text {
     pub link_preview_options: Option<LinkPreviewOptions>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Animation {
     /// Origin of the message replied to by the given message
     pub origin: MessageOrigin,
@@ -57,7 +62,8 @@ pub struct Animation {
     pub has_media_spoiler: Option<bool>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Audio {
     /// Origin of the message replied to by the given message
     pub origin: MessageOrigin,
@@ -69,7 +75,8 @@ pub struct Audio {
     pub audio: types::Audio,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Document {
     /// Origin of the message replied to by the given message
     pub origin: MessageOrigin,
@@ -81,7 +88,8 @@ pub struct Document {
     pub document: types::Document,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Photo {
     /// Origin of the message replied to by the given message
     pub origin: MessageOrigin,
@@ -95,7 +103,21 @@ pub struct Photo {
     pub has_media_spoiler: Option<bool>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PaidMedia {
+    /// Origin of the message replied to by the given message
+    pub origin: MessageOrigin,
+    /// Chat the original message belongs to. Available only if the chat is a supergroup or a channel.
+    pub chat: Option<Chat>,
+    /// Unique message identifier inside the original chat. Available only if the original chat is a supergroup or a channel.
+    pub message_id: Option<i64>,
+    /// Message contains paid media; information about the paid media
+    pub paid_media: types::PaidMediaInfo,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Sticker {
     /// Origin of the message replied to by the given message
     pub origin: MessageOrigin,
@@ -107,7 +129,8 @@ pub struct Sticker {
     pub sticker: types::Sticker,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Story {
     /// Origin of the message replied to by the given message
     pub origin: MessageOrigin,
@@ -119,7 +142,8 @@ pub struct Story {
     pub story: types::Story,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Video {
     /// Origin of the message replied to by the given message
     pub origin: MessageOrigin,
@@ -133,7 +157,8 @@ pub struct Video {
     pub has_media_spoiler: Option<bool>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct VideoNote {
     /// Origin of the message replied to by the given message
     pub origin: MessageOrigin,
@@ -145,7 +170,8 @@ pub struct VideoNote {
     pub video_note: types::VideoNote,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Voice {
     /// Origin of the message replied to by the given message
     pub origin: MessageOrigin,
@@ -157,7 +183,8 @@ pub struct Voice {
     pub voice: types::Voice,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Contact {
     /// Origin of the message replied to by the given message
     pub origin: MessageOrigin,
@@ -169,7 +196,8 @@ pub struct Contact {
     pub contact: types::Contact,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Dice {
     /// Origin of the message replied to by the given message
     pub origin: MessageOrigin,
@@ -181,7 +209,8 @@ pub struct Dice {
     pub dice: types::Dice,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Game {
     /// Origin of the message replied to by the given message
     pub origin: MessageOrigin,
@@ -193,7 +222,8 @@ pub struct Game {
     pub game: types::Game,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Giveaway {
     /// Origin of the message replied to by the given message
     pub origin: MessageOrigin,
@@ -205,7 +235,8 @@ pub struct Giveaway {
     pub giveaway: types::Giveaway,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct GiveawayWinners {
     /// Origin of the message replied to by the given message
     pub origin: MessageOrigin,
@@ -217,7 +248,8 @@ pub struct GiveawayWinners {
     pub giveaway_winners: types::GiveawayWinners,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Invoice {
     /// Origin of the message replied to by the given message
     pub origin: MessageOrigin,
@@ -229,7 +261,8 @@ pub struct Invoice {
     pub invoice: types::Invoice,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Location {
     /// Origin of the message replied to by the given message
     pub origin: MessageOrigin,
@@ -241,7 +274,8 @@ pub struct Location {
     pub location: types::Location,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Poll {
     /// Origin of the message replied to by the given message
     pub origin: MessageOrigin,
@@ -253,7 +287,21 @@ pub struct Poll {
     pub poll: types::Poll,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Checklist {
+    /// Origin of the message replied to by the given message
+    pub origin: MessageOrigin,
+    /// Chat the original message belongs to. Available only if the chat is a supergroup or a channel.
+    pub chat: Option<Chat>,
+    /// Unique message identifier inside the original chat. Available only if the original chat is a supergroup or a channel.
+    pub message_id: Option<i64>,
+    /// Message is a checklist
+    pub checklist: types::Checklist,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Venue {
     /// Origin of the message replied to by the given message
     pub origin: MessageOrigin,
@@ -285,7 +333,10 @@ mod tests {
 
         for json in jsons {
             let external_reply_info_kind = serde_json::from_value(json.clone()).unwrap();
-            let external_reply_info: ExternalReplyInfo = serde_json::from_value(json).unwrap();
+            let external_reply_info: ExternalReplyInfo =
+                serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&external_reply_info).unwrap(), json);
 
             match external_reply_info {
                 ExternalReplyInfo::Text(external_reply_info) => {
@@ -319,7 +370,10 @@ mod tests {
 
         for json in jsons {
             let external_reply_info_kind = serde_json::from_value(json.clone()).unwrap();
-            let external_reply_info: ExternalReplyInfo = serde_json::from_value(json).unwrap();
+            let external_reply_info: ExternalReplyInfo =
+                serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&external_reply_info).unwrap(), json);
 
             match external_reply_info {
                 ExternalReplyInfo::Animation(external_reply_info) => {
@@ -351,7 +405,10 @@ mod tests {
 
         for json in jsons {
             let external_reply_info_kind = serde_json::from_value(json.clone()).unwrap();
-            let external_reply_info: ExternalReplyInfo = serde_json::from_value(json).unwrap();
+            let external_reply_info: ExternalReplyInfo =
+                serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&external_reply_info).unwrap(), json);
 
             match external_reply_info {
                 ExternalReplyInfo::Audio(external_reply_info) => {
@@ -382,7 +439,10 @@ mod tests {
 
         for json in jsons {
             let external_reply_info_kind = serde_json::from_value(json.clone()).unwrap();
-            let external_reply_info: ExternalReplyInfo = serde_json::from_value(json).unwrap();
+            let external_reply_info: ExternalReplyInfo =
+                serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&external_reply_info).unwrap(), json);
 
             match external_reply_info {
                 ExternalReplyInfo::Document(external_reply_info) => {
@@ -415,7 +475,10 @@ mod tests {
 
         for json in jsons {
             let external_reply_info_kind = serde_json::from_value(json.clone()).unwrap();
-            let external_reply_info: ExternalReplyInfo = serde_json::from_value(json).unwrap();
+            let external_reply_info: ExternalReplyInfo =
+                serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&external_reply_info).unwrap(), json);
 
             match external_reply_info {
                 ExternalReplyInfo::Photo(external_reply_info) => {
@@ -426,6 +489,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn deserialize_paid_media() {
+        let jsons = [serde_json::json!({
+            "origin": {
+                "type": "user",
+                "date": 0,
+                "sender_user": {
+                    "id": 1,
+                    "is_bot": false,
+                    "first_name": "test",
+                },
+            },
+            "paid_media": {
+                "star_count": 1,
+                "paid_media": [{
+                    "type": "preview",
+                }],
+            },
+        })];
+
+        for json in jsons {
+            let external_reply_info_kind = serde_json::from_value(json.clone()).unwrap();
+            let external_reply_info: ExternalReplyInfo =
+                serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&external_reply_info).unwrap(), json);
+
+            match external_reply_info {
+                ExternalReplyInfo::PaidMedia(external_reply_info) => {
+                    assert_eq!(external_reply_info, external_reply_info_kind)
+                }
+                _ => panic!("Unexpected external reply info type: {external_reply_info:?}"),
+            }
+        }
+    }
+
     #[test]
     fn deserialize_sticker() {
         let jsons = [serde_json::json!({
@@ -451,7 +550,10 @@ mod tests {
 
         for json in jsons {
             let external_reply_info_kind = serde_json::from_value(json.clone()).unwrap();
-            let external_reply_info: ExternalReplyInfo = serde_json::from_value(json).unwrap();
+            let external_reply_info: ExternalReplyInfo =
+                serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&external_reply_info).unwrap(), json);
 
             match external_reply_info {
                 ExternalReplyInfo::Sticker(external_reply_info) => {
@@ -486,7 +588,10 @@ mod tests {
 
         for json in jsons {
             let external_reply_info_kind = serde_json::from_value(json.clone()).unwrap();
-            let external_reply_info: ExternalReplyInfo = serde_json::from_value(json).unwrap();
+            let external_reply_info: ExternalReplyInfo =
+                serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&external_reply_info).unwrap(), json);
 
             match external_reply_info {
                 ExternalReplyInfo::Story(external_reply_info) => {
@@ -520,7 +625,10 @@ mod tests {
 
         for json in jsons {
             let external_reply_info_kind = serde_json::from_value(json.clone()).unwrap();
-            let external_reply_info: ExternalReplyInfo = serde_json::from_value(json).unwrap();
+            let external_reply_info: ExternalReplyInfo =
+                serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&external_reply_info).unwrap(), json);
 
             match external_reply_info {
                 ExternalReplyInfo::Video(external_reply_info) => {
@@ -553,7 +661,10 @@ mod tests {
 
         for json in jsons {
             let external_reply_info_kind = serde_json::from_value(json.clone()).unwrap();
-            let external_reply_info: ExternalReplyInfo = serde_json::from_value(json).unwrap();
+            let external_reply_info: ExternalReplyInfo =
+                serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&external_reply_info).unwrap(), json);
 
             match external_reply_info {
                 ExternalReplyInfo::VideoNote(external_reply_info) => {
@@ -585,7 +696,10 @@ mod tests {
 
         for json in jsons {
             let external_reply_info_kind = serde_json::from_value(json.clone()).unwrap();
-            let external_reply_info: ExternalReplyInfo = serde_json::from_value(json).unwrap();
+            let external_reply_info: ExternalReplyInfo =
+                serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&external_reply_info).unwrap(), json);
 
             match external_reply_info {
                 ExternalReplyInfo::Voice(external_reply_info) => {
@@ -616,7 +730,10 @@ mod tests {
 
         for json in jsons {
             let external_reply_info_kind = serde_json::from_value(json.clone()).unwrap();
-            let external_reply_info: ExternalReplyInfo = serde_json::from_value(json).unwrap();
+            let external_reply_info: ExternalReplyInfo =
+                serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&external_reply_info).unwrap(), json);
 
             match external_reply_info {
                 ExternalReplyInfo::Contact(external_reply_info) => {
@@ -647,7 +764,10 @@ mod tests {
 
         for json in jsons {
             let external_reply_info_kind = serde_json::from_value(json.clone()).unwrap();
-            let external_reply_info: ExternalReplyInfo = serde_json::from_value(json).unwrap();
+            let external_reply_info: ExternalReplyInfo =
+                serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&external_reply_info).unwrap(), json);
 
             match external_reply_info {
                 ExternalReplyInfo::Dice(external_reply_info) => {
@@ -684,7 +804,10 @@ mod tests {
 
         for json in jsons {
             let external_reply_info_kind = serde_json::from_value(json.clone()).unwrap();
-            let external_reply_info: ExternalReplyInfo = serde_json::from_value(json).unwrap();
+            let external_reply_info: ExternalReplyInfo =
+                serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&external_reply_info).unwrap(), json);
 
             match external_reply_info {
                 ExternalReplyInfo::Game(external_reply_info) => {
@@ -720,7 +843,10 @@ mod tests {
 
         for json in jsons {
             let external_reply_info_kind = serde_json::from_value(json.clone()).unwrap();
-            let external_reply_info: ExternalReplyInfo = serde_json::from_value(json).unwrap();
+            let external_reply_info: ExternalReplyInfo =
+                serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&external_reply_info).unwrap(), json);
 
             match external_reply_info {
                 ExternalReplyInfo::Giveaway(external_reply_info) => {
@@ -762,7 +888,10 @@ mod tests {
 
         for json in jsons {
             let external_reply_info_kind = serde_json::from_value(json.clone()).unwrap();
-            let external_reply_info: ExternalReplyInfo = serde_json::from_value(json).unwrap();
+            let external_reply_info: ExternalReplyInfo =
+                serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&external_reply_info).unwrap(), json);
 
             match external_reply_info {
                 ExternalReplyInfo::GiveawayWinners(external_reply_info) => {
@@ -796,7 +925,10 @@ mod tests {
 
         for json in jsons {
             let external_reply_info_kind = serde_json::from_value(json.clone()).unwrap();
-            let external_reply_info: ExternalReplyInfo = serde_json::from_value(json).unwrap();
+            let external_reply_info: ExternalReplyInfo =
+                serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&external_reply_info).unwrap(), json);
 
             match external_reply_info {
                 ExternalReplyInfo::Invoice(external_reply_info) => {
@@ -831,7 +963,10 @@ mod tests {
 
         for json in jsons {
             let external_reply_info_kind = serde_json::from_value(json.clone()).unwrap();
-            let external_reply_info: ExternalReplyInfo = serde_json::from_value(json).unwrap();
+            let external_reply_info: ExternalReplyInfo =
+                serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&external_reply_info).unwrap(), json);
 
             match external_reply_info {
                 ExternalReplyInfo::Venue(external_reply_info) => {
@@ -862,7 +997,10 @@ mod tests {
 
         for json in jsons {
             let external_reply_info_kind = serde_json::from_value(json.clone()).unwrap();
-            let external_reply_info: ExternalReplyInfo = serde_json::from_value(json).unwrap();
+            let external_reply_info: ExternalReplyInfo =
+                serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&external_reply_info).unwrap(), json);
 
             match external_reply_info {
                 ExternalReplyInfo::Location(external_reply_info) => {
@@ -908,7 +1046,10 @@ mod tests {
 
         for json in jsons {
             let external_reply_info_kind = serde_json::from_value(json.clone()).unwrap();
-            let external_reply_info: ExternalReplyInfo = serde_json::from_value(json).unwrap();
+            let external_reply_info: ExternalReplyInfo =
+                serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&external_reply_info).unwrap(), json);
 
             match external_reply_info {
                 ExternalReplyInfo::Poll(external_reply_info) => {
@@ -918,4 +1059,43 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn deserialize_checklist() {
+        let jsons = [serde_json::json!({
+            "origin": {
+                "type": "user",
+                "date": 0,
+                "sender_user": {
+                    "id": 1,
+                    "is_bot": false,
+                    "first_name": "test",
+                },
+            },
+            "checklist": {
+                "title": "test",
+                "tasks": [
+                    {
+                        "id": 1,
+                        "text": "test",
+                    },
+                ],
+            },
+        })];
+
+        for json in jsons {
+            let external_reply_info_kind = serde_json::from_value(json.clone()).unwrap();
+            let external_reply_info: ExternalReplyInfo =
+                serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&external_reply_info).unwrap(), json);
+
+            match external_reply_info {
+                ExternalReplyInfo::Checklist(external_reply_info) => {
+                    assert_eq!(external_reply_info, external_reply_info_kind)
+                }
+                _ => panic!("Unexpected external reply info type: {external_reply_info:?}"),
+            }
+        }
+    }
 }
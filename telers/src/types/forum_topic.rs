@@ -1,9 +1,11 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 /// This object represents a forum topic.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#forumtopic>
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
 pub struct ForumTopic {
     /// Unique identifier of the forum topic
     pub message_thread_id: i64,
@@ -1,9 +1,9 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// This object represents a service message about a new forum topic created in the chat.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#forumtopiccreated>
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
 pub struct ForumTopicCreated {
     /// Name of the topic
     pub name: Box<str>,
@@ -1,9 +1,11 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 /// This object represents a service message about a new forum topic created in the chat.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#forumtopicedited>
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
 pub struct ForumTopicEdited {
     /// New name of the topic, if it was edited
     pub name: Option<Box<str>>,
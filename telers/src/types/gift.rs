@@ -0,0 +1,24 @@
+use super::Sticker;
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// This object represents a gift that can be sent by the bot.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#gift>
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Gift {
+    /// Unique identifier of the gift
+    pub id: Box<str>,
+    /// The sticker that represents the gift
+    pub sticker: Sticker,
+    /// The number of Telegram Stars that must be paid to send the sticker
+    pub star_count: i64,
+    /// The number of Telegram Stars that must be paid to upgrade the gift to a unique one
+    pub upgrade_star_count: Option<i64>,
+    /// The total number of the gifts of this type that can be sent; for limited gifts only
+    pub total_count: Option<i64>,
+    /// The number of remaining gifts of this type that can be sent; for limited gifts only
+    pub remaining_count: Option<i64>,
+}
@@ -1,13 +1,15 @@
-use super::{Location, Update, UpdateKind, User};
+use super::{InlineQueryResult, Location, Update, UpdateKind, User};
 
-use crate::{errors::ConvertToTypeError, FromEvent};
+use crate::{errors::ConvertToTypeError, methods::AnswerInlineQuery, FromEvent};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 /// This object represents an incoming inline query. When the user sends an empty query, your bot could return some default or trending results.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#inlinequery>
-#[derive(Debug, Default, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct InlineQuery {
     /// Unique identifier for this query
@@ -24,6 +26,18 @@ pub struct InlineQuery {
     pub location: Option<Location>,
 }
 
+impl InlineQuery {
+    /// Answer the inline query with the results
+    #[must_use]
+    pub fn answer<R, I>(&self, results: I) -> AnswerInlineQuery
+    where
+        R: Into<InlineQueryResult>,
+        I: IntoIterator<Item = R>,
+    {
+        AnswerInlineQuery::new(&*self.id, results)
+    }
+}
+
 impl TryFrom<Update> for InlineQuery {
     type Error = ConvertToTypeError;
 
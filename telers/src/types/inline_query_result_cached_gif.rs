@@ -21,6 +21,8 @@ pub struct InlineQueryResultCachedGif {
     pub parse_mode: Option<String>,
     /// List of special entities that appear in the caption, which can be specified instead of *parse_mode*
     pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Pass `true`, if the caption must be shown above the message media
+    pub show_caption_above_media: Option<bool>,
     /// [`Inline keyboard`](https://core.telegram.org/bots/features#inline-keyboards) attached to the message
     pub reply_markup: Option<InlineKeyboardMarkup>,
     /// Content of the message to be sent instead of the GIF animation
@@ -37,6 +39,7 @@ impl InlineQueryResultCachedGif {
             caption: None,
             parse_mode: None,
             caption_entities: None,
+            show_caption_above_media: None,
             reply_markup: None,
             input_message_content: None,
         }
@@ -110,6 +113,14 @@ impl InlineQueryResultCachedGif {
         }
     }
 
+    #[must_use]
+    pub fn show_caption_above_media(self, val: bool) -> Self {
+        Self {
+            show_caption_above_media: Some(val),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn reply_markup(self, val: impl Into<InlineKeyboardMarkup>) -> Self {
         Self {
@@ -168,4 +179,12 @@ impl InlineQueryResultCachedGif {
             ..self
         }
     }
+
+    #[must_use]
+    pub fn show_caption_above_media_option(self, val: Option<bool>) -> Self {
+        Self {
+            show_caption_above_media: val,
+            ..self
+        }
+    }
 }
@@ -26,6 +26,8 @@ pub struct InlineQueryResultVideo {
     pub parse_mode: Option<String>,
     /// List of special entities that appear in the caption, which can be specified instead of *parse_mode*
     pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Pass `true`, if the caption must be shown above the message media
+    pub show_caption_above_media: Option<bool>,
     /// Video width
     pub video_width: Option<i64>,
     /// Video height
@@ -58,6 +60,7 @@ impl InlineQueryResultVideo {
             caption: None,
             parse_mode: None,
             caption_entities: None,
+            show_caption_above_media: None,
             video_width: None,
             video_height: None,
             video_duration: None,
@@ -151,6 +154,14 @@ impl InlineQueryResultVideo {
         }
     }
 
+    #[must_use]
+    pub fn show_caption_above_media(self, val: bool) -> Self {
+        Self {
+            show_caption_above_media: Some(val),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn video_width(self, val: i64) -> Self {
         Self {
@@ -233,6 +244,14 @@ impl InlineQueryResultVideo {
         }
     }
 
+    #[must_use]
+    pub fn show_caption_above_media_option(self, val: Option<bool>) -> Self {
+        Self {
+            show_caption_above_media: val,
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn video_width_option(self, val: Option<i64>) -> Self {
         Self {
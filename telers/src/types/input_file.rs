@@ -31,10 +31,10 @@ pub const DEFAULT_CAPACITY: usize = 64 * 1024; // 64 KiB
 /// but if you want to use methods of specific type (for example [`FSFile::stream`] or [`StreamFile::set_stream`]),
 /// you need to use specific type.
 /// # Warning
-/// If you [`Clone`] file, you will get a new file with the same ID for [`FileId`], [`UrlFile`], [`FSFile`], [`BufferedFile`].
-/// So several parts will refer to the same data. It can be useful to minimize the amount of data uploaded.
-/// If case of [`StreamFile`] you will get a new file with a new ID,
-/// this is done to avoid problems when several parts with different streams refer to the first part and respectively to the first stream.
+/// If you [`Clone`] file, you will get a new file with the same ID, so several parts will refer to the same data.
+/// It can be useful to minimize the amount of data uploaded.
+/// In case of [`StreamFile`] the clone also shares the stream with the original file,
+/// so the stream is taken once for both of them. Check [`StreamFile::take_stream`] for more information.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#inputfile>
 #[derive(Debug, Clone, Hash, PartialEq)]
@@ -428,6 +428,7 @@ type SharedStream =
 /// This need because the stream can't be restored after it was taken.
 ///
 /// Check [`StreamFile::take_stream`] and [`StreamFile::set_stream`] for more information.
+#[derive(Clone)]
 pub struct StreamFile<'a> {
     id: Uuid,
     file_name: Option<Cow<'a, str>>,
@@ -554,19 +555,6 @@ impl Debug for StreamFile<'_> {
     }
 }
 
-impl Clone for StreamFile<'_> {
-    fn clone(&self) -> Self {
-        let id = Uuid::new_v4();
-
-        Self {
-            id,
-            file_name: self.file_name.clone(),
-            stream: self.stream.clone(),
-            str_to_file: format!("{ATTACH_PREFIX}{id}"),
-        }
-    }
-}
-
 impl Hash for StreamFile<'_> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.id.hash(state);
@@ -15,13 +15,13 @@ pub struct InputInvoiceMessageContent {
     pub description: String,
     /// Bot-defined invoice payload, 1-128 bytes. This will not be displayed to the user, use for your internal processes.
     pub payload: String,
-    /// Payments provider token, obtained via [`Botfather`](https://t.me/botfather)
-    pub provider_token: String,
-    /// Three-letter ISO 4217 currency code, see [`more on currencies`](https://core.telegram.org/bots/payments#supported-currencies)
+    /// Payment provider token, obtained via [`Botfather`](https://t.me/botfather). Pass `None` for payments in [Telegram Stars](https://t.me/BotNews/90).
+    pub provider_token: Option<String>,
+    /// Three-letter ISO 4217 currency code, see [`more on currencies`](https://core.telegram.org/bots/payments#supported-currencies). Pass `XTR` for payments in [Telegram Stars](https://t.me/BotNews/90).
     pub currency: String,
-    /// Price breakdown, a list of components (e.g. product price, tax, discount, delivery cost, delivery tax, bonus, etc.)
+    /// Price breakdown, a list of components (e.g. product price, tax, discount, delivery cost, delivery tax, bonus, etc.). Must contain exactly one item for payments in [Telegram Stars](https://t.me/BotNews/90).
     pub prices: Vec<LabeledPrice>,
-    /// The maximum accepted amount for tips in the *smallest units* of the currency (integer, **not** float/double). For example, for a maximum tip of `US$ 1.45` pass `max_tip_amount = 145`. See the *exp* parameter in [`currencies.json`](https://core.telegram.org/bots/payments/currencies.json), it shows the number of digits past the decimal point for each currency (2 for the majority of currencies). Defaults to 0
+    /// The maximum accepted amount for tips in the *smallest units* of the currency (integer, **not** float/double). For example, for a maximum tip of `US$ 1.45` pass `max_tip_amount = 145`. See the *exp* parameter in [`currencies.json`](https://core.telegram.org/bots/payments/currencies.json), it shows the number of digits past the decimal point for each currency (2 for the majority of currencies). Defaults to 0. Not supported for payments in [Telegram Stars](https://t.me/BotNews/90).
     pub max_tip_amount: Option<i64>,
     /// A JSON-serialized array of suggested amounts of tip in the *smallest units* of the currency (integer, **not** float/double). At most 4 suggested tip amounts can be specified. The suggested tip amounts must be positive, passed in a strictly increased order and must not exceed *max_tip_amount*.
     pub suggested_tip_amounts: Option<Vec<i64>>,
@@ -57,7 +57,6 @@ impl InputInvoiceMessageContent {
         title: impl Into<String>,
         description: impl Into<String>,
         payload: impl Into<String>,
-        provider_token: impl Into<String>,
         currency: impl Into<String>,
         prices: impl IntoIterator<Item = LabeledPrice>,
     ) -> Self {
@@ -65,7 +64,7 @@ impl InputInvoiceMessageContent {
             title: title.into(),
             description: description.into(),
             payload: payload.into(),
-            provider_token: provider_token.into(),
+            provider_token: None,
             currency: currency.into(),
             prices: prices.into_iter().collect(),
             max_tip_amount: None,
@@ -112,7 +111,7 @@ impl InputInvoiceMessageContent {
     #[must_use]
     pub fn provider_token(self, val: impl Into<String>) -> Self {
         Self {
-            provider_token: val.into(),
+            provider_token: Some(val.into()),
             ..self
         }
     }
@@ -267,6 +266,14 @@ impl InputInvoiceMessageContent {
 }
 
 impl InputInvoiceMessageContent {
+    #[must_use]
+    pub fn provider_token_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            provider_token: val.map(Into::into),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn max_tip_amount_option(self, val: Option<i64>) -> Self {
         Self {
@@ -17,6 +17,8 @@ pub struct InputMediaPhoto<'a> {
     pub parse_mode: Option<String>,
     /// List of special entities that appear in the caption, which can be specified instead of *parse_mode*
     pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Pass `true`, if the caption must be shown above the message media
+    pub show_caption_above_media: Option<bool>,
     /// Pass `true` if the photo needs to be covered with a spoiler animation
     pub has_spoiler: Option<bool>,
 }
@@ -29,6 +31,7 @@ impl<'a> InputMediaPhoto<'a> {
             caption: None,
             parse_mode: None,
             caption_entities: None,
+            show_caption_above_media: None,
             has_spoiler: None,
         }
     }
@@ -85,6 +88,14 @@ impl<'a> InputMediaPhoto<'a> {
         }
     }
 
+    #[must_use]
+    pub fn show_caption_above_media(self, val: bool) -> Self {
+        Self {
+            show_caption_above_media: Some(val),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn has_spoiler(self, val: bool) -> Self {
         Self {
@@ -128,6 +139,14 @@ impl<'a> InputMediaPhoto<'a> {
         }
     }
 
+    #[must_use]
+    pub fn show_caption_above_media_option(self, val: Option<bool>) -> Self {
+        Self {
+            show_caption_above_media: val,
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn has_spoiler_option(self, val: Option<bool>) -> Self {
         Self {
@@ -19,6 +19,8 @@ pub struct InputMediaVideo<'a> {
     pub parse_mode: Option<String>,
     /// List of special entities that appear in the caption, which can be specified instead of *parse_mode*
     pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Pass `true`, if the caption must be shown above the message media
+    pub show_caption_above_media: Option<bool>,
     /// Video width
     pub width: Option<i64>,
     /// Video height"
@@ -40,6 +42,7 @@ impl<'a> InputMediaVideo<'a> {
             caption: None,
             parse_mode: None,
             caption_entities: None,
+            show_caption_above_media: None,
             width: None,
             height: None,
             duration: None,
@@ -108,6 +111,14 @@ impl<'a> InputMediaVideo<'a> {
         }
     }
 
+    #[must_use]
+    pub fn show_caption_above_media(self, val: bool) -> Self {
+        Self {
+            show_caption_above_media: Some(val),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn width(self, val: i64) -> Self {
         Self {
@@ -191,6 +202,14 @@ impl<'a> InputMediaVideo<'a> {
         }
     }
 
+    #[must_use]
+    pub fn show_caption_above_media_option(self, val: Option<bool>) -> Self {
+        Self {
+            show_caption_above_media: val,
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn width_option(self, val: Option<i64>) -> Self {
         Self { width: val, ..self }
@@ -0,0 +1,106 @@
+use super::MessageEntity;
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// This object contains information about one answer option in a poll to send.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#inputpolloption>
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Serialize)]
+pub struct InputPollOption {
+    /// Option text, 1-100 characters
+    pub text: String,
+    /// Mode for parsing entities in the text. See [`formatting options`](https://core.telegram.org/bots/api#formatting-options) for more details. Currently, only custom emoji entities are allowed
+    pub text_parse_mode: Option<String>,
+    /// A JSON-serialized list of special entities that appear in the poll option text. It can be specified instead of `text_parse_mode`
+    pub text_entities: Option<Vec<MessageEntity>>,
+}
+
+impl InputPollOption {
+    #[must_use]
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            text_parse_mode: None,
+            text_entities: None,
+        }
+    }
+
+    #[must_use]
+    pub fn text(self, val: impl Into<String>) -> Self {
+        Self {
+            text: val.into(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn text_parse_mode(self, val: impl Into<String>) -> Self {
+        Self {
+            text_parse_mode: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn text_entity(self, val: MessageEntity) -> Self {
+        Self {
+            text_entities: Some(
+                self.text_entities
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(Some(val))
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn text_entities(self, val: impl IntoIterator<Item = MessageEntity>) -> Self {
+        Self {
+            text_entities: Some(
+                self.text_entities
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(val)
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+}
+
+impl InputPollOption {
+    #[must_use]
+    pub fn text_parse_mode_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            text_parse_mode: val.map(Into::into),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn text_entities_option(
+        self,
+        val: Option<impl IntoIterator<Item = MessageEntity>>,
+    ) -> Self {
+        Self {
+            text_entities: val.map(|val| val.into_iter().collect()),
+            ..self
+        }
+    }
+}
+
+impl From<String> for InputPollOption {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<&str> for InputPollOption {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
@@ -0,0 +1,90 @@
+use super::InputFile;
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// Describes a video to post as a story.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#inputstorycontentvideo>
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InputStoryContentVideo<'a> {
+    /// The video to post as a story. The video must be of the size 720x1280, streamable, encoded with H.265 codec, with key frames added each second in the MPEG4 format, and must not exceed 30 MB. The video can't be reused and can only be uploaded as a new file, so you can pass 'attach://<file_attach_name>' if the video was uploaded using `multipart/form-data` under <file_attach_name>. [`More information on Sending Files`](https://core.telegram.org/bots/api#sending-files).
+    pub video: InputFile<'a>,
+    /// Precise duration of the video in seconds; 0-60
+    pub duration: Option<f64>,
+    /// Timestamp in seconds of the frame that will be used as the static cover for the story. Defaults to 0.0.
+    pub cover_frame_timestamp: Option<f64>,
+    /// Pass `true` if the video has no sound
+    pub is_animation: Option<bool>,
+}
+
+impl<'a> InputStoryContentVideo<'a> {
+    #[must_use]
+    pub fn new(video: impl Into<InputFile<'a>>) -> Self {
+        Self {
+            video: video.into(),
+            duration: None,
+            cover_frame_timestamp: None,
+            is_animation: None,
+        }
+    }
+
+    #[must_use]
+    pub fn video(self, val: impl Into<InputFile<'a>>) -> Self {
+        Self {
+            video: val.into(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn duration(self, val: f64) -> Self {
+        Self {
+            duration: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn cover_frame_timestamp(self, val: f64) -> Self {
+        Self {
+            cover_frame_timestamp: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn is_animation(self, val: bool) -> Self {
+        Self {
+            is_animation: Some(val),
+            ..self
+        }
+    }
+}
+
+impl<'a> InputStoryContentVideo<'a> {
+    #[must_use]
+    pub fn duration_option(self, val: Option<f64>) -> Self {
+        Self {
+            duration: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn cover_frame_timestamp_option(self, val: Option<f64>) -> Self {
+        Self {
+            cover_frame_timestamp: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn is_animation_option(self, val: Option<bool>) -> Self {
+        Self {
+            is_animation: val,
+            ..self
+        }
+    }
+}
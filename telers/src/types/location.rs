@@ -1,9 +1,16 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[cfg(feature = "chrono")]
+use crate::utils::date_time;
+#[cfg(feature = "chrono")]
+use chrono::TimeDelta;
 
 /// This object represents a point on the map.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#location>
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Location {
     /// Longitude as defined by sender
     pub longitude: f64,
@@ -18,3 +25,12 @@ pub struct Location {
     /// The maximum distance for proximity alerts about approaching another chat member, in meters. For sent live locations only.
     pub proximity_alert_radius: Option<i64>,
 }
+
+#[cfg(feature = "chrono")]
+impl Location {
+    /// Time relative to the message sending date, during which the location can be updated, as [`TimeDelta`]
+    #[must_use]
+    pub fn live_period_delta(&self) -> Option<TimeDelta> {
+        self.live_period.map(date_time::from_seconds)
+    }
+}
@@ -0,0 +1,88 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// Describes the physical address of a location.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#locationaddress>
+#[skip_serializing_none]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize)]
+pub struct LocationAddress {
+    /// The two-letter ISO 3166-1 alpha-2 country code of the country where the location is located
+    pub country_code: String,
+    /// State of the location
+    pub state: Option<String>,
+    /// City of the location
+    pub city: Option<String>,
+    /// Street address of the location
+    pub street: Option<String>,
+}
+
+impl LocationAddress {
+    #[must_use]
+    pub fn new(country_code: impl Into<String>) -> Self {
+        Self {
+            country_code: country_code.into(),
+            state: None,
+            city: None,
+            street: None,
+        }
+    }
+
+    #[must_use]
+    pub fn country_code(self, val: impl Into<String>) -> Self {
+        Self {
+            country_code: val.into(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn state(self, val: impl Into<String>) -> Self {
+        Self {
+            state: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn city(self, val: impl Into<String>) -> Self {
+        Self {
+            city: Some(val.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn street(self, val: impl Into<String>) -> Self {
+        Self {
+            street: Some(val.into()),
+            ..self
+        }
+    }
+}
+
+impl LocationAddress {
+    #[must_use]
+    pub fn state_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            state: val.map(Into::into),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn city_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            city: val.map(Into::into),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn street_option(self, val: Option<impl Into<String>>) -> Self {
+        Self {
+            street: val.map(Into::into),
+            ..self
+        }
+    }
+}
@@ -1,11 +1,28 @@
 use super::{
     Chat, ExternalReplyInfo, InlineKeyboardMarkup, LinkPreviewOptions, MaybeInaccessibleMessage,
-    MessageEntity, MessageOrigin, PhotoSize, TextQuote, Update, UpdateKind, User,
+    MessageEntity, MessageEntityKind, MessageOrigin, PhotoSize, TextQuote, Update, UpdateKind,
+    User,
 };
 
-use crate::{errors::ConvertToTypeError, extractors::FromEvent, types};
+use crate::{
+    errors::ConvertToTypeError,
+    extractors::FromEvent,
+    methods::{
+        CopyMessage, DeleteBusinessMessages, DeleteMessage, EditMessageReplyMarkup,
+        EditMessageText, ForwardMessage, SendAnimation, SendDocument, SendMessage, SendPhoto,
+        SendVideo, SetMessageReaction,
+    },
+    types::{self, ChatIdKind, InputFile, ReactionType, ReplyParameters},
+    utils::text::{html_formatter, markdown_formatter, Formatter},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
-use serde::Deserialize;
+#[cfg(feature = "chrono")]
+use crate::utils::date_time;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
 
 /// This object represents a message.
 /// # Documentation
@@ -17,7 +34,7 @@ use serde::Deserialize;
 /// or if you want to get caption from all types where it has,
 /// instead of using [`Animation::caption`], [`Audio::caption`], ..., you can use [`Message::caption`].
 /// Similar methods are implemented for all major message types.
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 #[serde(untagged)]
 pub enum Message {
@@ -26,6 +43,7 @@ pub enum Message {
     Audio(Box<Audio>),
     Document(Box<Document>),
     Photo(Box<Photo>),
+    PaidMedia(Box<PaidMedia>),
     Sticker(Box<Sticker>),
     Story(Box<Story>),
     Video(Box<Video>),
@@ -35,6 +53,7 @@ pub enum Message {
     Dice(Box<Dice>),
     Game(Box<Game>),
     Poll(Box<Poll>),
+    Checklist(Box<Checklist>),
     Venue(Box<Venue>),
     Location(Box<Location>),
     NewChatMembers(Box<NewChatMembers>),
@@ -51,6 +70,7 @@ pub enum Message {
     Pinned(Box<Pinned>),
     Invoice(Box<Invoice>),
     SuccessfulPayment(Box<SuccessfulPayment>),
+    RefundedPayment(Box<RefundedPayment>),
     UsersShared(Box<UsersShared>),
     ChatShared(Box<ChatShared>),
     ConnectedWebsite(Box<ConnectedWebsite>),
@@ -58,6 +78,7 @@ pub enum Message {
     PassportData(Box<PassportData>),
     ProximityAlertTriggered(Box<ProximityAlertTriggered>),
     ChatBoostAdded(Box<ChatBoostAdded>),
+    ChatBackgroundSet(Box<ChatBackgroundSet>),
     ForumTopicCreated(Box<ForumTopicCreated>),
     ForumTopicEdited(Box<ForumTopicEdited>),
     ForumTopicClosed(Box<ForumTopicClosed>),
@@ -68,6 +89,13 @@ pub enum Message {
     Giveaway(Box<Giveaway>),
     GiveawayWinners(Box<GiveawayWinners>),
     GiveawayCompleted(Box<GiveawayCompleted>),
+    ChecklistTasksDone(Box<ChecklistTasksDone>),
+    ChecklistTasksAdded(Box<ChecklistTasksAdded>),
+    SuggestedPostApproved(Box<SuggestedPostApproved>),
+    SuggestedPostApprovalFailed(Box<SuggestedPostApprovalFailed>),
+    SuggestedPostDeclined(Box<SuggestedPostDeclined>),
+    SuggestedPostPaid(Box<SuggestedPostPaid>),
+    SuggestedPostRefunded(Box<SuggestedPostRefunded>),
     VideoChatScheduled(Box<VideoChatScheduled>),
     VideoChatStarted(Box<VideoChatStarted>),
     VideoChatEnded(Box<VideoChatEnded>),
@@ -75,7 +103,8 @@ pub enum Message {
     WebAppData(Box<WebAppData>),
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct Animation {
     /// Unique message identifier inside this chat
@@ -122,20 +151,27 @@ pub struct Animation {
     pub is_from_offline: Option<bool>,
     /// Signature of the post author for messages in channels, or the custom title of an anonymous group administrator
     pub author_signature: Option<Box<str>>,
+    /// Unique identifier of the message effect added to the message
+    pub effect_id: Option<Box<str>>,
     /// Information about the animation
     pub animation: types::Animation,
+    /// Information about the animation as a file. For backward compatibility, Telegram also sets this field when the `animation` field is set
+    pub document: Option<types::Document>,
     /// Caption
     pub caption: Option<Box<str>>,
     /// Special entities like usernames, URLs, bot commands, etc. that appear in the caption
     #[serde(rename = "caption_entities")]
     pub entities: Option<Box<[MessageEntity]>>,
+    /// `true`, if the caption must be shown above the message media
+    pub show_caption_above_media: Option<bool>,
     /// `true`, if the message media is covered by a spoiler animation
     pub has_media_spoiler: Option<bool>,
     /// Inline keyboard attached to the message. `login_url` buttons are represented as ordinary `url` buttons.
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct Audio {
     /// Unique message identifier inside this chat
@@ -184,6 +220,8 @@ pub struct Audio {
     pub media_group_id: Option<Box<str>>,
     /// Signature of the post author for messages in channels, or the custom title of an anonymous group administrator
     pub author_signature: Option<Box<str>>,
+    /// Unique identifier of the message effect added to the message
+    pub effect_id: Option<Box<str>>,
     /// Information about the file
     pub audio: types::Audio,
     /// Caption
@@ -195,7 +233,8 @@ pub struct Audio {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct Contact {
     /// Unique message identifier inside this chat
@@ -238,13 +277,16 @@ pub struct Contact {
     pub is_from_offline: Option<bool>,
     /// Signature of the post author for messages in channels, or the custom title of an anonymous group administrator
     pub author_signature: Option<Box<str>>,
+    /// Unique identifier of the message effect added to the message
+    pub effect_id: Option<Box<str>>,
     /// Information about the contact
     pub contact: types::Contact,
     /// Inline keyboard attached to the message. `login_url` buttons are represented as ordinary `url` buttons.
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct Dice {
     /// Unique message identifier inside this chat
@@ -285,13 +327,16 @@ pub struct Dice {
     pub is_from_offline: Option<bool>,
     /// Signature of the post author for messages in channels, or the custom title of an anonymous group administrator
     pub author_signature: Option<Box<str>>,
+    /// Unique identifier of the message effect added to the message
+    pub effect_id: Option<Box<str>>,
     /// Message is a dice with random value
     pub dice: types::Dice,
     /// Inline keyboard attached to the message. `login_url` buttons are represented as ordinary `url` buttons.
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct Document {
     /// Unique message identifier inside this chat
@@ -340,6 +385,8 @@ pub struct Document {
     pub media_group_id: Option<Box<str>>,
     /// Signature of the post author for messages in channels, or the custom title of an anonymous group administrator
     pub author_signature: Option<Box<str>>,
+    /// Unique identifier of the message effect added to the message
+    pub effect_id: Option<Box<str>>,
     /// Information about the file
     pub document: types::Document,
     /// Caption
@@ -351,7 +398,8 @@ pub struct Document {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct Game {
     /// Unique message identifier inside this chat
@@ -394,13 +442,16 @@ pub struct Game {
     pub is_from_offline: Option<bool>,
     /// Signature of the post author for messages in channels, or the custom title of an anonymous group administrator
     pub author_signature: Option<Box<str>>,
+    /// Unique identifier of the message effect added to the message
+    pub effect_id: Option<Box<str>>,
     /// Information about the game. [`More about games`](https://core.telegram.org/bots/api#games)
     pub game: types::Game,
     /// Inline keyboard attached to the message. `login_url` buttons are represented as ordinary `url` buttons.
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct Poll {
     /// Unique message identifier inside this chat
@@ -443,13 +494,68 @@ pub struct Poll {
     pub is_from_offline: Option<bool>,
     /// Signature of the post author for messages in channels, or the custom title of an anonymous group administrator
     pub author_signature: Option<Box<str>>,
+    /// Unique identifier of the message effect added to the message
+    pub effect_id: Option<Box<str>>,
     /// Information about the poll
     pub poll: types::Poll,
     /// Inline keyboard attached to the message. `login_url` buttons are represented as ordinary `url` buttons.
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
+#[event(try_from = Update)]
+pub struct Checklist {
+    /// Unique message identifier inside this chat
+    #[serde(rename = "message_id")]
+    pub id: i64,
+    /// Unique identifier of a message thread to which the message belongs; for supergroups only
+    #[serde(rename = "message_thread_id")]
+    pub thread_id: Option<i64>,
+    /// Sender of the message; empty for messages sent to channels. For backward compatibility, the field contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub from: Option<User>,
+    /// Sender of the message, sent on behalf of a chat. For example, the channel itself for channel posts, the supergroup itself for messages from anonymous group administrators, the linked channel for messages automatically forwarded to the discussion group. For backward compatibility, the field *from* contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub sender_chat: Option<Chat>,
+    /// If the sender of the message boosted the chat, the number of boosts added by the user
+    pub sender_boost_count: Option<i64>,
+    /// The bot that actually sent the message on behalf of the business account. Available only for outgoing messages sent on behalf of the connected business account.
+    pub sender_business_bot: Option<User>,
+    /// Date the message was sent in Unix time
+    pub date: i64,
+    /// Unique identifier of the business connection from which the message was received. If non-empty, the message belongs to a chat of the corresponding business account that is independent from any potential bot chat which might share the same identifier.
+    pub business_connection_id: Option<Box<str>>,
+    /// Conversation the message belongs to
+    pub chat: Chat,
+    /// Information about the original message for forwarded messages
+    pub forward_origin: Option<MessageOrigin>,
+    /// `true`, if the message is sent to a forum topic
+    pub is_topic_message: Option<bool>,
+    /// `true`, if the message is a channel post that was automatically forwarded to the connected discussion group
+    pub is_automatic_forward: Option<bool>,
+    /// For replies, the original message. Note that the [Message object](https://core.telegram.org/bots/api#message) in this field will not contain further *reply_to_message* fields even if it itself is a reply.
+    pub reply_to_message: Option<Message>,
+    /// For replies to a story, the original story
+    pub reply_to_story: Option<Story>,
+    /// Information about the message that is being replied to, which may come from another chat or forum topic
+    pub external_reply: Option<ExternalReplyInfo>,
+    /// Date the message was last edited in Unix time
+    pub edit_date: Option<i64>,
+    /// `true`, if the message can't be forwarded
+    pub has_protected_content: Option<bool>,
+    /// `true`, if the message was sent by an implicit action, for example, as an away or a greeting business message, or as a scheduled message
+    pub is_from_offline: Option<bool>,
+    /// Signature of the post author for messages in channels, or the custom title of an anonymous group administrator
+    pub author_signature: Option<Box<str>>,
+    /// Unique identifier of the message effect added to the message
+    pub effect_id: Option<Box<str>>,
+    /// Message is a checklist
+    pub checklist: types::Checklist,
+    /// Inline keyboard attached to the message. `login_url` buttons are represented as ordinary `url` buttons.
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct Venue {
     /// Unique message identifier inside this chat
@@ -494,13 +600,18 @@ pub struct Venue {
     pub is_from_offline: Option<bool>,
     /// Signature of the post author for messages in channels, or the custom title of an anonymous group administrator
     pub author_signature: Option<Box<str>>,
+    /// Unique identifier of the message effect added to the message
+    pub effect_id: Option<Box<str>>,
     /// Information about the venue
     pub venue: types::Venue,
+    /// Information about the venue location. For backward compatibility, Telegram also sets this field when the `venue` field is set
+    pub location: Option<types::Location>,
     /// Inline keyboard attached to the message. `login_url` buttons are represented as ordinary `url` buttons.
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct Location {
     /// Unique message identifier inside this chat
@@ -545,13 +656,16 @@ pub struct Location {
     pub is_from_offline: Option<bool>,
     /// Signature of the post author for messages in channels, or the custom title of an anonymous group administrator
     pub author_signature: Option<Box<str>>,
+    /// Unique identifier of the message effect added to the message
+    pub effect_id: Option<Box<str>>,
     /// Information about the location
     pub location: types::Location,
     /// Inline keyboard attached to the message. `login_url` buttons are represented as ordinary `url` buttons.
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct Photo {
     /// Unique message identifier inside this chat
@@ -600,6 +714,8 @@ pub struct Photo {
     pub media_group_id: Option<Box<str>>,
     /// Signature of the post author for messages in channels, or the custom title of an anonymous group administrator
     pub author_signature: Option<Box<str>>,
+    /// Unique identifier of the message effect added to the message
+    pub effect_id: Option<Box<str>>,
     /// Available sizes of the photo
     pub photo: Box<[PhotoSize]>,
     /// Caption
@@ -607,6 +723,8 @@ pub struct Photo {
     /// Special entities like usernames, URLs, bot commands, etc. that appear in the caption
     #[serde(rename = "caption_entities")]
     pub entities: Option<Box<[MessageEntity]>>,
+    /// `true`, if the caption must be shown above the message media
+    pub show_caption_above_media: Option<bool>,
     /// `true`, if the message media is covered by a spoiler animation
     pub has_media_spoiler: Option<bool>,
     /// Inline keyboard attached to the message. `login_url` buttons are represented as ordinary `url` buttons.
@@ -639,7 +757,71 @@ impl Photo {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
+#[event(try_from = Update)]
+pub struct PaidMedia {
+    /// Unique message identifier inside this chat
+    #[serde(rename = "message_id")]
+    pub id: i64,
+    /// Unique identifier of a message thread to which the message belongs; for supergroups only
+    #[serde(rename = "message_thread_id")]
+    pub thread_id: Option<i64>,
+    /// Sender of the message; empty for messages sent to channels. For backward compatibility, the field contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub from: Option<User>,
+    /// Sender of the message, sent on behalf of a chat. For example, the channel itself for channel posts, the supergroup itself for messages from anonymous group administrators, the linked channel for messages automatically forwarded to the discussion group. For backward compatibility, the field *from* contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub sender_chat: Option<Chat>,
+    /// If the sender of the message boosted the chat, the number of boosts added by the user
+    pub sender_boost_count: Option<i64>,
+    /// The bot that actually sent the message on behalf of the business account. Available only for outgoing messages sent on behalf of the connected business account.
+    pub sender_business_bot: Option<User>,
+    /// Date the message was sent in Unix time
+    pub date: i64,
+    /// Unique identifier of the business connection from which the message was received. If non-empty, the message belongs to a chat of the corresponding business account that is independent from any potential bot chat which might share the same identifier.
+    pub business_connection_id: Option<Box<str>>,
+    /// Conversation the message belongs to
+    pub chat: Chat,
+    /// Information about the original message for forwarded messages
+    pub forward_origin: Option<MessageOrigin>,
+    /// `true`, if the message is sent to a forum topic
+    pub is_topic_message: Option<bool>,
+    /// `true`, if the message is a channel post that was automatically forwarded to the connected discussion group
+    pub is_automatic_forward: Option<bool>,
+    /// For replies, the original message. Note that the [Message object](https://core.telegram.org/bots/api#message) in this field will not contain further *reply_to_message* fields even if it itself is a reply.
+    pub reply_to_message: Option<Message>,
+    /// For replies to a story, the original story
+    pub reply_to_story: Option<Story>,
+    /// Information about the message that is being replied to, which may come from another chat or forum topic
+    pub external_reply: Option<ExternalReplyInfo>,
+    /// For replies that quote part of the original message, the quoted part of the message
+    pub quote: Option<TextQuote>,
+    /// Bot through which the message was sent
+    pub via_bot: Option<User>,
+    /// Date the message was last edited in Unix time
+    pub edit_date: Option<i64>,
+    /// `true`, if the message can't be forwarded
+    pub has_protected_content: Option<bool>,
+    /// `true`, if the message was sent by an implicit action, for example, as an away or a greeting business message, or as a scheduled message
+    pub is_from_offline: Option<bool>,
+    /// Signature of the post author for messages in channels, or the custom title of an anonymous group administrator
+    pub author_signature: Option<Box<str>>,
+    /// Unique identifier of the message effect added to the message
+    pub effect_id: Option<Box<str>>,
+    /// Information about the paid media
+    pub paid_media: types::PaidMediaInfo,
+    /// Caption
+    pub caption: Option<Box<str>>,
+    /// Special entities like usernames, URLs, bot commands, etc. that appear in the caption
+    #[serde(rename = "caption_entities")]
+    pub entities: Option<Box<[MessageEntity]>>,
+    /// `true`, if the caption must be shown above the message media
+    pub show_caption_above_media: Option<bool>,
+    /// Inline keyboard attached to the message. `login_url` buttons are represented as ordinary `url` buttons.
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct Story {
     /// Unique message identifier inside this chat
@@ -674,7 +856,8 @@ pub struct Story {
     pub story: types::Story,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct Sticker {
     /// Unique message identifier inside this chat
@@ -717,11 +900,14 @@ pub struct Sticker {
     pub is_from_offline: Option<bool>,
     /// Signature of the post author for messages in channels, or the custom title of an anonymous group administrator
     pub author_signature: Option<Box<str>>,
+    /// Unique identifier of the message effect added to the message
+    pub effect_id: Option<Box<str>>,
     /// Information about the sticker
     pub sticker: types::Sticker,
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct Text {
     /// Unique message identifier inside this chat
@@ -768,6 +954,8 @@ pub struct Text {
     pub is_from_offline: Option<bool>,
     /// Signature of the post author for messages in channels, or the custom title of an anonymous group administrator
     pub author_signature: Option<Box<str>>,
+    /// Unique identifier of the message effect added to the message
+    pub effect_id: Option<Box<str>>,
     /// The actual UTF-8 text of the message
     pub text: Box<str>,
     /// Special entities like usernames, URLs, bot commands, etc. that appear in the text
@@ -778,7 +966,8 @@ pub struct Text {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct Video {
     /// Unique message identifier inside this chat
@@ -827,6 +1016,8 @@ pub struct Video {
     pub media_group_id: Option<Box<str>>,
     /// Signature of the post author for messages in channels, or the custom title of an anonymous group administrator
     pub author_signature: Option<Box<str>>,
+    /// Unique identifier of the message effect added to the message
+    pub effect_id: Option<Box<str>>,
     /// Information about the video
     pub video: types::Video,
     /// Caption
@@ -834,13 +1025,16 @@ pub struct Video {
     /// Special entities like usernames, URLs, bot commands, etc. that appear in the caption
     #[serde(rename = "caption_entities")]
     pub entities: Option<Box<[MessageEntity]>>,
+    /// `true`, if the caption must be shown above the message media
+    pub show_caption_above_media: Option<bool>,
     /// `true`, if the message media is covered by a spoiler animation
     pub has_media_spoiler: Option<bool>,
     /// Inline keyboard attached to the message. `login_url` buttons are represented as ordinary `url` buttons.
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct VideoNote {
     /// Unique message identifier inside this chat
@@ -881,13 +1075,16 @@ pub struct VideoNote {
     pub is_from_offline: Option<bool>,
     /// Signature of the post author for messages in channels, or the custom title of an anonymous group administrator
     pub author_signature: Option<Box<str>>,
+    /// Unique identifier of the message effect added to the message
+    pub effect_id: Option<Box<str>>,
     /// Information about the video message
     pub video_note: types::VideoNote,
     /// Inline keyboard attached to the message. `login_url` buttons are represented as ordinary `url` buttons.
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct Voice {
     /// Unique message identifier inside this chat
@@ -932,6 +1129,8 @@ pub struct Voice {
     pub is_from_offline: Option<bool>,
     /// Signature of the post author for messages in channels, or the custom title of an anonymous group administrator
     pub author_signature: Option<Box<str>>,
+    /// Unique identifier of the message effect added to the message
+    pub effect_id: Option<Box<str>>,
     /// Information about the file
     pub voice: types::Voice,
     /// Caption
@@ -943,12 +1142,15 @@ pub struct Voice {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct MigrateToChat {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
     pub id: i64,
+    /// Sender of the message; empty for messages sent to channels. For backward compatibility, the field contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub from: Option<User>,
     /// Sender of the message, sent on behalf of a chat. For example, the channel itself for channel posts, the supergroup itself for messages from anonymous group administrators, the linked channel for messages automatically forwarded to the discussion group. For backward compatibility, the field *from* contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
     pub sender_chat: Option<Chat>,
     /// Date the message was sent in Unix time
@@ -960,12 +1162,15 @@ pub struct MigrateToChat {
     pub to_chat_id: i64,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct MigrateFromChat {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
     pub id: i64,
+    /// Sender of the message; empty for messages sent to channels. For backward compatibility, the field contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub from: Option<User>,
     /// Sender of the message, sent on behalf of a chat. For example, the channel itself for channel posts, the supergroup itself for messages from anonymous group administrators, the linked channel for messages automatically forwarded to the discussion group. For backward compatibility, the field *from* contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
     pub sender_chat: Option<Chat>,
     /// Date the message was sent in Unix time
@@ -977,7 +1182,8 @@ pub struct MigrateFromChat {
     pub from_chat_id: i64,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct NewChatMembers {
     /// Unique message identifier inside this chat
@@ -996,7 +1202,8 @@ pub struct NewChatMembers {
     pub members: Box<[User]>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct LeftChatMember {
     /// Unique message identifier inside this chat
@@ -1015,7 +1222,8 @@ pub struct LeftChatMember {
     pub member: User,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct NewChatTitle {
     /// Unique message identifier inside this chat
@@ -1034,7 +1242,8 @@ pub struct NewChatTitle {
     pub title: Box<str>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct NewChatPhoto {
     /// Unique message identifier inside this chat
@@ -1053,7 +1262,8 @@ pub struct NewChatPhoto {
     pub photo: Box<[PhotoSize]>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct DeleteChatPhoto {
     /// Unique message identifier inside this chat
@@ -1072,7 +1282,8 @@ pub struct DeleteChatPhoto {
     pub photo: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct GroupChatCreated {
     /// Unique message identifier inside this chat
@@ -1091,7 +1302,8 @@ pub struct GroupChatCreated {
     pub created: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct SupergroupChatCreated {
     /// Unique message identifier inside this chat
@@ -1110,7 +1322,8 @@ pub struct SupergroupChatCreated {
     pub created: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct ChannelChatCreated {
     /// Unique message identifier inside this chat
@@ -1130,7 +1343,8 @@ pub struct ChannelChatCreated {
 }
 
 #[allow(clippy::module_name_repetitions)]
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct MessageAutoDeleteTimerChanged {
     /// Unique message identifier inside this chat
@@ -1149,7 +1363,8 @@ pub struct MessageAutoDeleteTimerChanged {
     pub timer: types::MessageAutoDeleteTimerChanged,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct Pinned {
     /// Unique message identifier inside this chat
@@ -1179,7 +1394,8 @@ pub struct Pinned {
     pub message: Box<MaybeInaccessibleMessage>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct Invoice {
     /// Unique message identifier inside this chat
@@ -1220,13 +1436,16 @@ pub struct Invoice {
     pub is_from_offline: Option<bool>,
     /// Signature of the post author for messages in channels, or the custom title of an anonymous group administrator
     pub author_signature: Option<Box<str>>,
+    /// Unique identifier of the message effect added to the message
+    pub effect_id: Option<Box<str>>,
     /// Message is an invoice for a [`payment`](https://core.telegram.org/bots/api#payments), information about the invoice. [`More about payments`](https://core.telegram.org/bots/api#payments)
     pub invoice: types::Invoice,
     /// Inline keyboard attached to the message. `login_url` buttons are represented as ordinary `url` buttons.
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct SuccessfulPayment {
     /// Unique message identifier inside this chat
@@ -1245,7 +1464,28 @@ pub struct SuccessfulPayment {
     pub payment: types::SuccessfulPayment,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
+#[event(try_from = Update)]
+pub struct RefundedPayment {
+    /// Unique message identifier inside this chat
+    #[serde(rename = "message_id")]
+    pub id: i64,
+    /// Sender of the message; empty for messages sent to channels. For backward compatibility, the field contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub from: Option<User>,
+    /// Sender of the message, sent on behalf of a chat. For example, the channel itself for channel posts, the supergroup itself for messages from anonymous group administrators, the linked channel for messages automatically forwarded to the discussion group. For backward compatibility, the field *from* contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub sender_chat: Option<Chat>,
+    /// Date the message was sent in Unix time
+    pub date: i64,
+    /// Conversation the message belongs to
+    pub chat: Chat,
+    /// Message is a service message about a refunded payment, information about the payment. [`More about payments`](https://core.telegram.org/bots/api#payments)
+    #[serde(rename = "refunded_payment")]
+    pub payment: types::RefundedPayment,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct UsersShared {
     /// Unique message identifier inside this chat
@@ -1262,7 +1502,8 @@ pub struct UsersShared {
     pub shared: types::UsersShared,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct ChatShared {
     /// Unique message identifier inside this chat
@@ -1279,12 +1520,15 @@ pub struct ChatShared {
     pub shared: types::ChatShared,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct ConnectedWebsite {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
     pub id: i64,
+    /// Sender of the message; empty for messages sent to channels. For backward compatibility, the field contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub from: Option<User>,
     /// Date the message was sent in Unix time
     pub date: i64,
     /// Conversation the message belongs to
@@ -1294,12 +1538,15 @@ pub struct ConnectedWebsite {
     pub website: Box<str>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct WriteAccessAllowed {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
     pub id: i64,
+    /// Sender of the message; empty for messages sent to channels. For backward compatibility, the field contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub from: Option<User>,
     /// Date the message was sent in Unix time
     pub date: i64,
     /// Conversation the message belongs to
@@ -1309,7 +1556,8 @@ pub struct WriteAccessAllowed {
     pub allowed: types::WriteAccessAllowed,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct PassportData {
     /// Unique message identifier inside this chat
@@ -1337,12 +1585,15 @@ pub struct PassportData {
     pub data: types::PassportData,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct ProximityAlertTriggered {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
     pub id: i64,
+    /// Sender of the message; empty for messages sent to channels. For backward compatibility, the field contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub from: Option<User>,
     /// Date the message was sent in Unix time
     pub date: i64,
     /// Conversation the message belongs to
@@ -1352,12 +1603,15 @@ pub struct ProximityAlertTriggered {
     pub triggered: types::ProximityAlertTriggered,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct ChatBoostAdded {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
     pub id: i64,
+    /// Sender of the message; empty for messages sent to channels. For backward compatibility, the field contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub from: Option<User>,
     /// Date the message was sent in Unix time
     pub date: i64,
     /// Conversation the message belongs to
@@ -1367,7 +1621,26 @@ pub struct ChatBoostAdded {
     pub added: types::ChatBoostAdded,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
+#[event(try_from = Update)]
+pub struct ChatBackgroundSet {
+    /// Unique message identifier inside this chat
+    #[serde(rename = "message_id")]
+    pub id: i64,
+    /// Sender of the message; empty for messages sent to channels. For backward compatibility, the field contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub from: Option<User>,
+    /// Date the message was sent in Unix time
+    pub date: i64,
+    /// Conversation the message belongs to
+    pub chat: Chat,
+    /// Service message: chat background set
+    #[serde(rename = "chat_background_set")]
+    pub background: types::ChatBackground,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct ForumTopicCreated {
     /// Unique message identifier inside this chat
@@ -1395,7 +1668,8 @@ pub struct ForumTopicCreated {
     pub created: types::ForumTopicCreated,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct ForumTopicEdited {
     /// Unique message identifier inside this chat
@@ -1423,7 +1697,8 @@ pub struct ForumTopicEdited {
     pub edited: types::ForumTopicEdited,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct ForumTopicClosed {
     /// Unique message identifier inside this chat
@@ -1451,7 +1726,8 @@ pub struct ForumTopicClosed {
     pub closed: types::ForumTopicClosed,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct ForumTopicReopened {
     /// Unique message identifier inside this chat
@@ -1479,7 +1755,8 @@ pub struct ForumTopicReopened {
     pub reopened: types::ForumTopicReopened,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct GeneralForumTopicHidden {
     /// Unique message identifier inside this chat
@@ -1507,7 +1784,8 @@ pub struct GeneralForumTopicHidden {
     pub hidden: types::GeneralForumTopicHidden,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct GeneralForumTopicUnhidden {
     /// Unique message identifier inside this chat
@@ -1535,7 +1813,8 @@ pub struct GeneralForumTopicUnhidden {
     pub unhidden: types::GeneralForumTopicUnhidden,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct GiveawayCreated {
     /// Unique message identifier inside this chat
@@ -1557,7 +1836,8 @@ pub struct GiveawayCreated {
     pub created: types::GiveawayCreated,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct Giveaway {
     /// Unique message identifier inside this chat
@@ -1580,7 +1860,8 @@ pub struct Giveaway {
     pub giveaway: types::Giveaway,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct GiveawayWinners {
     /// Unique message identifier inside this chat
@@ -1604,7 +1885,8 @@ pub struct GiveawayWinners {
     pub winners: types::GiveawayWinners,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct GiveawayCompleted {
     /// Unique message identifier inside this chat
@@ -1626,7 +1908,148 @@ pub struct GiveawayCompleted {
     pub completed: types::GiveawayCompleted,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
+#[event(try_from = Update)]
+pub struct ChecklistTasksDone {
+    /// Unique message identifier inside this chat
+    #[serde(rename = "message_id")]
+    pub id: i64,
+    /// Sender of the message; empty for messages sent to channels. For backward compatibility, the field contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub from: Option<User>,
+    /// Sender of the message, sent on behalf of a chat. For example, the channel itself for channel posts, the supergroup itself for messages from anonymous group administrators, the linked channel for messages automatically forwarded to the discussion group. For backward compatibility, the field *from* contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub sender_chat: Option<Chat>,
+    /// Date the message was sent in Unix time
+    pub date: i64,
+    /// Conversation the message belongs to
+    pub chat: Chat,
+    /// Service message: some tasks in a checklist were marked as done or not done
+    #[serde(rename = "checklist_tasks_done")]
+    pub tasks_done: types::ChecklistTasksDone,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
+#[event(try_from = Update)]
+pub struct ChecklistTasksAdded {
+    /// Unique message identifier inside this chat
+    #[serde(rename = "message_id")]
+    pub id: i64,
+    /// Sender of the message; empty for messages sent to channels. For backward compatibility, the field contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub from: Option<User>,
+    /// Sender of the message, sent on behalf of a chat. For example, the channel itself for channel posts, the supergroup itself for messages from anonymous group administrators, the linked channel for messages automatically forwarded to the discussion group. For backward compatibility, the field *from* contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub sender_chat: Option<Chat>,
+    /// Date the message was sent in Unix time
+    pub date: i64,
+    /// Conversation the message belongs to
+    pub chat: Chat,
+    /// Service message: tasks were added to a checklist
+    #[serde(rename = "checklist_tasks_added")]
+    pub tasks_added: types::ChecklistTasksAdded,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
+#[event(try_from = Update)]
+pub struct SuggestedPostApproved {
+    /// Unique message identifier inside this chat
+    #[serde(rename = "message_id")]
+    pub id: i64,
+    /// Sender of the message; empty for messages sent to channels. For backward compatibility, the field contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub from: Option<User>,
+    /// Sender of the message, sent on behalf of a chat. For example, the channel itself for channel posts, the supergroup itself for messages from anonymous group administrators, the linked channel for messages automatically forwarded to the discussion group. For backward compatibility, the field *from* contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub sender_chat: Option<Chat>,
+    /// Date the message was sent in Unix time
+    pub date: i64,
+    /// Conversation the message belongs to
+    pub chat: Chat,
+    /// Service message: a suggested post was approved
+    #[serde(rename = "suggested_post_approved")]
+    pub approved: types::SuggestedPostApproved,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
+#[event(try_from = Update)]
+pub struct SuggestedPostApprovalFailed {
+    /// Unique message identifier inside this chat
+    #[serde(rename = "message_id")]
+    pub id: i64,
+    /// Sender of the message; empty for messages sent to channels. For backward compatibility, the field contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub from: Option<User>,
+    /// Sender of the message, sent on behalf of a chat. For example, the channel itself for channel posts, the supergroup itself for messages from anonymous group administrators, the linked channel for messages automatically forwarded to the discussion group. For backward compatibility, the field *from* contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub sender_chat: Option<Chat>,
+    /// Date the message was sent in Unix time
+    pub date: i64,
+    /// Conversation the message belongs to
+    pub chat: Chat,
+    /// Service message: approval of a suggested post has failed
+    #[serde(rename = "suggested_post_approval_failed")]
+    pub approval_failed: types::SuggestedPostApprovalFailed,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
+#[event(try_from = Update)]
+pub struct SuggestedPostDeclined {
+    /// Unique message identifier inside this chat
+    #[serde(rename = "message_id")]
+    pub id: i64,
+    /// Sender of the message; empty for messages sent to channels. For backward compatibility, the field contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub from: Option<User>,
+    /// Sender of the message, sent on behalf of a chat. For example, the channel itself for channel posts, the supergroup itself for messages from anonymous group administrators, the linked channel for messages automatically forwarded to the discussion group. For backward compatibility, the field *from* contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub sender_chat: Option<Chat>,
+    /// Date the message was sent in Unix time
+    pub date: i64,
+    /// Conversation the message belongs to
+    pub chat: Chat,
+    /// Service message: a suggested post was declined
+    #[serde(rename = "suggested_post_declined")]
+    pub declined: types::SuggestedPostDeclined,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
+#[event(try_from = Update)]
+pub struct SuggestedPostPaid {
+    /// Unique message identifier inside this chat
+    #[serde(rename = "message_id")]
+    pub id: i64,
+    /// Sender of the message; empty for messages sent to channels. For backward compatibility, the field contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub from: Option<User>,
+    /// Sender of the message, sent on behalf of a chat. For example, the channel itself for channel posts, the supergroup itself for messages from anonymous group administrators, the linked channel for messages automatically forwarded to the discussion group. For backward compatibility, the field *from* contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub sender_chat: Option<Chat>,
+    /// Date the message was sent in Unix time
+    pub date: i64,
+    /// Conversation the message belongs to
+    pub chat: Chat,
+    /// Service message: payment for a suggested post was received
+    #[serde(rename = "suggested_post_paid")]
+    pub paid: types::SuggestedPostPaid,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
+#[event(try_from = Update)]
+pub struct SuggestedPostRefunded {
+    /// Unique message identifier inside this chat
+    #[serde(rename = "message_id")]
+    pub id: i64,
+    /// Sender of the message; empty for messages sent to channels. For backward compatibility, the field contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub from: Option<User>,
+    /// Sender of the message, sent on behalf of a chat. For example, the channel itself for channel posts, the supergroup itself for messages from anonymous group administrators, the linked channel for messages automatically forwarded to the discussion group. For backward compatibility, the field *from* contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub sender_chat: Option<Chat>,
+    /// Date the message was sent in Unix time
+    pub date: i64,
+    /// Conversation the message belongs to
+    pub chat: Chat,
+    /// Service message: payment for a suggested post was refunded
+    #[serde(rename = "suggested_post_refunded")]
+    pub refunded: types::SuggestedPostRefunded,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct VideoChatScheduled {
     /// Unique message identifier inside this chat
@@ -1645,7 +2068,8 @@ pub struct VideoChatScheduled {
     pub scheduled: types::VideoChatScheduled,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct VideoChatStarted {
     /// Unique message identifier inside this chat
@@ -1664,7 +2088,8 @@ pub struct VideoChatStarted {
     pub started: types::VideoChatStarted,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct VideoChatEnded {
     /// Unique message identifier inside this chat
@@ -1683,7 +2108,8 @@ pub struct VideoChatEnded {
     pub ended: types::VideoChatEnded,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct VideoChatParticipantsInvited {
     /// Unique message identifier inside this chat
@@ -1702,12 +2128,15 @@ pub struct VideoChatParticipantsInvited {
     pub invited: types::VideoChatParticipantsInvited,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct WebAppData {
     /// Unique message identifier inside this chat
     #[serde(rename = "message_id")]
     pub id: i64,
+    /// Sender of the message; empty for messages sent to channels. For backward compatibility, the field contains a fake sender user in non-channel chats, if the message was sent on behalf of a chat.
+    pub from: Option<User>,
     /// Date the message was sent in Unix time
     pub date: i64,
     /// Conversation the message belongs to
@@ -1717,6 +2146,23 @@ pub struct WebAppData {
     pub data: types::WebAppData,
 }
 
+/// Get entities of the kinds with the parts of the text they cover, entities out of the text bounds are skipped
+fn parse_entities<'a>(
+    text: &'a str,
+    entities: &[MessageEntity],
+    kinds: &[MessageEntityKind],
+) -> Vec<(MessageEntity, &'a str)> {
+    entities
+        .iter()
+        .filter(|entity| kinds.is_empty() || kinds.iter().any(|kind| kind.is_same(&entity.kind)))
+        .filter_map(|entity| {
+            entity
+                .extract_from(text)
+                .map(|entity_text| (entity.clone(), entity_text))
+        })
+        .collect()
+}
+
 impl Message {
     #[must_use]
     pub const fn id(&self) -> i64 {
@@ -1726,6 +2172,7 @@ impl Message {
             Message::Audio(message) => message.id,
             Message::Document(message) => message.id,
             Message::Photo(message) => message.id,
+            Message::PaidMedia(message) => message.id,
             Message::Sticker(message) => message.id,
             Message::Story(message) => message.id,
             Message::Video(message) => message.id,
@@ -1735,6 +2182,7 @@ impl Message {
             Message::Dice(message) => message.id,
             Message::Game(message) => message.id,
             Message::Poll(message) => message.id,
+            Message::Checklist(message) => message.id,
             Message::Venue(message) => message.id,
             Message::Location(message) => message.id,
             Message::NewChatMembers(message) => message.id,
@@ -1751,6 +2199,7 @@ impl Message {
             Message::Pinned(message) => message.id,
             Message::Invoice(message) => message.id,
             Message::SuccessfulPayment(message) => message.id,
+            Message::RefundedPayment(message) => message.id,
             Message::UsersShared(message) => message.id,
             Message::ChatShared(message) => message.id,
             Message::ConnectedWebsite(message) => message.id,
@@ -1758,6 +2207,7 @@ impl Message {
             Message::PassportData(message) => message.id,
             Message::ProximityAlertTriggered(message) => message.id,
             Message::ChatBoostAdded(message) => message.id,
+            Message::ChatBackgroundSet(message) => message.id,
             Message::ForumTopicCreated(message) => message.id,
             Message::ForumTopicEdited(message) => message.id,
             Message::ForumTopicClosed(message) => message.id,
@@ -1773,6 +2223,13 @@ impl Message {
             Message::Giveaway(message) => message.id,
             Message::GiveawayWinners(message) => message.id,
             Message::GiveawayCompleted(message) => message.id,
+            Message::ChecklistTasksDone(message) => message.id,
+            Message::ChecklistTasksAdded(message) => message.id,
+            Message::SuggestedPostApproved(message) => message.id,
+            Message::SuggestedPostApprovalFailed(message) => message.id,
+            Message::SuggestedPostDeclined(message) => message.id,
+            Message::SuggestedPostPaid(message) => message.id,
+            Message::SuggestedPostRefunded(message) => message.id,
         }
     }
 
@@ -1784,6 +2241,7 @@ impl Message {
             Message::Audio(message) => message.thread_id,
             Message::Document(message) => message.thread_id,
             Message::Photo(message) => message.thread_id,
+            Message::PaidMedia(message) => message.thread_id,
             Message::Sticker(message) => message.thread_id,
             Message::Story(message) => message.thread_id,
             Message::Video(message) => message.thread_id,
@@ -1793,6 +2251,7 @@ impl Message {
             Message::Dice(message) => message.thread_id,
             Message::Game(message) => message.thread_id,
             Message::Poll(message) => message.thread_id,
+            Message::Checklist(message) => message.thread_id,
             Message::Venue(message) => message.thread_id,
             Message::Location(message) => message.thread_id,
             Message::Pinned(message) => message.thread_id,
@@ -1820,6 +2279,7 @@ impl Message {
             Message::Audio(message) => message.date,
             Message::Document(message) => message.date,
             Message::Photo(message) => message.date,
+            Message::PaidMedia(message) => message.date,
             Message::Sticker(message) => message.date,
             Message::Story(message) => message.date,
             Message::Video(message) => message.date,
@@ -1829,6 +2289,7 @@ impl Message {
             Message::Dice(message) => message.date,
             Message::Game(message) => message.date,
             Message::Poll(message) => message.date,
+            Message::Checklist(message) => message.date,
             Message::Venue(message) => message.date,
             Message::Location(message) => message.date,
             Message::NewChatMembers(message) => message.date,
@@ -1845,6 +2306,7 @@ impl Message {
             Message::Pinned(message) => message.date,
             Message::Invoice(message) => message.date,
             Message::SuccessfulPayment(message) => message.date,
+            Message::RefundedPayment(message) => message.date,
             Message::UsersShared(message) => message.date,
             Message::ChatShared(message) => message.date,
             Message::ConnectedWebsite(message) => message.date,
@@ -1852,6 +2314,7 @@ impl Message {
             Message::PassportData(message) => message.date,
             Message::ProximityAlertTriggered(message) => message.date,
             Message::ChatBoostAdded(message) => message.date,
+            Message::ChatBackgroundSet(message) => message.date,
             Message::ForumTopicCreated(message) => message.date,
             Message::ForumTopicEdited(message) => message.date,
             Message::ForumTopicClosed(message) => message.date,
@@ -1867,6 +2330,13 @@ impl Message {
             Message::Giveaway(message) => message.date,
             Message::GiveawayWinners(message) => message.date,
             Message::GiveawayCompleted(message) => message.date,
+            Message::ChecklistTasksDone(message) => message.date,
+            Message::ChecklistTasksAdded(message) => message.date,
+            Message::SuggestedPostApproved(message) => message.date,
+            Message::SuggestedPostApprovalFailed(message) => message.date,
+            Message::SuggestedPostDeclined(message) => message.date,
+            Message::SuggestedPostPaid(message) => message.date,
+            Message::SuggestedPostRefunded(message) => message.date,
         }
     }
 
@@ -1878,6 +2348,7 @@ impl Message {
             Message::Audio(message) => message.sender_business_bot.as_ref(),
             Message::Document(message) => message.sender_business_bot.as_ref(),
             Message::Photo(message) => message.sender_business_bot.as_ref(),
+            Message::PaidMedia(message) => message.sender_business_bot.as_ref(),
             Message::Sticker(message) => message.sender_business_bot.as_ref(),
             Message::Video(message) => message.sender_business_bot.as_ref(),
             Message::VideoNote(message) => message.sender_business_bot.as_ref(),
@@ -1886,6 +2357,7 @@ impl Message {
             Message::Dice(message) => message.sender_business_bot.as_ref(),
             Message::Game(message) => message.sender_business_bot.as_ref(),
             Message::Poll(message) => message.sender_business_bot.as_ref(),
+            Message::Checklist(message) => message.sender_business_bot.as_ref(),
             Message::Venue(message) => message.sender_business_bot.as_ref(),
             Message::Location(message) => message.sender_business_bot.as_ref(),
             Message::Invoice(message) => message.sender_business_bot.as_ref(),
@@ -1917,6 +2389,10 @@ impl Message {
                 Some(ref connection_id) => Some(connection_id),
                 None => None,
             },
+            Message::PaidMedia(message) => match message.business_connection_id {
+                Some(ref connection_id) => Some(connection_id),
+                None => None,
+            },
             Message::Sticker(message) => match message.business_connection_id {
                 Some(ref connection_id) => Some(connection_id),
                 None => None,
@@ -1953,6 +2429,10 @@ impl Message {
                 Some(ref connection_id) => Some(connection_id),
                 None => None,
             },
+            Message::Checklist(message) => match message.business_connection_id {
+                Some(ref connection_id) => Some(connection_id),
+                None => None,
+            },
             Message::Venue(message) => match message.business_connection_id {
                 Some(ref connection_id) => Some(connection_id),
                 None => None,
@@ -1981,6 +2461,7 @@ impl Message {
             Message::Audio(message) => &message.chat,
             Message::Document(message) => &message.chat,
             Message::Photo(message) => &message.chat,
+            Message::PaidMedia(message) => &message.chat,
             Message::Sticker(message) => &message.chat,
             Message::Story(message) => &message.chat,
             Message::Video(message) => &message.chat,
@@ -1990,6 +2471,7 @@ impl Message {
             Message::Dice(message) => &message.chat,
             Message::Game(message) => &message.chat,
             Message::Poll(message) => &message.chat,
+            Message::Checklist(message) => &message.chat,
             Message::Venue(message) => &message.chat,
             Message::Location(message) => &message.chat,
             Message::NewChatMembers(message) => &message.chat,
@@ -2006,6 +2488,7 @@ impl Message {
             Message::Pinned(message) => &message.chat,
             Message::Invoice(message) => &message.chat,
             Message::SuccessfulPayment(message) => &message.chat,
+            Message::RefundedPayment(message) => &message.chat,
             Message::UsersShared(message) => &message.chat,
             Message::ChatShared(message) => &message.chat,
             Message::ConnectedWebsite(message) => &message.chat,
@@ -2013,6 +2496,7 @@ impl Message {
             Message::PassportData(message) => &message.chat,
             Message::ProximityAlertTriggered(message) => &message.chat,
             Message::ChatBoostAdded(message) => &message.chat,
+            Message::ChatBackgroundSet(message) => &message.chat,
             Message::ForumTopicCreated(message) => &message.chat,
             Message::ForumTopicEdited(message) => &message.chat,
             Message::ForumTopicClosed(message) => &message.chat,
@@ -2028,6 +2512,13 @@ impl Message {
             Message::Giveaway(message) => &message.chat,
             Message::GiveawayWinners(message) => &message.chat,
             Message::GiveawayCompleted(message) => &message.chat,
+            Message::ChecklistTasksDone(message) => &message.chat,
+            Message::ChecklistTasksAdded(message) => &message.chat,
+            Message::SuggestedPostApproved(message) => &message.chat,
+            Message::SuggestedPostApprovalFailed(message) => &message.chat,
+            Message::SuggestedPostDeclined(message) => &message.chat,
+            Message::SuggestedPostPaid(message) => &message.chat,
+            Message::SuggestedPostRefunded(message) => &message.chat,
         }
     }
 
@@ -2039,6 +2530,7 @@ impl Message {
             Message::Audio(message) => message.via_bot.as_ref(),
             Message::Document(message) => message.via_bot.as_ref(),
             Message::Photo(message) => message.via_bot.as_ref(),
+            Message::PaidMedia(message) => message.via_bot.as_ref(),
             Message::Sticker(message) => message.via_bot.as_ref(),
             Message::Video(message) => message.via_bot.as_ref(),
             Message::Voice(message) => message.via_bot.as_ref(),
@@ -2087,6 +2579,10 @@ impl Message {
                 Some(ref caption) => Some(caption),
                 None => None,
             },
+            Message::PaidMedia(message) => match message.caption {
+                Some(ref caption) => Some(caption),
+                None => None,
+            },
             _ => None,
         }
     }
@@ -2101,13 +2597,113 @@ impl Message {
     }
 
     #[must_use]
-    pub const fn from(&self) -> Option<&User> {
+    #[allow(clippy::match_as_ref)]
+    pub const fn entities(&self) -> Option<&[MessageEntity]> {
         match self {
-            Message::Text(message) => message.from.as_ref(),
-            Message::Animation(message) => message.from.as_ref(),
-            Message::Audio(message) => message.from.as_ref(),
-            Message::Document(message) => message.from.as_ref(),
+            Message::Text(message) => match message.entities {
+                Some(ref entities) => Some(entities),
+                None => None,
+            },
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::match_as_ref)]
+    pub const fn caption_entities(&self) -> Option<&[MessageEntity]> {
+        match self {
+            Message::Animation(message) => match message.entities {
+                Some(ref entities) => Some(entities),
+                None => None,
+            },
+            Message::Audio(message) => match message.entities {
+                Some(ref entities) => Some(entities),
+                None => None,
+            },
+            Message::Document(message) => match message.entities {
+                Some(ref entities) => Some(entities),
+                None => None,
+            },
+            Message::Photo(message) => match message.entities {
+                Some(ref entities) => Some(entities),
+                None => None,
+            },
+            Message::PaidMedia(message) => match message.entities {
+                Some(ref entities) => Some(entities),
+                None => None,
+            },
+            Message::Video(message) => match message.entities {
+                Some(ref entities) => Some(entities),
+                None => None,
+            },
+            Message::Voice(message) => match message.entities {
+                Some(ref entities) => Some(entities),
+                None => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Get entities of the text with the parts of the text they cover
+    /// # Arguments
+    /// * `kinds` - Kinds of entities to get, if empty, then all entities are returned.
+    ///   Kinds are compared regardless of their data, see [`MessageEntityKind::is_same`]
+    #[must_use]
+    pub fn parse_entities(&self, kinds: &[MessageEntityKind]) -> Vec<(MessageEntity, &str)> {
+        match (self.text(), self.entities()) {
+            (Some(text), Some(entities)) => parse_entities(text, entities, kinds),
+            _ => vec![],
+        }
+    }
+
+    /// Get entities of the caption with the parts of the caption they cover
+    /// # Arguments
+    /// * `kinds` - Kinds of entities to get, if empty, then all entities are returned.
+    ///   Kinds are compared regardless of their data, see [`MessageEntityKind::is_same`]
+    #[must_use]
+    pub fn parse_caption_entities(
+        &self,
+        kinds: &[MessageEntityKind],
+    ) -> Vec<(MessageEntity, &str)> {
+        match (self.caption(), self.caption_entities()) {
+            (Some(caption), Some(entities)) => parse_entities(caption, entities, kinds),
+            _ => vec![],
+        }
+    }
+
+    /// Render the text or caption with its entities to HTML, for example, to re-send the message with formatting.
+    /// See [`Formatter::render_entities`] for more info
+    #[must_use]
+    pub fn html_text(&self) -> Option<String> {
+        self.render_text(&*html_formatter::FORMATTER)
+    }
+
+    /// Render the text or caption with its entities to MarkdownV2, for example, to re-send the message with formatting.
+    /// See [`Formatter::render_entities`] for more info
+    #[must_use]
+    pub fn md_text(&self) -> Option<String> {
+        self.render_text(&*markdown_formatter::FORMATTER)
+    }
+
+    fn render_text(&self, formatter: &impl Formatter) -> Option<String> {
+        if let Some(text) = self.text() {
+            Some(formatter.render_entities(text, self.entities().unwrap_or_default()))
+        } else {
+            self.caption().map(|caption| {
+                formatter.render_entities(caption, self.caption_entities().unwrap_or_default())
+            })
+        }
+    }
+
+    #[must_use]
+    pub const fn from(&self) -> Option<&User> {
+        match self {
+            Message::Text(message) => message.from.as_ref(),
+            Message::Animation(message) => message.from.as_ref(),
+            Message::Audio(message) => message.from.as_ref(),
+            Message::Document(message) => message.from.as_ref(),
             Message::Photo(message) => message.from.as_ref(),
+            Message::PaidMedia(message) => message.from.as_ref(),
             Message::Sticker(message) => message.from.as_ref(),
             Message::Story(message) => message.from.as_ref(),
             Message::Video(message) => message.from.as_ref(),
@@ -2117,6 +2713,7 @@ impl Message {
             Message::Dice(message) => message.from.as_ref(),
             Message::Game(message) => message.from.as_ref(),
             Message::Poll(message) => message.from.as_ref(),
+            Message::Checklist(message) => message.from.as_ref(),
             Message::Venue(message) => message.from.as_ref(),
             Message::Location(message) => message.from.as_ref(),
             Message::NewChatMembers(message) => message.from.as_ref(),
@@ -2131,6 +2728,7 @@ impl Message {
             Message::Pinned(message) => message.from.as_ref(),
             Message::Invoice(message) => message.from.as_ref(),
             Message::SuccessfulPayment(message) => message.from.as_ref(),
+            Message::RefundedPayment(message) => message.from.as_ref(),
             Message::UsersShared(message) => message.from.as_ref(),
             Message::ChatShared(message) => message.from.as_ref(),
             Message::PassportData(message) => message.from.as_ref(),
@@ -2148,7 +2746,21 @@ impl Message {
             Message::Giveaway(message) => message.from.as_ref(),
             Message::GiveawayWinners(message) => message.from.as_ref(),
             Message::GiveawayCompleted(message) => message.from.as_ref(),
-            _ => None,
+            Message::ChecklistTasksDone(message) => message.from.as_ref(),
+            Message::ChecklistTasksAdded(message) => message.from.as_ref(),
+            Message::SuggestedPostApproved(message) => message.from.as_ref(),
+            Message::SuggestedPostApprovalFailed(message) => message.from.as_ref(),
+            Message::SuggestedPostDeclined(message) => message.from.as_ref(),
+            Message::SuggestedPostPaid(message) => message.from.as_ref(),
+            Message::SuggestedPostRefunded(message) => message.from.as_ref(),
+            Message::MigrateToChat(message) => message.from.as_ref(),
+            Message::MigrateFromChat(message) => message.from.as_ref(),
+            Message::ConnectedWebsite(message) => message.from.as_ref(),
+            Message::WriteAccessAllowed(message) => message.from.as_ref(),
+            Message::ProximityAlertTriggered(message) => message.from.as_ref(),
+            Message::ChatBoostAdded(message) => message.from.as_ref(),
+            Message::ChatBackgroundSet(message) => message.from.as_ref(),
+            Message::WebAppData(message) => message.from.as_ref(),
         }
     }
 
@@ -2168,6 +2780,7 @@ impl Message {
             Message::Audio(message) => message.sender_boost_count,
             Message::Document(message) => message.sender_boost_count,
             Message::Photo(message) => message.sender_boost_count,
+            Message::PaidMedia(message) => message.sender_boost_count,
             Message::Sticker(message) => message.sender_boost_count,
             Message::Story(message) => message.sender_boost_count,
             Message::Video(message) => message.sender_boost_count,
@@ -2177,6 +2790,7 @@ impl Message {
             Message::Dice(message) => message.sender_boost_count,
             Message::Game(message) => message.sender_boost_count,
             Message::Poll(message) => message.sender_boost_count,
+            Message::Checklist(message) => message.sender_boost_count,
             Message::Venue(message) => message.sender_boost_count,
             Message::Location(message) => message.sender_boost_count,
             Message::Invoice(message) => message.sender_boost_count,
@@ -2192,6 +2806,7 @@ impl Message {
             Message::Audio(message) => message.sender_chat.as_ref(),
             Message::Document(message) => message.sender_chat.as_ref(),
             Message::Photo(message) => message.sender_chat.as_ref(),
+            Message::PaidMedia(message) => message.sender_chat.as_ref(),
             Message::Sticker(message) => message.sender_chat.as_ref(),
             Message::Story(message) => message.sender_chat.as_ref(),
             Message::Video(message) => message.sender_chat.as_ref(),
@@ -2201,6 +2816,7 @@ impl Message {
             Message::Dice(message) => message.sender_chat.as_ref(),
             Message::Game(message) => message.sender_chat.as_ref(),
             Message::Poll(message) => message.sender_chat.as_ref(),
+            Message::Checklist(message) => message.sender_chat.as_ref(),
             Message::Venue(message) => message.sender_chat.as_ref(),
             Message::Location(message) => message.sender_chat.as_ref(),
             Message::NewChatMembers(message) => message.sender_chat.as_ref(),
@@ -2217,6 +2833,7 @@ impl Message {
             Message::Pinned(message) => message.sender_chat.as_ref(),
             Message::Invoice(message) => message.sender_chat.as_ref(),
             Message::SuccessfulPayment(message) => message.sender_chat.as_ref(),
+            Message::RefundedPayment(message) => message.sender_chat.as_ref(),
             Message::PassportData(message) => message.sender_chat.as_ref(),
             Message::ForumTopicCreated(message) => message.sender_chat.as_ref(),
             Message::ForumTopicEdited(message) => message.sender_chat.as_ref(),
@@ -2232,6 +2849,13 @@ impl Message {
             Message::Giveaway(message) => message.sender_chat.as_ref(),
             Message::GiveawayWinners(message) => message.sender_chat.as_ref(),
             Message::GiveawayCompleted(message) => message.sender_chat.as_ref(),
+            Message::ChecklistTasksDone(message) => message.sender_chat.as_ref(),
+            Message::ChecklistTasksAdded(message) => message.sender_chat.as_ref(),
+            Message::SuggestedPostApproved(message) => message.sender_chat.as_ref(),
+            Message::SuggestedPostApprovalFailed(message) => message.sender_chat.as_ref(),
+            Message::SuggestedPostDeclined(message) => message.sender_chat.as_ref(),
+            Message::SuggestedPostPaid(message) => message.sender_chat.as_ref(),
+            Message::SuggestedPostRefunded(message) => message.sender_chat.as_ref(),
             _ => None,
         }
     }
@@ -2268,6 +2892,10 @@ impl Message {
                 Some(ref author_signature) => Some(author_signature),
                 None => None,
             },
+            Message::PaidMedia(message) => match message.author_signature {
+                Some(ref author_signature) => Some(author_signature),
+                None => None,
+            },
             Message::Sticker(message) => match message.author_signature {
                 Some(ref author_signature) => Some(author_signature),
                 None => None,
@@ -2304,6 +2932,10 @@ impl Message {
                 Some(ref author_signature) => Some(author_signature),
                 None => None,
             },
+            Message::Checklist(message) => match message.author_signature {
+                Some(ref author_signature) => Some(author_signature),
+                None => None,
+            },
             Message::Venue(message) => match message.author_signature {
                 Some(ref author_signature) => Some(author_signature),
                 None => None,
@@ -2324,6 +2956,110 @@ impl Message {
         }
     }
 
+    #[must_use]
+    #[allow(clippy::match_as_ref)]
+    pub const fn effect_id(&self) -> Option<&str> {
+        match self {
+            Message::Animation(message) => match message.effect_id {
+                Some(ref effect_id) => Some(effect_id),
+                None => None,
+            },
+            Message::Audio(message) => match message.effect_id {
+                Some(ref effect_id) => Some(effect_id),
+                None => None,
+            },
+            Message::Contact(message) => match message.effect_id {
+                Some(ref effect_id) => Some(effect_id),
+                None => None,
+            },
+            Message::Dice(message) => match message.effect_id {
+                Some(ref effect_id) => Some(effect_id),
+                None => None,
+            },
+            Message::Document(message) => match message.effect_id {
+                Some(ref effect_id) => Some(effect_id),
+                None => None,
+            },
+            Message::Game(message) => match message.effect_id {
+                Some(ref effect_id) => Some(effect_id),
+                None => None,
+            },
+            Message::Poll(message) => match message.effect_id {
+                Some(ref effect_id) => Some(effect_id),
+                None => None,
+            },
+            Message::Checklist(message) => match message.effect_id {
+                Some(ref effect_id) => Some(effect_id),
+                None => None,
+            },
+            Message::Venue(message) => match message.effect_id {
+                Some(ref effect_id) => Some(effect_id),
+                None => None,
+            },
+            Message::Location(message) => match message.effect_id {
+                Some(ref effect_id) => Some(effect_id),
+                None => None,
+            },
+            Message::Photo(message) => match message.effect_id {
+                Some(ref effect_id) => Some(effect_id),
+                None => None,
+            },
+            Message::PaidMedia(message) => match message.effect_id {
+                Some(ref effect_id) => Some(effect_id),
+                None => None,
+            },
+            Message::Sticker(message) => match message.effect_id {
+                Some(ref effect_id) => Some(effect_id),
+                None => None,
+            },
+            Message::Text(message) => match message.effect_id {
+                Some(ref effect_id) => Some(effect_id),
+                None => None,
+            },
+            Message::Video(message) => match message.effect_id {
+                Some(ref effect_id) => Some(effect_id),
+                None => None,
+            },
+            Message::VideoNote(message) => match message.effect_id {
+                Some(ref effect_id) => Some(effect_id),
+                None => None,
+            },
+            Message::Voice(message) => match message.effect_id {
+                Some(ref effect_id) => Some(effect_id),
+                None => None,
+            },
+            Message::Invoice(message) => match message.effect_id {
+                Some(ref effect_id) => Some(effect_id),
+                None => None,
+            },
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::match_as_ref)]
+    pub const fn media_group_id(&self) -> Option<&str> {
+        match self {
+            Message::Audio(message) => match message.media_group_id {
+                Some(ref media_group_id) => Some(media_group_id),
+                None => None,
+            },
+            Message::Document(message) => match message.media_group_id {
+                Some(ref media_group_id) => Some(media_group_id),
+                None => None,
+            },
+            Message::Photo(message) => match message.media_group_id {
+                Some(ref media_group_id) => Some(media_group_id),
+                None => None,
+            },
+            Message::Video(message) => match message.media_group_id {
+                Some(ref media_group_id) => Some(media_group_id),
+                None => None,
+            },
+            _ => None,
+        }
+    }
+
     #[must_use]
     pub const fn reply_to_message(&self) -> Option<&Message> {
         match self {
@@ -2332,6 +3068,7 @@ impl Message {
             Message::Audio(message) => message.reply_to_message.as_ref(),
             Message::Document(message) => message.reply_to_message.as_ref(),
             Message::Photo(message) => message.reply_to_message.as_ref(),
+            Message::PaidMedia(message) => message.reply_to_message.as_ref(),
             Message::Sticker(message) => message.reply_to_message.as_ref(),
             Message::Video(message) => message.reply_to_message.as_ref(),
             Message::VideoNote(message) => message.reply_to_message.as_ref(),
@@ -2340,6 +3077,7 @@ impl Message {
             Message::Dice(message) => message.reply_to_message.as_ref(),
             Message::Game(message) => message.reply_to_message.as_ref(),
             Message::Poll(message) => message.reply_to_message.as_ref(),
+            Message::Checklist(message) => message.reply_to_message.as_ref(),
             Message::Venue(message) => message.reply_to_message.as_ref(),
             Message::Location(message) => message.reply_to_message.as_ref(),
             Message::Pinned(message) => message.reply_to_message.as_ref(),
@@ -2365,6 +3103,7 @@ impl Message {
             Message::Audio(message) => message.reply_to_story.as_ref(),
             Message::Document(message) => message.reply_to_story.as_ref(),
             Message::Photo(message) => message.reply_to_story.as_ref(),
+            Message::PaidMedia(message) => message.reply_to_story.as_ref(),
             Message::Sticker(message) => message.reply_to_story.as_ref(),
             Message::Video(message) => message.reply_to_story.as_ref(),
             Message::VideoNote(message) => message.reply_to_story.as_ref(),
@@ -2373,6 +3112,7 @@ impl Message {
             Message::Dice(message) => message.reply_to_story.as_ref(),
             Message::Game(message) => message.reply_to_story.as_ref(),
             Message::Poll(message) => message.reply_to_story.as_ref(),
+            Message::Checklist(message) => message.reply_to_story.as_ref(),
             Message::Venue(message) => message.reply_to_story.as_ref(),
             Message::Location(message) => message.reply_to_story.as_ref(),
             Message::Pinned(message) => message.reply_to_story.as_ref(),
@@ -2395,6 +3135,7 @@ impl Message {
             Message::Audio(message) => message.external_reply.as_ref(),
             Message::Document(message) => message.external_reply.as_ref(),
             Message::Photo(message) => message.external_reply.as_ref(),
+            Message::PaidMedia(message) => message.external_reply.as_ref(),
             Message::Sticker(message) => message.external_reply.as_ref(),
             Message::Story(message) => message.external_reply.as_ref(),
             Message::Video(message) => message.external_reply.as_ref(),
@@ -2406,6 +3147,7 @@ impl Message {
             Message::Giveaway(message) => message.external_reply.as_ref(),
             Message::GiveawayWinners(message) => message.external_reply.as_ref(),
             Message::Poll(message) => message.external_reply.as_ref(),
+            Message::Checklist(message) => message.external_reply.as_ref(),
             Message::Venue(message) => message.external_reply.as_ref(),
             Message::Location(message) => message.external_reply.as_ref(),
             Message::Invoice(message) => message.external_reply.as_ref(),
@@ -2423,6 +3165,7 @@ impl Message {
             Message::Video(message) => message.quote.as_ref(),
             Message::Voice(message) => message.quote.as_ref(),
             Message::Photo(message) => message.quote.as_ref(),
+            Message::PaidMedia(message) => message.quote.as_ref(),
             _ => None,
         }
     }
@@ -2435,9 +3178,11 @@ impl Message {
             Message::Audio(message) => message.edit_date,
             Message::Document(message) => message.edit_date,
             Message::Photo(message) => message.edit_date,
+            Message::PaidMedia(message) => message.edit_date,
             Message::Video(message) => message.edit_date,
             Message::Game(message) => message.edit_date,
             Message::Poll(message) => message.edit_date,
+            Message::Checklist(message) => message.edit_date,
             Message::Venue(message) => message.edit_date,
             Message::Location(message) => message.edit_date,
             _ => None,
@@ -2452,6 +3197,7 @@ impl Message {
             Message::Audio(message) => message.reply_markup.as_ref(),
             Message::Document(message) => message.reply_markup.as_ref(),
             Message::Photo(message) => message.reply_markup.as_ref(),
+            Message::PaidMedia(message) => message.reply_markup.as_ref(),
             Message::Video(message) => message.reply_markup.as_ref(),
             Message::VideoNote(message) => message.reply_markup.as_ref(),
             Message::Voice(message) => message.reply_markup.as_ref(),
@@ -2459,6 +3205,7 @@ impl Message {
             Message::Dice(message) => message.reply_markup.as_ref(),
             Message::Game(message) => message.reply_markup.as_ref(),
             Message::Poll(message) => message.reply_markup.as_ref(),
+            Message::Checklist(message) => message.reply_markup.as_ref(),
             Message::Venue(message) => message.reply_markup.as_ref(),
             Message::Location(message) => message.reply_markup.as_ref(),
             Message::Invoice(message) => message.reply_markup.as_ref(),
@@ -2476,6 +3223,7 @@ impl Message {
             Message::Video(message) => message.is_automatic_forward,
             Message::Voice(message) => message.is_automatic_forward,
             Message::Photo(message) => message.is_automatic_forward,
+            Message::PaidMedia(message) => message.is_automatic_forward,
             _ => None,
         }
     }
@@ -2490,6 +3238,7 @@ impl Message {
             Message::Video(message) => message.has_protected_content,
             Message::Voice(message) => message.has_protected_content,
             Message::Photo(message) => message.has_protected_content,
+            Message::PaidMedia(message) => message.has_protected_content,
             _ => None,
         }
     }
@@ -2502,6 +3251,7 @@ impl Message {
             Message::Audio(message) => message.forward_origin.as_ref(),
             Message::Document(message) => message.forward_origin.as_ref(),
             Message::Photo(message) => message.forward_origin.as_ref(),
+            Message::PaidMedia(message) => message.forward_origin.as_ref(),
             Message::Sticker(message) => message.forward_origin.as_ref(),
             Message::Story(message) => message.forward_origin.as_ref(),
             Message::Video(message) => message.forward_origin.as_ref(),
@@ -2511,6 +3261,7 @@ impl Message {
             Message::Dice(message) => message.forward_origin.as_ref(),
             Message::Game(message) => message.forward_origin.as_ref(),
             Message::Poll(message) => message.forward_origin.as_ref(),
+            Message::Checklist(message) => message.forward_origin.as_ref(),
             Message::Venue(message) => message.forward_origin.as_ref(),
             Message::Location(message) => message.forward_origin.as_ref(),
             Message::Invoice(message) => message.forward_origin.as_ref(),
@@ -2574,6 +3325,14 @@ impl Message {
         }
     }
 
+    #[must_use]
+    pub const fn checklist(&self) -> Option<&types::Checklist> {
+        match self {
+            Message::Checklist(message) => Some(&message.checklist),
+            _ => None,
+        }
+    }
+
     #[must_use]
     pub const fn venue(&self) -> Option<&types::Venue> {
         match self {
@@ -2688,6 +3447,14 @@ impl Message {
         }
     }
 
+    #[must_use]
+    pub const fn refunded_payment(&self) -> Option<&types::RefundedPayment> {
+        match self {
+            Message::RefundedPayment(message) => Some(&message.payment),
+            _ => None,
+        }
+    }
+
     #[must_use]
     pub const fn users_shared(&self) -> Option<&types::UsersShared> {
         match self {
@@ -2744,6 +3511,14 @@ impl Message {
         }
     }
 
+    #[must_use]
+    pub const fn chat_background_set(&self) -> Option<&types::ChatBackground> {
+        match self {
+            Message::ChatBackgroundSet(message) => Some(&message.background),
+            _ => None,
+        }
+    }
+
     #[must_use]
     pub const fn forum_topic_created(&self) -> Option<&types::ForumTopicCreated> {
         match self {
@@ -2824,6 +3599,64 @@ impl Message {
         }
     }
 
+    #[must_use]
+    pub const fn checklist_tasks_done(&self) -> Option<&types::ChecklistTasksDone> {
+        match self {
+            Message::ChecklistTasksDone(message) => Some(&message.tasks_done),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn checklist_tasks_added(&self) -> Option<&types::ChecklistTasksAdded> {
+        match self {
+            Message::ChecklistTasksAdded(message) => Some(&message.tasks_added),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn suggested_post_approved(&self) -> Option<&types::SuggestedPostApproved> {
+        match self {
+            Message::SuggestedPostApproved(message) => Some(&message.approved),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn suggested_post_approval_failed(
+        &self,
+    ) -> Option<&types::SuggestedPostApprovalFailed> {
+        match self {
+            Message::SuggestedPostApprovalFailed(message) => Some(&message.approval_failed),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn suggested_post_declined(&self) -> Option<&types::SuggestedPostDeclined> {
+        match self {
+            Message::SuggestedPostDeclined(message) => Some(&message.declined),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn suggested_post_paid(&self) -> Option<&types::SuggestedPostPaid> {
+        match self {
+            Message::SuggestedPostPaid(message) => Some(&message.paid),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn suggested_post_refunded(&self) -> Option<&types::SuggestedPostRefunded> {
+        match self {
+            Message::SuggestedPostRefunded(message) => Some(&message.refunded),
+            _ => None,
+        }
+    }
+
     #[must_use]
     pub const fn video_chat_scheduled(&self) -> Option<&types::VideoChatScheduled> {
         match self {
@@ -2910,6 +3743,14 @@ impl Message {
         }
     }
 
+    #[must_use]
+    pub const fn paid_media(&self) -> Option<&types::PaidMediaInfo> {
+        match self {
+            Message::PaidMedia(message) => Some(&message.paid_media),
+            _ => None,
+        }
+    }
+
     #[must_use]
     pub const fn story(&self) -> Option<&types::Story> {
         match self {
@@ -2967,6 +3808,166 @@ impl Message {
     }
 }
 
+#[cfg(feature = "chrono")]
+impl Message {
+    /// Date the message was sent.
+    /// Every message has the date, so [`DateTime::UNIX_EPOCH`] is returned only if the date is out of range
+    #[must_use]
+    pub fn date_utc(&self) -> DateTime<Utc> {
+        date_time::from_timestamp(self.date()).unwrap_or(DateTime::UNIX_EPOCH)
+    }
+
+    /// Date the message was last edited
+    #[must_use]
+    pub fn edit_date_utc(&self) -> Option<DateTime<Utc>> {
+        self.edit_date().and_then(date_time::from_timestamp)
+    }
+}
+
+/// Shortcuts to build methods for the message.
+/// Every shortcut fills in the chat of the message, the forum topic if the message is sent to a forum topic,
+/// and the business connection if the message is received from a connected business account,
+/// so the returned builder can be customized further and sent by [`Bot::send`](crate::client::Bot::send).
+impl Message {
+    /// Forum topic to send answers to: set only for messages in forum supergroups
+    const fn answer_thread_id(&self) -> Option<i64> {
+        match self.chat() {
+            Chat::Supergroup(chat) => match chat.is_forum {
+                Some(true) => self.thread_id(),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Send a text message to the chat of the message
+    #[must_use]
+    pub fn answer(&self, text: impl Into<String>) -> SendMessage {
+        SendMessage::new(self.chat().id(), text)
+            .message_thread_id_option(self.answer_thread_id())
+            .business_connection_id_option(self.business_connection_id())
+    }
+
+    /// Send a text message as a reply to the message
+    #[must_use]
+    pub fn reply(&self, text: impl Into<String>) -> SendMessage {
+        self.answer(text)
+            .reply_parameters(ReplyParameters::new(self.id()))
+    }
+
+    /// Send a photo to the chat of the message
+    #[must_use]
+    pub fn answer_photo<'a>(&self, photo: impl Into<InputFile<'a>>) -> SendPhoto<'a> {
+        SendPhoto::new(self.chat().id(), photo)
+            .message_thread_id_option(self.answer_thread_id())
+            .business_connection_id_option(self.business_connection_id())
+    }
+
+    /// Send a photo as a reply to the message
+    #[must_use]
+    pub fn reply_photo<'a>(&self, photo: impl Into<InputFile<'a>>) -> SendPhoto<'a> {
+        self.answer_photo(photo)
+            .reply_parameters(ReplyParameters::new(self.id()))
+    }
+
+    /// Send a video to the chat of the message
+    #[must_use]
+    pub fn answer_video<'a>(&self, video: impl Into<InputFile<'a>>) -> SendVideo<'a> {
+        SendVideo::new(self.chat().id(), video)
+            .message_thread_id_option(self.answer_thread_id())
+            .business_connection_id_option(self.business_connection_id())
+    }
+
+    /// Send a video as a reply to the message
+    #[must_use]
+    pub fn reply_video<'a>(&self, video: impl Into<InputFile<'a>>) -> SendVideo<'a> {
+        self.answer_video(video)
+            .reply_parameters(ReplyParameters::new(self.id()))
+    }
+
+    /// Send an animation to the chat of the message
+    #[must_use]
+    pub fn answer_animation<'a>(&self, animation: impl Into<InputFile<'a>>) -> SendAnimation<'a> {
+        SendAnimation::new(self.chat().id(), animation)
+            .message_thread_id_option(self.answer_thread_id())
+            .business_connection_id_option(self.business_connection_id())
+    }
+
+    /// Send an animation as a reply to the message
+    #[must_use]
+    pub fn reply_animation<'a>(&self, animation: impl Into<InputFile<'a>>) -> SendAnimation<'a> {
+        self.answer_animation(animation)
+            .reply_parameters(ReplyParameters::new(self.id()))
+    }
+
+    /// Send a document to the chat of the message
+    #[must_use]
+    pub fn answer_document<'a>(&self, document: impl Into<InputFile<'a>>) -> SendDocument<'a> {
+        SendDocument::new(self.chat().id(), document)
+            .message_thread_id_option(self.answer_thread_id())
+            .business_connection_id_option(self.business_connection_id())
+    }
+
+    /// Send a document as a reply to the message
+    #[must_use]
+    pub fn reply_document<'a>(&self, document: impl Into<InputFile<'a>>) -> SendDocument<'a> {
+        self.answer_document(document)
+            .reply_parameters(ReplyParameters::new(self.id()))
+    }
+
+    /// Edit text of the message
+    #[must_use]
+    pub fn edit_text(&self, text: impl Into<String>) -> EditMessageText {
+        EditMessageText::new(text)
+            .chat_id(self.chat().id())
+            .message_id(self.id())
+            .business_connection_id_option(self.business_connection_id())
+    }
+
+    /// Edit inline keyboard of the message. Without a keyboard the current one is removed
+    #[must_use]
+    pub fn edit_reply_markup(&self) -> EditMessageReplyMarkup {
+        EditMessageReplyMarkup::new()
+            .chat_id(self.chat().id())
+            .message_id(self.id())
+            .business_connection_id_option(self.business_connection_id())
+    }
+
+    /// Delete the message.
+    /// Messages received via a business connection can't be deleted by this method, use [`Message::delete_business`] instead
+    #[must_use]
+    pub fn delete(&self) -> DeleteMessage {
+        DeleteMessage::new(self.chat().id(), self.id())
+    }
+
+    /// Delete the message on behalf of the business account.
+    /// Returns `None` if the message wasn't received via a business connection
+    #[must_use]
+    pub fn delete_business(&self) -> Option<DeleteBusinessMessages> {
+        self.business_connection_id().map(|business_connection_id| {
+            DeleteBusinessMessages::new([self.id()]).business_connection_id(business_connection_id)
+        })
+    }
+
+    /// Forward the message to the chat
+    #[must_use]
+    pub fn forward_to(&self, chat_id: impl Into<ChatIdKind>) -> ForwardMessage {
+        ForwardMessage::new(chat_id, self.chat().id(), self.id())
+    }
+
+    /// Copy the message to the chat
+    #[must_use]
+    pub fn copy_to(&self, chat_id: impl Into<ChatIdKind>) -> CopyMessage {
+        CopyMessage::new(chat_id, self.chat().id(), self.id())
+    }
+
+    /// Set a reaction on the message
+    #[must_use]
+    pub fn react(&self, reaction: impl Into<ReactionType>) -> SetMessageReaction {
+        SetMessageReaction::new(self.chat().id(), self.id()).reaction(reaction)
+    }
+}
+
 impl Default for Message {
     #[must_use]
     fn default() -> Self {
@@ -3011,10 +4012,12 @@ impl_try_from_message!(MigrateToChat, MigrateToChat);
 impl_try_from_message!(MigrateFromChat, MigrateFromChat);
 impl_try_from_message!(Pinned, Pinned);
 impl_try_from_message!(SuccessfulPayment, SuccessfulPayment);
+impl_try_from_message!(RefundedPayment, RefundedPayment);
 impl_try_from_message!(ConnectedWebsite, ConnectedWebsite);
 impl_try_from_message!(PassportData, PassportData);
 impl_try_from_message!(ProximityAlertTriggered, ProximityAlertTriggered);
 impl_try_from_message!(ChatBoostAdded, ChatBoostAdded);
+impl_try_from_message!(ChatBackgroundSet, ChatBackgroundSet);
 impl_try_from_message!(ForumTopicCreated, ForumTopicCreated);
 impl_try_from_message!(ForumTopicEdited, ForumTopicEdited);
 impl_try_from_message!(ForumTopicClosed, ForumTopicClosed);
@@ -3025,14 +4028,23 @@ impl_try_from_message!(GiveawayCreated, GiveawayCreated);
 impl_try_from_message!(Giveaway, Giveaway);
 impl_try_from_message!(GiveawayWinners, GiveawayWinners);
 impl_try_from_message!(GiveawayCompleted, GiveawayCompleted);
+impl_try_from_message!(ChecklistTasksDone, ChecklistTasksDone);
+impl_try_from_message!(ChecklistTasksAdded, ChecklistTasksAdded);
+impl_try_from_message!(SuggestedPostApproved, SuggestedPostApproved);
+impl_try_from_message!(SuggestedPostApprovalFailed, SuggestedPostApprovalFailed);
+impl_try_from_message!(SuggestedPostDeclined, SuggestedPostDeclined);
+impl_try_from_message!(SuggestedPostPaid, SuggestedPostPaid);
+impl_try_from_message!(SuggestedPostRefunded, SuggestedPostRefunded);
 impl_try_from_message!(VideoChatScheduled, VideoChatScheduled);
 impl_try_from_message!(VideoChatStarted, VideoChatStarted);
 impl_try_from_message!(VideoChatEnded, VideoChatEnded);
 impl_try_from_message!(VideoChatParticipantsInvited, VideoChatParticipantsInvited);
 impl_try_from_message!(WebAppData, WebAppData);
 impl_try_from_message!(Poll, Poll);
+impl_try_from_message!(Checklist, Checklist);
 impl_try_from_message!(Venue, Venue);
 impl_try_from_message!(Photo, Photo);
+impl_try_from_message!(PaidMedia, PaidMedia);
 impl_try_from_message!(Story, Story);
 impl_try_from_message!(Sticker, Sticker);
 impl_try_from_message!(Video, Video);
@@ -3092,10 +4104,12 @@ impl_try_from_update!(MigrateToChat);
 impl_try_from_update!(MigrateFromChat);
 impl_try_from_update!(Pinned);
 impl_try_from_update!(SuccessfulPayment);
+impl_try_from_update!(RefundedPayment);
 impl_try_from_update!(ConnectedWebsite);
 impl_try_from_update!(PassportData);
 impl_try_from_update!(ProximityAlertTriggered);
 impl_try_from_update!(ChatBoostAdded);
+impl_try_from_update!(ChatBackgroundSet);
 impl_try_from_update!(ForumTopicCreated);
 impl_try_from_update!(ForumTopicEdited);
 impl_try_from_update!(ForumTopicClosed);
@@ -3106,14 +4120,23 @@ impl_try_from_update!(GiveawayCreated);
 impl_try_from_update!(Giveaway);
 impl_try_from_update!(GiveawayWinners);
 impl_try_from_update!(GiveawayCompleted);
+impl_try_from_update!(ChecklistTasksDone);
+impl_try_from_update!(ChecklistTasksAdded);
+impl_try_from_update!(SuggestedPostApproved);
+impl_try_from_update!(SuggestedPostApprovalFailed);
+impl_try_from_update!(SuggestedPostDeclined);
+impl_try_from_update!(SuggestedPostPaid);
+impl_try_from_update!(SuggestedPostRefunded);
 impl_try_from_update!(VideoChatScheduled);
 impl_try_from_update!(VideoChatStarted);
 impl_try_from_update!(VideoChatEnded);
 impl_try_from_update!(VideoChatParticipantsInvited);
 impl_try_from_update!(WebAppData);
 impl_try_from_update!(Poll);
+impl_try_from_update!(Checklist);
 impl_try_from_update!(Venue);
 impl_try_from_update!(Photo);
+impl_try_from_update!(PaidMedia);
 impl_try_from_update!(Story);
 impl_try_from_update!(Sticker);
 impl_try_from_update!(Video);
@@ -3252,7 +4275,7 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
 
             match message {
                 Message::Text(message) => assert_eq!(*message, message_kind),
@@ -3304,7 +4327,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::Animation(message) => assert_eq!(*message, message_kind),
@@ -3332,7 +4357,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::Audio(message) => assert_eq!(*message, message_kind),
@@ -3359,7 +4386,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::Document(message) => assert_eq!(*message, message_kind),
@@ -3388,7 +4417,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::Photo(message) => assert_eq!(*message, message_kind),
@@ -3397,6 +4428,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn deserialize_paid_media() {
+        let jsons = [serde_json::json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": {
+                "id": -1,
+                "title": "test",
+                "type": "channel",
+            },
+            "paid_media": {
+                "star_count": 1,
+                "paid_media": [
+                    {
+                        "type": "preview",
+                        "width": 1,
+                        "height": 1,
+                    },
+                    {
+                        "type": "photo",
+                        "photo": [{
+                            "file_id": "test",
+                            "file_unique_id": "test",
+                            "width": 1,
+                            "height": 1,
+                        }],
+                    },
+                ],
+            },
+        })];
+
+        for json in jsons {
+            let message_kind = serde_json::from_value(json.clone()).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
+
+            match message {
+                Message::PaidMedia(message) => assert_eq!(*message, message_kind),
+                _ => panic!("Unexpected message type: {message:?}"),
+            }
+        }
+    }
+
     #[test]
     fn deserialize_sticker() {
         let jsons = [serde_json::json!({
@@ -3420,7 +4495,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::Sticker(message) => assert_eq!(*message, message_kind),
@@ -3452,7 +4529,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::Story(message) => assert_eq!(*message, message_kind),
@@ -3483,7 +4562,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::Video(message) => assert_eq!(*message, message_kind),
@@ -3513,7 +4594,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::VideoNote(message) => assert_eq!(*message, message_kind),
@@ -3542,7 +4625,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::Voice(message) => assert_eq!(*message, message_kind),
@@ -3570,7 +4655,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::Contact(message) => assert_eq!(*message, message_kind),
@@ -3598,7 +4685,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::Dice(message) => assert_eq!(*message, message_kind),
@@ -3632,7 +4721,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::Game(message) => assert_eq!(*message, message_kind),
@@ -3675,7 +4766,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::Poll(message) => assert_eq!(*message, message_kind),
@@ -3684,6 +4777,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn deserialize_checklist() {
+        let jsons = [serde_json::json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": {
+                "id": -1,
+                "title": "test",
+                "type": "channel",
+            },
+            "checklist": {
+                "title": "test",
+                "tasks": [
+                    {
+                        "id": 1,
+                        "text": "test",
+                    },
+                ],
+            },
+        })];
+
+        for json in jsons {
+            let message_kind = serde_json::from_value(json.clone()).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
+
+            match message {
+                Message::Checklist(message) => assert_eq!(*message, message_kind),
+                _ => panic!("Unexpected message type: {message:?}"),
+            }
+        }
+    }
+
     #[test]
     fn deserialize_venue() {
         let jsons = [
@@ -3729,7 +4856,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::Venue(message) => assert_eq!(*message, message_kind),
@@ -3756,7 +4885,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::Location(message) => assert_eq!(*message, message_kind),
@@ -3784,7 +4915,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::NewChatMembers(message) => assert_eq!(*message, message_kind),
@@ -3812,7 +4945,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::LeftChatMember(message) => assert_eq!(*message, message_kind),
@@ -3836,7 +4971,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::NewChatTitle(message) => assert_eq!(*message, message_kind),
@@ -3865,7 +5002,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::NewChatPhoto(message) => assert_eq!(*message, message_kind),
@@ -3889,7 +5028,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::DeleteChatPhoto(message) => assert_eq!(*message, message_kind),
@@ -3913,7 +5054,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::GroupChatCreated(message) => {
@@ -3939,7 +5082,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::SupergroupChatCreated(message) => {
@@ -3965,7 +5110,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::ChannelChatCreated(message) => {
@@ -3993,7 +5140,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::MessageAutoDeleteTimerChanged(message) => {
@@ -4019,7 +5168,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::MigrateToChat(message) => {
@@ -4045,7 +5196,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::MigrateFromChat(message) => {
@@ -4080,7 +5233,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::Pinned(message) => assert_eq!(*message, message_kind),
@@ -4110,7 +5265,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::Invoice(message) => assert_eq!(*message, message_kind),
@@ -4140,7 +5297,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::SuccessfulPayment(message) => {
@@ -4151,6 +5310,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn deserialize_refunded_payment() {
+        let jsons = [serde_json::json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": {
+                "id": -1,
+                "title": "test",
+                "type": "channel",
+            },
+            "refunded_payment": {"currency": "XTR", "total_amount": 1, "invoice_payload": "test", "telegram_payment_charge_id": "test"},
+        })];
+
+        for json in jsons {
+            let message_kind = serde_json::from_value(json.clone()).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
+
+            match message {
+                Message::RefundedPayment(message) => {
+                    assert_eq!(message, message_kind);
+                }
+                _ => panic!("Unexpected message type: {message:?}"),
+            }
+        }
+    }
+
     #[test]
     fn deserialize_users_shared() {
         let jsons = [serde_json::json!({
@@ -4171,7 +5358,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::UsersShared(message) => assert_eq!(*message, message_kind),
@@ -4198,7 +5387,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::ChatShared(message) => assert_eq!(*message, message_kind),
@@ -4222,7 +5413,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::ConnectedWebsite(message) => {
@@ -4248,7 +5441,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::WriteAccessAllowed(message) => {
@@ -4285,7 +5480,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::PassportData(message) => assert_eq!(*message, message_kind),
@@ -4321,7 +5518,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::ProximityAlertTriggered(message) => {
@@ -4349,7 +5548,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::ChatBoostAdded(message) => {
@@ -4360,6 +5561,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn deserialize_chat_background_set() {
+        let jsons = [serde_json::json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": {
+                "id": -1,
+                "title": "test",
+                "type": "channel",
+            },
+            "chat_background_set": {"type": {"type": "chat_theme", "theme_name": "🌈"}},
+        })];
+
+        for json in jsons {
+            let message_kind = serde_json::from_value(json.clone()).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
+
+            match message {
+                Message::ChatBackgroundSet(message) => {
+                    assert_eq!(message, message_kind);
+                }
+                _ => panic!("Unexpected message type: {message:?}"),
+            }
+        }
+    }
+
     #[test]
     fn deserialize_forum_topic_created() {
         let jsons = [serde_json::json!({
@@ -4379,7 +5608,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::ForumTopicCreated(message) => {
@@ -4408,7 +5639,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::ForumTopicEdited(message) => {
@@ -4434,7 +5667,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::ForumTopicClosed(message) => {
@@ -4460,7 +5695,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::ForumTopicReopened(message) => {
@@ -4486,7 +5723,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::GeneralForumTopicHidden(message) => {
@@ -4512,7 +5751,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::GeneralForumTopicUnhidden(message) => {
@@ -4538,7 +5779,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::GiveawayCreated(message) => {
@@ -4572,7 +5815,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::Giveaway(message) => assert_eq!(*message, message_kind),
@@ -4610,7 +5855,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::GiveawayWinners(message) => {
@@ -4638,7 +5885,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::GiveawayCompleted(message) => {
@@ -4649,6 +5898,202 @@ mod tests {
         }
     }
 
+    #[test]
+    fn deserialize_checklist_tasks_done() {
+        let jsons = [serde_json::json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": {
+                "id": -1,
+                "title": "test",
+                "type": "channel",
+            },
+            "checklist_tasks_done": {"marked_as_done_task_ids": [1]},
+        })];
+
+        for json in jsons {
+            let message_kind = serde_json::from_value(json.clone()).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
+
+            match message {
+                Message::ChecklistTasksDone(message) => {
+                    assert_eq!(message, message_kind);
+                }
+                _ => panic!("Unexpected message type: {message:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn deserialize_checklist_tasks_added() {
+        let jsons = [serde_json::json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": {
+                "id": -1,
+                "title": "test",
+                "type": "channel",
+            },
+            "checklist_tasks_added": {"tasks": [{"id": 1, "text": "test"}]},
+        })];
+
+        for json in jsons {
+            let message_kind = serde_json::from_value(json.clone()).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
+
+            match message {
+                Message::ChecklistTasksAdded(message) => {
+                    assert_eq!(message, message_kind);
+                }
+                _ => panic!("Unexpected message type: {message:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn deserialize_suggested_post_approved() {
+        let jsons = [serde_json::json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": {
+                "id": -1,
+                "title": "test",
+                "type": "channel",
+            },
+            "suggested_post_approved": {"send_date": 0},
+        })];
+
+        for json in jsons {
+            let message_kind = serde_json::from_value(json.clone()).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
+
+            match message {
+                Message::SuggestedPostApproved(message) => {
+                    assert_eq!(message, message_kind);
+                }
+                _ => panic!("Unexpected message type: {message:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn deserialize_suggested_post_approval_failed() {
+        let jsons = [serde_json::json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": {
+                "id": -1,
+                "title": "test",
+                "type": "channel",
+            },
+            "suggested_post_approval_failed": {"price": {"currency": "XTR", "amount": 5}},
+        })];
+
+        for json in jsons {
+            let message_kind = serde_json::from_value(json.clone()).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
+
+            match message {
+                Message::SuggestedPostApprovalFailed(message) => {
+                    assert_eq!(message, message_kind);
+                }
+                _ => panic!("Unexpected message type: {message:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn deserialize_suggested_post_declined() {
+        let jsons = [serde_json::json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": {
+                "id": -1,
+                "title": "test",
+                "type": "channel",
+            },
+            "suggested_post_declined": {},
+        })];
+
+        for json in jsons {
+            let message_kind = serde_json::from_value(json.clone()).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
+
+            match message {
+                Message::SuggestedPostDeclined(message) => {
+                    assert_eq!(message, message_kind);
+                }
+                _ => panic!("Unexpected message type: {message:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn deserialize_suggested_post_paid() {
+        let jsons = [serde_json::json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": {
+                "id": -1,
+                "title": "test",
+                "type": "channel",
+            },
+            "suggested_post_paid": {"currency": "XTR"},
+        })];
+
+        for json in jsons {
+            let message_kind = serde_json::from_value(json.clone()).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
+
+            match message {
+                Message::SuggestedPostPaid(message) => {
+                    assert_eq!(message, message_kind);
+                }
+                _ => panic!("Unexpected message type: {message:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn deserialize_suggested_post_refunded() {
+        let jsons = [serde_json::json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": {
+                "id": -1,
+                "title": "test",
+                "type": "channel",
+            },
+            "suggested_post_refunded": {"reason": "post_deleted"},
+        })];
+
+        for json in jsons {
+            let message_kind = serde_json::from_value(json.clone()).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
+
+            match message {
+                Message::SuggestedPostRefunded(message) => {
+                    assert_eq!(message, message_kind);
+                }
+                _ => panic!("Unexpected message type: {message:?}"),
+            }
+        }
+    }
+
     #[test]
     fn deserialize_video_chat_scheduled() {
         let jsons = [serde_json::json!({
@@ -4666,7 +6111,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::VideoChatScheduled(message) => {
@@ -4692,7 +6139,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::VideoChatStarted(message) => {
@@ -4720,7 +6169,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::VideoChatEnded(message) => {
@@ -4759,7 +6210,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::VideoChatParticipantsInvited(message) => {
@@ -4788,7 +6241,9 @@ mod tests {
 
         for json in jsons {
             let message_kind = serde_json::from_value(json.clone()).unwrap();
-            let message: Message = serde_json::from_value(json).unwrap();
+            let message: Message = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&message).unwrap(), json);
 
             match message {
                 Message::WebAppData(message) => assert_eq!(*message, message_kind),
@@ -4898,4 +6353,71 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn answer_shortcuts() {
+        let message: Message = serde_json::from_value(serde_json::json!({
+            "message_id": 2,
+            "message_thread_id": 1,
+            "date": 0,
+            "chat": {
+                "id": -1,
+                "title": "test",
+                "type": "supergroup",
+                "is_forum": true,
+            },
+            "is_topic_message": true,
+            "text": "test",
+        }))
+        .unwrap();
+
+        let method = message.answer("test");
+        assert_eq!(method.chat_id, ChatIdKind::Id(-1));
+        assert_eq!(method.message_thread_id, Some(1));
+        assert_eq!(method.business_connection_id, None);
+        assert_eq!(method.reply_parameters, None);
+
+        let method = message.reply("test");
+        assert_eq!(method.message_thread_id, Some(1));
+        assert_eq!(method.reply_parameters, Some(ReplyParameters::new(2)));
+
+        let method = message.edit_text("test");
+        assert_eq!(method.chat_id, Some(ChatIdKind::Id(-1)));
+        assert_eq!(method.message_id, Some(2));
+
+        let message: Message = serde_json::from_value(serde_json::json!({
+            "message_id": 2,
+            "message_thread_id": 1,
+            "date": 0,
+            "chat": {
+                "id": -1,
+                "title": "test",
+                "type": "supergroup",
+            },
+            "text": "test",
+        }))
+        .unwrap();
+
+        assert_eq!(message.answer("test").message_thread_id, None);
+
+        let message: Message = serde_json::from_value(serde_json::json!({
+            "message_id": 2,
+            "date": 0,
+            "business_connection_id": "test",
+            "chat": {
+                "id": 1,
+                "type": "private",
+            },
+            "text": "test",
+        }))
+        .unwrap();
+
+        let method = message.reply("test");
+        assert_eq!(method.business_connection_id.as_deref(), Some("test"));
+        assert_eq!(method.chat_id, ChatIdKind::Id(1));
+
+        let method = message.delete_business().unwrap();
+        assert_eq!(method.business_connection_id.as_deref(), Some("test"));
+        assert_eq!(method.message_ids, [2]);
+    }
 }
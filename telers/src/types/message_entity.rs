@@ -2,6 +2,7 @@ use super::User;
 
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::ops::Range;
 use strum_macros::Display;
 
 /// This object represents one special entity in a text message. For example, hashtags, usernames, URLs, etc.
@@ -100,7 +101,56 @@ impl CustomEmoji {
     }
 }
 
+impl Kind {
+    /// Check if the kinds are the same regardless of their data,
+    /// for example, [`Kind::Pre`] with different languages are the same kind
+    #[must_use]
+    pub fn is_same(&self, other: &Kind) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+/// Convert offset in UTF-16 code units to byte index in the text
+fn utf16_to_byte_index(text: &str, offset: usize) -> Option<usize> {
+    let mut utf16_index = 0;
+
+    for (byte_index, char) in text.char_indices() {
+        if utf16_index == offset {
+            return Some(byte_index);
+        }
+        if utf16_index > offset {
+            // Offset points to the middle of a surrogate pair
+            return None;
+        }
+
+        utf16_index += char.len_utf16();
+    }
+
+    (utf16_index == offset).then_some(text.len())
+}
+
 impl MessageEntity {
+    /// Get the part of the text this entity covers.
+    /// Offset and length of the entity are in UTF-16 code units, so they are converted to byte indexes of the text
+    /// # Returns
+    /// `None` if the entity is out of the text bounds or splits a char
+    #[must_use]
+    pub fn extract_from<'a>(&self, text: &'a str) -> Option<&'a str> {
+        self.byte_range(text).map(|range| &text[range])
+    }
+
+    /// Get the range of bytes of the text this entity covers,
+    /// offset and length of the entity are converted from UTF-16 code units
+    /// # Returns
+    /// `None` if the entity is out of the text bounds or splits a char
+    #[must_use]
+    pub fn byte_range(&self, text: &str) -> Option<Range<usize>> {
+        let start = utf16_to_byte_index(text, self.offset.into())?;
+        let length = utf16_to_byte_index(&text[start..], self.length.into())?;
+
+        Some(start..start + length)
+    }
+
     #[must_use]
     pub fn new(offset: u16, length: u16, kind: impl Into<Kind>) -> Self {
         Self {
@@ -261,3 +311,31 @@ impl From<CustomEmoji> for Kind {
         Self::CustomEmoji(custom_emoji)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_from() {
+        let text = "a😀b ёж";
+
+        assert_eq!(MessageEntity::new_bold(0, 1).extract_from(text), Some("a"));
+        assert_eq!(MessageEntity::new_bold(1, 2).extract_from(text), Some("😀"));
+        assert_eq!(MessageEntity::new_bold(3, 1).extract_from(text), Some("b"));
+        assert_eq!(MessageEntity::new_bold(5, 2).extract_from(text), Some("ёж"));
+        assert_eq!(MessageEntity::new_bold(7, 0).extract_from(text), Some(""));
+        // Splits the surrogate pair
+        assert_eq!(MessageEntity::new_bold(2, 1).extract_from(text), None);
+        assert_eq!(MessageEntity::new_bold(0, 2).extract_from(text), None);
+        // Out of the text bounds
+        assert_eq!(MessageEntity::new_bold(5, 3).extract_from(text), None);
+        assert_eq!(MessageEntity::new_bold(8, 0).extract_from(text), None);
+    }
+
+    #[test]
+    fn test_kind_is_same() {
+        assert!(Kind::Pre(Pre::new()).is_same(&Kind::Pre(Pre::new_language("rust"))));
+        assert!(!Kind::Bold.is_same(&Kind::Italic));
+    }
+}
@@ -1,11 +1,13 @@
 use super::Chat;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 /// The message was originally sent on behalf of a chat to a group chat.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#messageoriginchat>
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct MessageOriginChat {
     /// Date the message was sent originally in Unix time
     pub date: i64,
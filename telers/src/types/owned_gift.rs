@@ -0,0 +1,104 @@
+use super::{OwnedGiftRegular, OwnedGiftUnique};
+
+use serde::{Deserialize, Serialize};
+
+/// This object describes a gift received and owned by a user or a chat. Currently, it can be one of
+/// - [`OwnedGiftRegular`]
+/// - [`OwnedGiftUnique`]
+/// # Documentation
+/// <https://core.telegram.org/bots/api#ownedgift>
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OwnedGift {
+    Regular(Box<OwnedGiftRegular>),
+    Unique(Box<OwnedGiftUnique>),
+}
+
+impl From<OwnedGiftRegular> for OwnedGift {
+    fn from(gift: OwnedGiftRegular) -> Self {
+        Self::Regular(Box::new(gift))
+    }
+}
+
+impl From<OwnedGiftUnique> for OwnedGift {
+    fn from(gift: OwnedGiftUnique) -> Self {
+        Self::Unique(Box::new(gift))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let jsons = [
+            serde_json::json!({
+                "type": "regular",
+                "gift": {
+                    "id": "test",
+                    "sticker": {
+                        "file_id": "test",
+                        "file_unique_id": "test",
+                        "type": "regular",
+                        "width": 1,
+                        "height": 1,
+                        "is_animated": false,
+                        "is_video": false,
+                    },
+                    "star_count": 1,
+                },
+                "send_date": 0,
+            }),
+            serde_json::json!({
+                "type": "unique",
+                "gift": {
+                    "base_name": "test",
+                    "name": "test",
+                    "number": 1,
+                    "model": {
+                        "name": "test",
+                        "sticker": {
+                            "file_id": "test",
+                            "file_unique_id": "test",
+                            "type": "regular",
+                            "width": 1,
+                            "height": 1,
+                            "is_animated": false,
+                            "is_video": false,
+                        },
+                        "rarity_per_mille": 1,
+                    },
+                    "symbol": {
+                        "name": "test",
+                        "sticker": {
+                            "file_id": "test",
+                            "file_unique_id": "test",
+                            "type": "regular",
+                            "width": 1,
+                            "height": 1,
+                            "is_animated": false,
+                            "is_video": false,
+                        },
+                        "rarity_per_mille": 1,
+                    },
+                    "backdrop": {
+                        "name": "test",
+                        "colors": {
+                            "center_color": 0,
+                            "edge_color": 0,
+                            "symbol_color": 0,
+                            "text_color": 0,
+                        },
+                        "rarity_per_mille": 1,
+                    },
+                },
+                "send_date": 0,
+            }),
+        ];
+
+        for json in jsons {
+            let _: OwnedGift = serde_json::from_value(json).unwrap();
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use super::{PaidMediaPhoto, PaidMediaPreview, PaidMediaVideo};
+
+use serde::{Deserialize, Serialize};
+
+/// This object describes paid media. Currently, it can be one of
+/// - [`PaidMediaPreview`]
+/// - [`PaidMediaPhoto`]
+/// - [`PaidMediaVideo`]
+/// # Documentation
+/// <https://core.telegram.org/bots/api#paidmedia>
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PaidMedia {
+    Preview(PaidMediaPreview),
+    Photo(PaidMediaPhoto),
+    Video(PaidMediaVideo),
+}
+
+impl From<PaidMediaPreview> for PaidMedia {
+    fn from(media: PaidMediaPreview) -> Self {
+        Self::Preview(media)
+    }
+}
+
+impl From<PaidMediaPhoto> for PaidMedia {
+    fn from(media: PaidMediaPhoto) -> Self {
+        Self::Photo(media)
+    }
+}
+
+impl From<PaidMediaVideo> for PaidMedia {
+    fn from(media: PaidMediaVideo) -> Self {
+        Self::Video(media)
+    }
+}
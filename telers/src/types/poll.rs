@@ -2,12 +2,18 @@ use super::{MessageEntity, PollOption, Update, UpdateKind};
 
 use crate::{errors::ConvertToTypeError, extractors::FromEvent};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[cfg(feature = "chrono")]
+use crate::utils::date_time;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, TimeDelta, Utc};
 
 /// This object contains information about a poll.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#poll>
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, FromEvent)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum Poll {
@@ -15,13 +21,16 @@ pub enum Poll {
     Quiz(Quiz),
 }
 
-#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct Regular {
     /// Unique poll identifier
     pub id: Box<str>,
     /// Poll question, 1-300 characters
     pub question: Box<str>,
+    /// Special entities that appear in the question. Currently, only custom emoji entities are allowed in poll questions
+    pub question_entities: Option<Box<[MessageEntity]>>,
     /// List of poll options
     pub options: Box<[PollOption]>,
     /// Total number of users that voted in the poll
@@ -38,13 +47,16 @@ pub struct Regular {
     pub close_date: Option<i64>,
 }
 
-#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct Quiz {
     /// Unique poll identifier
     pub id: Box<str>,
     /// Poll question, 1-300 characters
     pub question: Box<str>,
+    /// Special entities that appear in the question. Currently, only custom emoji entities are allowed in poll questions
+    pub question_entities: Option<Box<[MessageEntity]>>,
     /// List of poll options
     pub options: Box<[PollOption]>,
     /// Total number of users that voted in the poll
@@ -73,6 +85,29 @@ impl Default for Poll {
     }
 }
 
+#[cfg(feature = "chrono")]
+impl Poll {
+    /// Amount of time the poll will be active after creation
+    #[must_use]
+    pub fn open_period_delta(&self) -> Option<TimeDelta> {
+        match self {
+            Poll::Regular(poll) => poll.open_period,
+            Poll::Quiz(poll) => poll.open_period,
+        }
+        .map(date_time::from_seconds)
+    }
+
+    /// Point in time when the poll will be automatically closed
+    #[must_use]
+    pub fn close_date_utc(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Poll::Regular(poll) => poll.close_date,
+            Poll::Quiz(poll) => poll.close_date,
+        }
+        .and_then(date_time::from_timestamp)
+    }
+}
+
 impl TryFrom<Poll> for Regular {
     type Error = ConvertToTypeError;
 
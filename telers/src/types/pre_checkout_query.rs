@@ -1,13 +1,15 @@
 use super::{OrderInfo, Update, UpdateKind, User};
 
-use crate::{errors::ConvertToTypeError, FromEvent};
+use crate::{errors::ConvertToTypeError, methods::AnswerPreCheckoutQuery, FromEvent};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 /// This object contains information about an incoming pre-checkout query.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#precheckoutquery>
-#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize, FromEvent)]
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize, Serialize, FromEvent)]
 #[event(try_from = Update)]
 pub struct PreCheckoutQuery {
     /// Unique query identifier
@@ -26,6 +28,14 @@ pub struct PreCheckoutQuery {
     pub order_info: Option<OrderInfo>,
 }
 
+impl PreCheckoutQuery {
+    /// Answer the pre-checkout query. Pass `false` and set an error message if there was a problem
+    #[must_use]
+    pub fn answer(&self, ok: bool) -> AnswerPreCheckoutQuery {
+        AnswerPreCheckoutQuery::new(&*self.id, ok)
+    }
+}
+
 impl TryFrom<Update> for PreCheckoutQuery {
     type Error = ConvertToTypeError;
 
@@ -1,11 +1,11 @@
 use super::ReactionType;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Represents a reaction added to a message along with the number of times it was added.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#reactioncount>
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
 pub struct ReactionCount {
     /// Type of the reaction
     #[serde(rename = "type")]
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// Describes an amount of Telegram Stars.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#staramount>
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
+pub struct StarAmount {
+    /// Integer amount of Telegram Stars, rounded to 0; can be negative
+    pub amount: i64,
+    /// The number of 1/1000000000 shares of Telegram Stars; from -999999999 to 999999999; can be negative if and only if `amount` is non-positive
+    pub nanostar_amount: Option<i64>,
+}
@@ -0,0 +1,22 @@
+use super::TransactionPartner;
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// Describes a Telegram Star transaction.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#startransaction>
+#[skip_serializing_none]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
+pub struct StarTransaction {
+    /// Unique identifier of the transaction. Coincides with the identifer of the original transaction for refund transactions. Coincides with `SuccessfulPayment.telegram_payment_charge_id` for successful incoming payments from users.
+    pub id: Box<str>,
+    /// Number of Telegram Stars transferred by the transaction
+    pub amount: i64,
+    /// Date the transaction was created in Unix time
+    pub date: i64,
+    /// Source of an incoming transaction (e.g., a user purchasing goods or services, Fragment refunding a failed withdrawal). Only for incoming transactions
+    pub source: Option<TransactionPartner>,
+    /// Receiver of an outgoing transaction (e.g., a user for a purchase refund, Fragment for a withdrawal). Only for outgoing transactions
+    pub receiver: Option<TransactionPartner>,
+}
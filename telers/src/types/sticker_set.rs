@@ -1,11 +1,13 @@
 use super::{PhotoSize, Sticker};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 /// This object represents a sticker set.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#stickerset>
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct StickerSet {
     /// Sticker set name
     pub name: Box<str>,
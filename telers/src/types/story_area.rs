@@ -0,0 +1,41 @@
+use super::{StoryAreaPosition, StoryAreaType};
+
+use serde::Serialize;
+
+/// Describes a clickable area on a story media.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#storyarea>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StoryArea {
+    /// Position of the area
+    pub position: StoryAreaPosition,
+    /// Type of the area
+    #[serde(rename = "type")]
+    pub area_type: StoryAreaType,
+}
+
+impl StoryArea {
+    #[must_use]
+    pub fn new(position: StoryAreaPosition, area_type: impl Into<StoryAreaType>) -> Self {
+        Self {
+            position,
+            area_type: area_type.into(),
+        }
+    }
+
+    #[must_use]
+    pub fn position(self, val: StoryAreaPosition) -> Self {
+        Self {
+            position: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn area_type(self, val: impl Into<StoryAreaType>) -> Self {
+        Self {
+            area_type: val.into(),
+            ..self
+        }
+    }
+}
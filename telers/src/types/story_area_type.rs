@@ -0,0 +1,54 @@
+use super::{
+    StoryAreaTypeLink, StoryAreaTypeLocation, StoryAreaTypeSuggestedReaction,
+    StoryAreaTypeUniqueGift, StoryAreaTypeWeather,
+};
+
+use serde::Serialize;
+
+/// Describes the type of a clickable area on a story. Currently, it can be one of
+/// - [`StoryAreaTypeLocation`]
+/// - [`StoryAreaTypeSuggestedReaction`]
+/// - [`StoryAreaTypeLink`]
+/// - [`StoryAreaTypeWeather`]
+/// - [`StoryAreaTypeUniqueGift`]
+/// # Documentation
+/// <https://core.telegram.org/bots/api#storyareatype>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StoryAreaType {
+    Location(StoryAreaTypeLocation),
+    SuggestedReaction(StoryAreaTypeSuggestedReaction),
+    Link(StoryAreaTypeLink),
+    Weather(StoryAreaTypeWeather),
+    UniqueGift(StoryAreaTypeUniqueGift),
+}
+
+impl From<StoryAreaTypeLocation> for StoryAreaType {
+    fn from(area_type: StoryAreaTypeLocation) -> Self {
+        Self::Location(area_type)
+    }
+}
+
+impl From<StoryAreaTypeSuggestedReaction> for StoryAreaType {
+    fn from(area_type: StoryAreaTypeSuggestedReaction) -> Self {
+        Self::SuggestedReaction(area_type)
+    }
+}
+
+impl From<StoryAreaTypeLink> for StoryAreaType {
+    fn from(area_type: StoryAreaTypeLink) -> Self {
+        Self::Link(area_type)
+    }
+}
+
+impl From<StoryAreaTypeWeather> for StoryAreaType {
+    fn from(area_type: StoryAreaTypeWeather) -> Self {
+        Self::Weather(area_type)
+    }
+}
+
+impl From<StoryAreaTypeUniqueGift> for StoryAreaType {
+    fn from(area_type: StoryAreaTypeUniqueGift) -> Self {
+        Self::UniqueGift(area_type)
+    }
+}
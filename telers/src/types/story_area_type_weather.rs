@@ -0,0 +1,49 @@
+use serde::Serialize;
+
+/// Describes a story area containing weather information. Currently, a story can have up to 3 weather areas.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#storyareatypeweather>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StoryAreaTypeWeather {
+    /// Temperature, in degree Celsius
+    pub temperature: f64,
+    /// Emoji representing the weather
+    pub emoji: String,
+    /// A color of the area background in the ARGB format
+    pub background_color: i64,
+}
+
+impl StoryAreaTypeWeather {
+    #[must_use]
+    pub fn new(temperature: f64, emoji: impl Into<String>, background_color: i64) -> Self {
+        Self {
+            temperature,
+            emoji: emoji.into(),
+            background_color,
+        }
+    }
+
+    #[must_use]
+    pub fn temperature(self, val: f64) -> Self {
+        Self {
+            temperature: val,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn emoji(self, val: impl Into<String>) -> Self {
+        Self {
+            emoji: val.into(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn background_color(self, val: i64) -> Self {
+        Self {
+            background_color: val,
+            ..self
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use super::SuggestedPostPrice;
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// Contains parameters of a post that is being suggested by the bot.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#suggestedpostparameters>
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Serialize)]
+pub struct SuggestedPostParameters {
+    /// Proposed price for the post. If the field is omitted, then the post is unpaid.
+    pub price: Option<SuggestedPostPrice>,
+    /// Proposed send date of the post. If specified, then the date must be between 300 second and 2678400 seconds (30 days) in the future. If the field is omitted, then the post can be published at any time within 30 days at the sole discretion of the user who approves it.
+    pub send_date: Option<i64>,
+}
+
+impl SuggestedPostParameters {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn price(self, val: SuggestedPostPrice) -> Self {
+        Self {
+            price: Some(val),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn send_date(self, val: i64) -> Self {
+        Self {
+            send_date: Some(val),
+            ..self
+        }
+    }
+}
+
+impl SuggestedPostParameters {
+    #[must_use]
+    pub fn price_option(self, val: Option<SuggestedPostPrice>) -> Self {
+        Self { price: val, ..self }
+    }
+
+    #[must_use]
+    pub fn send_date_option(self, val: Option<i64>) -> Self {
+        Self {
+            send_date: val,
+            ..self
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes the price of a suggested post.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#suggestedpostprice>
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SuggestedPostPrice {
+    /// Currency in which the post will be paid. Currently, must be one of `XTR` for Telegram Stars or `TON` for toncoins
+    pub currency: String,
+    /// The amount of the currency that will be paid for the post in the smallest units of the currency, i.e. Telegram Stars or nanotoncoins. Currently, price in Telegram Stars must be between 5 and 100000, and price in nanotoncoins must be between 10000000 and 10000000000000.
+    pub amount: i64,
+}
+
+impl SuggestedPostPrice {
+    #[must_use]
+    pub fn new(currency: impl Into<String>, amount: i64) -> Self {
+        Self {
+            currency: currency.into(),
+            amount,
+        }
+    }
+
+    #[must_use]
+    pub fn currency(self, val: impl Into<String>) -> Self {
+        Self {
+            currency: val.into(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn amount(self, val: i64) -> Self {
+        Self {
+            amount: val,
+            ..self
+        }
+    }
+}
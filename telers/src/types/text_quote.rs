@@ -1,11 +1,13 @@
 use super::MessageEntity;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 /// This object contains information about the quoted part of a message that is replied to by the given message.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#textquote>
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
 pub struct TextQuote {
     /// Text of the quoted part of a message that is replied to by the given message
     pub text: Box<str>,
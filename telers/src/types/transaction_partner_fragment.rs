@@ -0,0 +1,14 @@
+use super::RevenueWithdrawalState;
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// Describes a withdrawal transaction with Fragment.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#transactionpartnerfragment>
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TransactionPartnerFragment {
+    /// State of the transaction if the transaction is outgoing
+    pub withdrawal_state: Option<RevenueWithdrawalState>,
+}
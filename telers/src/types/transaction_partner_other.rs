@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes a transaction with an unknown source or recipient.
+/// # Documentation
+/// <https://core.telegram.org/bots/api#transactionpartnerother>
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TransactionPartnerOther {}
@@ -2,13 +2,17 @@ use super::{
     BusinessConnection, BusinessMessagesDeleted, CallbackQuery, Chat, ChatBoostRemoved,
     ChatBoostSource, ChatBoostSourcePremium, ChatBoostUpdated, ChatJoinRequest, ChatMemberUpdated,
     ChosenInlineResult, InaccessibleMessage, InlineQuery, MaybeInaccessibleMessage, Message,
-    MessageReactionCountUpdated, MessageReactionUpdated, Poll, PollAnswer, PreCheckoutQuery,
-    ShippingQuery, User,
+    MessageReactionCountUpdated, MessageReactionUpdated, PaidMediaPurchased, Poll, PollAnswer,
+    PreCheckoutQuery, ShippingQuery, User,
 };
 
 use crate::{enums::UpdateType, extractors::FromEvent};
 
-use serde::{de::MapAccess, Deserialize, Deserializer};
+use serde::{
+    de::{DeserializeSeed, MapAccess},
+    ser::SerializeMap as _,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 use std::{
     fmt::{self, Formatter},
     str::FromStr as _,
@@ -18,7 +22,7 @@ use std::{
 /// At most **one** of the optional parameters can be present in any given update.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#update>
-#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Update {
     #[serde(rename = "update_id")]
     pub id: i64,
@@ -74,6 +78,8 @@ pub enum Kind {
     ChatBoost(ChatBoostUpdated),
     /// A boost was removed from a chat. The bot must be an administrator in the chat to receive these updates.
     RemovedChatBoost(ChatBoostRemoved),
+    /// A user purchased paid media with a non-empty payload sent by the bot in a non-channel chat.
+    PurchasedPaidMedia(PaidMediaPurchased),
 }
 
 impl Kind {
@@ -134,7 +140,8 @@ impl Kind {
             | Kind::PreCheckoutQuery(PreCheckoutQuery { from, .. })
             | Kind::MyChatMember(ChatMemberUpdated { from, .. })
             | Kind::ChatMember(ChatMemberUpdated { from, .. })
-            | Kind::ChatJoinRequest(ChatJoinRequest { from, .. }) => Some(from),
+            | Kind::ChatJoinRequest(ChatJoinRequest { from, .. })
+            | Kind::PurchasedPaidMedia(PaidMediaPurchased { from, .. }) => Some(from),
             Kind::BusinessConnection(BusinessConnection { user, .. })
             | Kind::ChatBoost(ChatBoostUpdated {
                 boost: ChatBoostSource::Premium(ChatBoostSourcePremium { user }),
@@ -289,6 +296,35 @@ impl From<Update> for Kind {
     }
 }
 
+/// Deserialize a key of [`Kind`] as [`UpdateType`], either from a borrowed or an owned string
+struct UpdateTypeKey;
+
+impl<'de> DeserializeSeed<'de> for UpdateTypeKey {
+    type Value = UpdateType;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for UpdateTypeKey {
+    type Value = UpdateType;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("an update type")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        UpdateType::from_str(value).map_err(|err| E::custom(format!("Unknown update type: {err}")))
+    }
+}
+
 impl<'de> Deserialize<'de> for Kind {
     #[allow(clippy::too_many_lines)]
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -308,31 +344,10 @@ impl<'de> Deserialize<'de> for Kind {
             where
                 A: MapAccess<'de>,
             {
-                let mut tmp = None;
-
-                // Try to deserialize a borrowed-str key, or else try deserializing an owned string key
-                let key = map.next_key::<&str>().or_else(|_| {
-                    map.next_key::<String>().map(|k| {
-                        tmp = k;
-                        tmp.as_deref()
-                    })
-                });
-
-                let update_type = match key {
-                    Ok(Some(key)) => match UpdateType::from_str(key) {
-                        Ok(update_type) => update_type,
-                        Err(err) => {
-                            return Err(serde::de::Error::custom(format!(
-                                "Unknown update type: {err}"
-                            )))
-                        }
-                    },
+                let update_type = match map.next_key_seed(UpdateTypeKey) {
+                    Ok(Some(update_type)) => update_type,
                     Ok(None) => return Err(serde::de::Error::custom("No update type key found")),
-                    Err(err) => {
-                        return Err(serde::de::Error::custom(format!(
-                            "No update type key with type &str or String found: {err}"
-                        )))
-                    }
+                    Err(err) => return Err(err),
                 };
 
                 let update_kind = match update_type {
@@ -394,6 +409,9 @@ impl<'de> Deserialize<'de> for Kind {
                     UpdateType::RemovedChatBoost => map
                         .next_value::<ChatBoostRemoved>()
                         .map(Kind::RemovedChatBoost),
+                    UpdateType::PurchasedPaidMedia => map
+                        .next_value::<PaidMediaPurchased>()
+                        .map(Kind::PurchasedPaidMedia),
                 };
 
                 match update_kind {
@@ -409,6 +427,43 @@ impl<'de> Deserialize<'de> for Kind {
     }
 }
 
+impl Serialize for Kind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let key: &'static str = UpdateType::from(self).into();
+
+        let mut map = serializer.serialize_map(Some(1))?;
+        match self {
+            Kind::Message(val) => map.serialize_entry(key, val)?,
+            Kind::EditedMessage(val) => map.serialize_entry(key, val)?,
+            Kind::ChannelPost(val) => map.serialize_entry(key, val)?,
+            Kind::EditedChannelPost(val) => map.serialize_entry(key, val)?,
+            Kind::BusinessConnection(val) => map.serialize_entry(key, val)?,
+            Kind::BusinessMessage(val) => map.serialize_entry(key, val)?,
+            Kind::EditedBusinessMessage(val) => map.serialize_entry(key, val)?,
+            Kind::DeletedBusinessMessages(val) => map.serialize_entry(key, val)?,
+            Kind::MessageReaction(val) => map.serialize_entry(key, val)?,
+            Kind::MessageReactionCount(val) => map.serialize_entry(key, val)?,
+            Kind::InlineQuery(val) => map.serialize_entry(key, val)?,
+            Kind::ChosenInlineResult(val) => map.serialize_entry(key, val)?,
+            Kind::CallbackQuery(val) => map.serialize_entry(key, val)?,
+            Kind::ShippingQuery(val) => map.serialize_entry(key, val)?,
+            Kind::PreCheckoutQuery(val) => map.serialize_entry(key, val)?,
+            Kind::Poll(val) => map.serialize_entry(key, val)?,
+            Kind::PollAnswer(val) => map.serialize_entry(key, val)?,
+            Kind::MyChatMember(val) => map.serialize_entry(key, val)?,
+            Kind::ChatMember(val) => map.serialize_entry(key, val)?,
+            Kind::ChatJoinRequest(val) => map.serialize_entry(key, val)?,
+            Kind::ChatBoost(val) => map.serialize_entry(key, val)?,
+            Kind::RemovedChatBoost(val) => map.serialize_entry(key, val)?,
+            Kind::PurchasedPaidMedia(val) => map.serialize_entry(key, val)?,
+        }
+        map.end()
+    }
+}
+
 impl Update {
     #[must_use]
     pub const fn text(&self) -> Option<&str> {
@@ -475,3 +530,365 @@ impl Update {
         self.kind().message()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_round_trip() {
+        let user = serde_json::json!({
+            "id": 1,
+            "is_bot": false,
+            "first_name": "test",
+            "username": "test",
+            "language_code": "en",
+        });
+        let private_chat = serde_json::json!({
+            "id": 1,
+            "type": "private",
+            "first_name": "test",
+            "username": "test",
+        });
+        let group_chat = serde_json::json!({
+            "id": -1,
+            "type": "group",
+            "title": "test",
+        });
+        let supergroup_chat = serde_json::json!({
+            "id": -1001,
+            "type": "supergroup",
+            "title": "test",
+            "is_forum": true,
+        });
+        let text_message = serde_json::json!({
+            "message_id": 1,
+            "from": user,
+            "date": 1_700_000_000,
+            "chat": private_chat,
+            "text": "/start test",
+            "entities": [
+                {
+                    "offset": 0,
+                    "length": 6,
+                    "type": "bot_command",
+                },
+            ],
+        });
+
+        let jsons = [
+            serde_json::json!({
+                "update_id": 1,
+                "message": text_message,
+            }),
+            serde_json::json!({
+                "update_id": 2,
+                "edited_message": {
+                    "message_id": 2,
+                    "from": user,
+                    "date": 1_700_000_000,
+                    "edit_date": 1_700_000_060,
+                    "chat": private_chat,
+                    "photo": [
+                        {
+                            "file_id": "test",
+                            "file_unique_id": "test",
+                            "width": 90,
+                            "height": 90,
+                            "file_size": 1024,
+                        },
+                    ],
+                    "caption": "test",
+                    "caption_entities": [
+                        {
+                            "offset": 0,
+                            "length": 4,
+                            "type": "text_link",
+                            "url": "https://example.com",
+                        },
+                    ],
+                },
+            }),
+            serde_json::json!({
+                "update_id": 3,
+                "message": {
+                    "message_id": 3,
+                    "message_thread_id": 2,
+                    "from": user,
+                    "date": 1_700_000_000,
+                    "chat": supergroup_chat,
+                    "is_topic_message": true,
+                    "reply_to_message": text_message,
+                    "text": "test",
+                },
+            }),
+            serde_json::json!({
+                "update_id": 4,
+                "message": {
+                    "message_id": 4,
+                    "from": user,
+                    "date": 1_700_000_000,
+                    "chat": supergroup_chat,
+                    "new_chat_members": [user],
+                },
+            }),
+            serde_json::json!({
+                "update_id": 5,
+                "channel_post": {
+                    "message_id": 5,
+                    "sender_chat": {
+                        "id": -1002,
+                        "type": "channel",
+                        "title": "test",
+                    },
+                    "date": 1_700_000_000,
+                    "chat": {
+                        "id": -1002,
+                        "type": "channel",
+                        "title": "test",
+                    },
+                    "poll": {
+                        "id": "test",
+                        "question": "test",
+                        "options": [
+                            {
+                                "text": "test",
+                                "voter_count": 1,
+                            },
+                            {
+                                "text": "test",
+                                "voter_count": 0,
+                            },
+                        ],
+                        "total_voter_count": 1,
+                        "is_closed": false,
+                        "is_anonymous": true,
+                        "type": "regular",
+                        "allows_multiple_answers": false,
+                    },
+                },
+            }),
+            serde_json::json!({
+                "update_id": 6,
+                "callback_query": {
+                    "id": "test",
+                    "from": user,
+                    "message": text_message,
+                    "chat_instance": "test",
+                    "data": "test",
+                },
+            }),
+            serde_json::json!({
+                "update_id": 7,
+                "callback_query": {
+                    "id": "test",
+                    "from": user,
+                    "message": {
+                        "message_id": 1,
+                        "date": 0,
+                        "chat": private_chat,
+                    },
+                    "chat_instance": "test",
+                    "data": "test",
+                },
+            }),
+            serde_json::json!({
+                "update_id": 8,
+                "inline_query": {
+                    "id": "test",
+                    "from": user,
+                    "query": "test",
+                    "offset": "",
+                    "chat_type": "sender",
+                },
+            }),
+            serde_json::json!({
+                "update_id": 9,
+                "my_chat_member": {
+                    "chat": supergroup_chat,
+                    "from": user,
+                    "date": 1_700_000_000,
+                    "old_chat_member": {
+                        "status": "left",
+                        "user": user,
+                    },
+                    "new_chat_member": {
+                        "status": "member",
+                        "user": user,
+                    },
+                },
+            }),
+            serde_json::json!({
+                "update_id": 10,
+                "poll_answer": {
+                    "poll_id": "test",
+                    "user": user,
+                    "option_ids": [0],
+                },
+            }),
+            serde_json::json!({
+                "update_id": 11,
+                "pre_checkout_query": {
+                    "id": "test",
+                    "from": user,
+                    "currency": "XTR",
+                    "total_amount": 100,
+                    "invoice_payload": "test",
+                },
+            }),
+            serde_json::json!({
+                "update_id": 12,
+                "message_reaction": {
+                    "chat": supergroup_chat,
+                    "message_id": 1,
+                    "user": user,
+                    "date": 1_700_000_000,
+                    "old_reaction": [],
+                    "new_reaction": [
+                        {
+                            "type": "emoji",
+                            "emoji": "👍",
+                        },
+                    ],
+                },
+            }),
+            serde_json::json!({
+                "update_id": 13,
+                "purchased_paid_media": {
+                    "from": user,
+                    "paid_media_payload": "test",
+                },
+            }),
+            // Service messages
+            serde_json::json!({
+                "update_id": 14,
+                "message": {
+                    "message_id": 14,
+                    "from": user,
+                    "date": 1_700_000_000,
+                    "chat": group_chat,
+                    "migrate_to_chat_id": -1001,
+                },
+            }),
+            serde_json::json!({
+                "update_id": 15,
+                "message": {
+                    "message_id": 15,
+                    "from": user,
+                    "date": 1_700_000_000,
+                    "chat": supergroup_chat,
+                    "migrate_from_chat_id": -1,
+                },
+            }),
+            serde_json::json!({
+                "update_id": 16,
+                "message": {
+                    "message_id": 16,
+                    "from": user,
+                    "date": 1_700_000_000,
+                    "chat": private_chat,
+                    "write_access_allowed": {
+                        "web_app_name": "test",
+                    },
+                },
+            }),
+            serde_json::json!({
+                "update_id": 17,
+                "message": {
+                    "message_id": 17,
+                    "from": user,
+                    "date": 1_700_000_000,
+                    "chat": private_chat,
+                    "connected_website": "example.com",
+                },
+            }),
+            serde_json::json!({
+                "update_id": 18,
+                "message": {
+                    "message_id": 18,
+                    "from": user,
+                    "date": 1_700_000_000,
+                    "chat": supergroup_chat,
+                    "proximity_alert_triggered": {
+                        "traveler": user,
+                        "watcher": user,
+                        "distance": 100,
+                    },
+                },
+            }),
+            serde_json::json!({
+                "update_id": 19,
+                "message": {
+                    "message_id": 19,
+                    "from": user,
+                    "date": 1_700_000_000,
+                    "chat": supergroup_chat,
+                    "boost_added": {
+                        "boost_count": 1,
+                    },
+                },
+            }),
+            serde_json::json!({
+                "update_id": 20,
+                "message": {
+                    "message_id": 20,
+                    "from": user,
+                    "date": 1_700_000_000,
+                    "chat": supergroup_chat,
+                    "left_chat_member": user,
+                },
+            }),
+            serde_json::json!({
+                "update_id": 21,
+                "message": {
+                    "message_id": 21,
+                    "from": user,
+                    "date": 1_700_000_000,
+                    "chat": private_chat,
+                    "web_app_data": {
+                        "data": "test",
+                        "button_text": "test",
+                    },
+                },
+            }),
+        ];
+
+        for json in jsons {
+            let update: Update = serde_json::from_value(json.clone()).unwrap();
+
+            assert_eq!(serde_json::to_value(&update).unwrap(), json);
+            assert_eq!(
+                serde_json::from_value::<Update>(serde_json::to_value(&update).unwrap()).unwrap(),
+                update
+            );
+        }
+    }
+
+    #[test]
+    fn deserialize_purchased_paid_media() {
+        let update: Update = serde_json::from_value(serde_json::json!({
+            "update_id": 1,
+            "purchased_paid_media": {
+                "from": {
+                    "id": 1,
+                    "is_bot": false,
+                    "first_name": "test",
+                },
+                "paid_media_payload": "test",
+            },
+        }))
+        .unwrap();
+
+        assert_eq!(UpdateType::from(&update), UpdateType::PurchasedPaidMedia);
+        assert_eq!(
+            UpdateType::from(update.kind()),
+            UpdateType::PurchasedPaidMedia
+        );
+        assert_eq!(update.from_id(), Some(1));
+
+        let Kind::PurchasedPaidMedia(purchased) = update.kind() else {
+            panic!("Unexpected update kind: {:?}", update.kind());
+        };
+        assert_eq!(&*purchased.paid_media_payload, "test");
+    }
+}
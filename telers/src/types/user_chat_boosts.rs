@@ -1,11 +1,11 @@
 use super::ChatBoost;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// This object represents a list of boosts added to a chat by a user.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#userchatboosts>
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
 pub struct UserChatBoosts {
     /// The list of boosts added to the chat by the user
     pub boosts: Box<[ChatBoost]>,
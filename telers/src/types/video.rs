@@ -1,11 +1,18 @@
 use super::PhotoSize;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[cfg(feature = "chrono")]
+use crate::utils::date_time;
+#[cfg(feature = "chrono")]
+use chrono::TimeDelta;
 
 /// This object represents a video file.
 /// # Documentation
 /// <https://core.telegram.org/bots/api#video>
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Video {
     /// Identifier for this file, which can be used to download or reuse the file
     pub file_id: Box<str>,
@@ -26,3 +33,12 @@ pub struct Video {
     /// File size in bytes. It can be bigger than 2^31 and some programming languages may have difficulty/silent defects in interpreting it. But it has at most 52 significant bits, so a signed 64-bit integer or double-precision float type are safe for storing this value.
     pub file_size: Option<i64>,
 }
+
+#[cfg(feature = "chrono")]
+impl Video {
+    /// Duration of the media as [`TimeDelta`]
+    #[must_use]
+    pub fn duration_delta(&self) -> TimeDelta {
+        date_time::from_seconds(self.duration)
+    }
+}
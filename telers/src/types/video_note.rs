@@ -1,11 +1,18 @@
 use super::PhotoSize;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[cfg(feature = "chrono")]
+use crate::utils::date_time;
+#[cfg(feature = "chrono")]
+use chrono::TimeDelta;
 
 /// This object represents a [`video message`](https://telegram.org/blog/video-messages-and-telescope) (available in Telegram apps as of [`v.4.0`](https://telegram.org/blog/video-messages-and-telescope)).
 /// # Documentation
 /// <https://core.telegram.org/bots/api#videonote>
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize)]
 pub struct VideoNote {
     /// Identifier for this file, which can be used to download or reuse the file
     pub file_id: Box<str>,
@@ -20,3 +27,12 @@ pub struct VideoNote {
     /// File size in bytes
     pub file_size: Option<i64>,
 }
+
+#[cfg(feature = "chrono")]
+impl VideoNote {
+    /// Duration of the media as [`TimeDelta`]
+    #[must_use]
+    pub fn duration_delta(&self) -> TimeDelta {
+        date_time::from_seconds(self.duration)
+    }
+}
@@ -1,2 +1,8 @@
+pub mod bot_commands;
+pub mod callback_data;
+#[cfg(feature = "chrono")]
+pub mod date_time;
+pub mod deep_linking;
+pub mod keyboard;
 pub mod text;
 pub mod token;
@@ -0,0 +1,464 @@
+//! Typed commands with arguments.
+//!
+//! Derive [`BotCommands`] for an enum, where each variant is a command and its fields are the arguments of the command:
+//!
+//! ```
+//! use telers::utils::bot_commands::BotCommands;
+//!
+//! #[derive(Debug, PartialEq, BotCommands)]
+//! enum Command {
+//!     #[command(description = "Start the bot")]
+//!     Start,
+//!     #[command(description = "Buy an item")]
+//!     Buy { item: String, count: Option<u32> },
+//!     #[command(rename = "say", description = "Repeat the text")]
+//!     Echo(#[command(rest)] String),
+//! }
+//!
+//! assert_eq!(
+//!     Command::parse_text("/buy \"red apple\" 2").unwrap(),
+//!     Command::Buy { item: "red apple".to_owned(), count: Some(2) },
+//! );
+//! assert_eq!(
+//!     Command::parse_text("/say hello,\nworld").unwrap(),
+//!     Command::Echo("hello,\nworld".to_owned()),
+//! );
+//! assert_eq!(Command::bot_commands().len(), 3);
+//! ```
+//!
+//! Arguments are separated by any whitespaces and parsed with [`FromStr`].
+//! An argument with whitespaces can be quoted with `"`, quotes and backslashes inside are escaped with `\`.
+//! Fields of `Option<T>` type are optional arguments, so they must go after required ones,
+//! and a field with `#[command(rest)]` attribute gets the rest of the text.
+//!
+//! To handle only messages with these commands, use [`BotCommands::filter`],
+//! and to get the parsed command in a handler, just add the type to the handler arguments.
+//! If the arguments are invalid, the extraction fails with [`ErrorKind`] that describes the problem and can be sent to the user.
+//!
+//! # Notes
+//! The extraction doesn't check the bot mention, for example, `/start@other_bot` is extracted as `/start`,
+//! because the username of the bot is unknown without a request to Telegram API.
+//! So the extractor must be paired with [`BotCommands::filter`], which skips commands addressed to other bots.
+//! If you parse commands by hand, use [`BotCommands::parse_text_with_username`] to check the mention.
+
+use crate::{
+    errors::ExtractionError,
+    filters::Command,
+    types::{BotCommand, Update},
+};
+
+use std::{borrow::Cow, fmt::Display, str::FromStr};
+
+pub use telers_macros::BotCommands;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ErrorKind {
+    #[error("Text isn't a command")]
+    NotCommand,
+    #[error("Unknown command `{command}`")]
+    UnknownCommand { command: String },
+    #[error("Command `{command}` requires argument `{argument}`")]
+    MissingArgument {
+        command: String,
+        argument: &'static str,
+    },
+    #[error("Invalid argument `{argument}` of command `{command}`: {message}")]
+    InvalidArgument {
+        command: String,
+        argument: &'static str,
+        message: String,
+    },
+    #[error("Command `{command}` got an unexpected argument `{value}`")]
+    TooManyArguments { command: String, value: String },
+    #[error("Command `{command}` has an unclosed quote in arguments")]
+    UnclosedQuote { command: String },
+    #[error("Command `{command}` is addressed to another bot `{mention}`")]
+    OtherBotMention { command: String, mention: String },
+}
+
+impl From<ErrorKind> for ExtractionError {
+    fn from(err: ErrorKind) -> Self {
+        Self::new(err.to_string())
+    }
+}
+
+/// Typed commands with arguments.
+/// Usually this trait is implemented by the [`BotCommands`](derive@BotCommands) derive macro
+pub trait BotCommands: Sized {
+    /// Prefix of the commands
+    const PREFIX: char;
+
+    /// Names of all commands without prefix
+    #[must_use]
+    fn names() -> &'static [&'static str];
+
+    /// Parse the command by its name without prefix and mention, and its raw arguments
+    /// # Errors
+    /// If the command is unknown or the arguments are invalid
+    fn parse(command: &str, args: &str) -> Result<Self, ErrorKind>;
+
+    /// Commands with descriptions to use in [`SetMyCommands`](crate::methods::SetMyCommands).
+    /// Commands without description aren't included
+    #[must_use]
+    fn bot_commands() -> Vec<BotCommand>;
+
+    /// Parse the command from text, for example, `/buy@bot_username apple 2`
+    /// # Notes
+    /// The mention isn't checked, use [`BotCommands::parse_text_with_username`] to skip commands addressed to other bots
+    /// # Errors
+    /// If the text isn't a command, the command is unknown or the arguments are invalid
+    fn parse_text(text: &str) -> Result<Self, ErrorKind> {
+        let (command, _mention, args) = split_text(text, Self::PREFIX)?;
+
+        Self::parse(command, args)
+    }
+
+    /// Parse the command from text like [`BotCommands::parse_text`] and check that the command mentions the bot,
+    /// if the command has a mention
+    /// # Arguments
+    /// * `username` - Username of the bot without `@`
+    /// # Errors
+    /// If the command is addressed to another bot or can't be parsed, see [`BotCommands::parse_text`]
+    fn parse_text_with_username(text: &str, username: &str) -> Result<Self, ErrorKind> {
+        let (command, mention, args) = split_text(text, Self::PREFIX)?;
+
+        match mention {
+            Some(mention) if !mention.eq_ignore_ascii_case(username) => {
+                Err(ErrorKind::OtherBotMention {
+                    command: command.to_owned(),
+                    mention: mention.to_owned(),
+                })
+            }
+            _ => Self::parse(command, args),
+        }
+    }
+
+    /// Parse the command from text or caption of the message of the update
+    /// # Notes
+    /// The mention isn't checked, see [`BotCommands::parse_text`]
+    /// # Errors
+    /// If the update doesn't contain a command or the command can't be parsed, see [`BotCommands::parse_text`]
+    fn from_update(update: &Update) -> Result<Self, ErrorKind> {
+        match update
+            .message()
+            .and_then(|message| message.text_or_caption())
+        {
+            Some(text) => Self::parse_text(text),
+            None => Err(ErrorKind::NotCommand),
+        }
+    }
+
+    /// Create a filter that passes messages with any of these commands
+    #[must_use]
+    fn filter() -> Command<'static> {
+        Command::many_with_prefix(Self::names().iter().copied(), Self::PREFIX)
+    }
+}
+
+/// Split the text into the command without prefix, its mention and raw arguments
+fn split_text(text: &str, prefix: char) -> Result<(&str, Option<&str>, &str), ErrorKind> {
+    let Some(text) = text.trim_start().strip_prefix(prefix) else {
+        return Err(ErrorKind::NotCommand);
+    };
+
+    let (full_command, args) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+
+    let (command, mention) = match full_command.split_once('@') {
+        Some((command, mention)) => (command, Some(mention)),
+        None => (full_command, None),
+    };
+
+    if command.is_empty() {
+        return Err(ErrorKind::NotCommand);
+    }
+
+    Ok((command, mention, args))
+}
+
+/// Parser of the command arguments.
+/// This type is used by the [`BotCommands`](derive@BotCommands) derive macro
+#[derive(Debug)]
+pub struct Args<'a> {
+    command: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Args<'a> {
+    #[must_use]
+    pub const fn new(command: &'a str, args: &'a str) -> Self {
+        Self {
+            command,
+            rest: args,
+        }
+    }
+
+    /// Get the next argument, quoted argument is unquoted and unescaped
+    fn next_token(&mut self) -> Result<Option<Cow<'a, str>>, ErrorKind> {
+        let rest = self.rest.trim_start();
+
+        let Some(quoted) = rest.strip_prefix('"') else {
+            if rest.is_empty() {
+                self.rest = rest;
+                return Ok(None);
+            }
+
+            let (token, rest) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+
+            self.rest = rest;
+            return Ok(Some(Cow::Borrowed(token)));
+        };
+
+        let mut token = String::new();
+        let mut chars = quoted.char_indices();
+
+        while let Some((index, char)) = chars.next() {
+            match char {
+                '"' => {
+                    self.rest = &quoted[index + 1..];
+                    return Ok(Some(Cow::Owned(token)));
+                }
+                '\\' => match chars.next() {
+                    Some((_, escaped @ ('"' | '\\'))) => token.push(escaped),
+                    Some((_, other)) => {
+                        token.push('\\');
+                        token.push(other);
+                    }
+                    None => token.push('\\'),
+                },
+                _ => token.push(char),
+            }
+        }
+
+        Err(ErrorKind::UnclosedQuote {
+            command: self.command.to_owned(),
+        })
+    }
+
+    fn parse_value<T>(&self, argument: &'static str, value: &str) -> Result<T, ErrorKind>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        value
+            .parse()
+            .map_err(|err: T::Err| ErrorKind::InvalidArgument {
+                command: self.command.to_owned(),
+                argument,
+                message: err.to_string(),
+            })
+    }
+
+    /// Parse the next required argument
+    /// # Errors
+    /// If the argument is missing or can't be parsed
+    pub fn next<T>(&mut self, argument: &'static str) -> Result<T, ErrorKind>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        match self.next_token()? {
+            Some(value) => self.parse_value(argument, &value),
+            None => Err(ErrorKind::MissingArgument {
+                command: self.command.to_owned(),
+                argument,
+            }),
+        }
+    }
+
+    /// Parse the next optional argument
+    /// # Errors
+    /// If the argument can't be parsed
+    pub fn next_option<T>(&mut self, argument: &'static str) -> Result<Option<T>, ErrorKind>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        match self.next_token()? {
+            Some(value) => self.parse_value(argument, &value).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Parse the rest of the text as a required argument
+    /// # Errors
+    /// If the rest of the text is empty or can't be parsed
+    pub fn rest<T>(&mut self, argument: &'static str) -> Result<T, ErrorKind>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        match self.rest_option(argument)? {
+            Some(value) => Ok(value),
+            None => Err(ErrorKind::MissingArgument {
+                command: self.command.to_owned(),
+                argument,
+            }),
+        }
+    }
+
+    /// Parse the rest of the text as an optional argument
+    /// # Errors
+    /// If the rest of the text can't be parsed
+    pub fn rest_option<T>(&mut self, argument: &'static str) -> Result<Option<T>, ErrorKind>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let rest = self.rest.trim();
+        self.rest = "";
+
+        if rest.is_empty() {
+            Ok(None)
+        } else {
+            self.parse_value(argument, rest).map(Some)
+        }
+    }
+
+    /// Check that all arguments are parsed
+    /// # Errors
+    /// If there are unexpected arguments
+    pub fn finish(mut self) -> Result<(), ErrorKind> {
+        match self.next_token()? {
+            Some(value) => Err(ErrorKind::TooManyArguments {
+                command: self.command.to_owned(),
+                value: value.into_owned(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, BotCommands)]
+    enum Commands {
+        #[command(description = "Start the bot")]
+        Start,
+        #[command(description = "Add a task")]
+        Add { title: String, priority: Option<u8> },
+        #[command(rename = "note")]
+        WriteNote(u32, #[command(rest)] Option<String>),
+    }
+
+    #[derive(Debug, PartialEq, BotCommands)]
+    #[command(prefix = "!")]
+    enum Admin {
+        BanUser(i64),
+    }
+
+    #[test]
+    fn test_parse_text() {
+        assert_eq!(Commands::parse_text("/start").unwrap(), Commands::Start);
+        assert_eq!(
+            Commands::parse_text("/start@bot_username").unwrap(),
+            Commands::Start
+        );
+        assert_eq!(
+            Commands::parse_text("/add  \"buy \\\"milk\\\"\"\n 3").unwrap(),
+            Commands::Add {
+                title: "buy \"milk\"".to_owned(),
+                priority: Some(3)
+            }
+        );
+        assert_eq!(
+            Commands::parse_text("/add milk").unwrap(),
+            Commands::Add {
+                title: "milk".to_owned(),
+                priority: None
+            }
+        );
+        assert_eq!(
+            Commands::parse_text("/note 1 first line\nsecond line ").unwrap(),
+            Commands::WriteNote(1, Some("first line\nsecond line".to_owned()))
+        );
+        assert_eq!(
+            Commands::parse_text("/note 1").unwrap(),
+            Commands::WriteNote(1, None)
+        );
+        assert_eq!(
+            Admin::parse_text("!ban_user 42").unwrap(),
+            Admin::BanUser(42)
+        );
+    }
+
+    #[test]
+    fn test_parse_text_with_username() {
+        assert_eq!(
+            Commands::parse_text_with_username("/start", "bot_username").unwrap(),
+            Commands::Start
+        );
+        assert_eq!(
+            Commands::parse_text_with_username("/start@Bot_Username", "bot_username").unwrap(),
+            Commands::Start
+        );
+        assert_eq!(
+            Commands::parse_text_with_username("/start@other_bot", "bot_username"),
+            Err(ErrorKind::OtherBotMention {
+                command: "start".to_owned(),
+                mention: "other_bot".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_text_errors() {
+        assert_eq!(Commands::parse_text("start"), Err(ErrorKind::NotCommand));
+        assert_eq!(Commands::parse_text("/"), Err(ErrorKind::NotCommand));
+        assert_eq!(
+            Commands::parse_text("/stop"),
+            Err(ErrorKind::UnknownCommand {
+                command: "stop".to_owned()
+            })
+        );
+        assert_eq!(
+            Commands::parse_text("/add"),
+            Err(ErrorKind::MissingArgument {
+                command: "add".to_owned(),
+                argument: "title"
+            })
+        );
+        assert!(matches!(
+            Commands::parse_text("/add milk high"),
+            Err(ErrorKind::InvalidArgument {
+                argument: "priority",
+                ..
+            })
+        ));
+        assert_eq!(
+            Commands::parse_text("/add milk 1 2"),
+            Err(ErrorKind::TooManyArguments {
+                command: "add".to_owned(),
+                value: "2".to_owned()
+            })
+        );
+        assert_eq!(
+            Commands::parse_text("/add \"milk"),
+            Err(ErrorKind::UnclosedQuote {
+                command: "add".to_owned()
+            })
+        );
+        assert_eq!(
+            Commands::parse_text("/start now"),
+            Err(ErrorKind::TooManyArguments {
+                command: "start".to_owned(),
+                value: "now".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_bot_commands() {
+        assert_eq!(Commands::names(), ["start", "add", "note"]);
+        assert_eq!(
+            Commands::bot_commands(),
+            [
+                BotCommand::new("start", "Start the bot"),
+                BotCommand::new("add", "Add a task"),
+            ]
+        );
+        assert_eq!(Admin::names(), ["ban_user"]);
+        assert!(Admin::bot_commands().is_empty());
+    }
+}
@@ -0,0 +1,365 @@
+//! Typed callback data of inline keyboard buttons.
+//!
+//! Instead of formatting and parsing strings by hand, derive [`CallbackData`] for a struct
+//! and use [`CallbackData::pack`] to create callback data of a button and [`CallbackData::unpack`] to parse it back.
+//! Packed data is the prefix and the values of the fields joined by the separator, for example, `buy:42:1`.
+//!
+//! ```
+//! use telers::utils::callback_data::CallbackData;
+//!
+//! #[derive(Debug, PartialEq, CallbackData)]
+//! #[callback_data(prefix = "buy", sep = ":")]
+//! struct Buy {
+//!     item_id: i64,
+//!     count: Option<u32>,
+//! }
+//!
+//! let data = Buy { item_id: 42, count: Some(1) }.pack().unwrap();
+//! assert_eq!(data, "buy:42:1");
+//! assert_eq!(Buy::unpack(&data).unwrap(), Buy { item_id: 42, count: Some(1) });
+//! ```
+//!
+//! Values of the fields are converted with [`ToString`] and [`FromStr`], `None` values are packed as empty strings.
+//! To handle only callback queries with the data, use [`CallbackData::filter`],
+//! and to get the parsed data in a handler, just add the type to the handler arguments.
+
+use crate::{
+    errors::ExtractionError,
+    filters::CallbackData as CallbackDataFilter,
+    types::{CallbackQuery, Update, UpdateKind},
+    utils::keyboard::MAX_CALLBACK_DATA_LEN,
+};
+
+use std::{fmt::Display, str::FromStr};
+
+pub use telers_macros::CallbackData;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ErrorKind {
+    #[error("Value of field `{field}` contains the separator `{separator}`")]
+    SeparatorInValue {
+        field: &'static str,
+        separator: char,
+    },
+    #[error(
+        "Value of optional field `{field}` is empty, so it can't be distinguished from `None`"
+    )]
+    EmptyOptionalValue { field: &'static str },
+    #[error("Callback data is {len} bytes long, but the maximum is {max}")]
+    TooLong { len: usize, max: usize },
+    #[error("Callback data doesn't start with the prefix `{prefix}`")]
+    InvalidPrefix { prefix: &'static str },
+    #[error("Callback data contains {count} values, but {expected} expected")]
+    InvalidValuesCount { count: usize, expected: usize },
+    #[error("Failed to parse value of field `{field}`: {message}")]
+    InvalidValue {
+        field: &'static str,
+        message: String,
+    },
+    #[error("Update doesn't contain callback data")]
+    Missing,
+}
+
+impl From<ErrorKind> for ExtractionError {
+    fn from(err: ErrorKind) -> Self {
+        Self::new(err.to_string())
+    }
+}
+
+/// Typed callback data of inline keyboard buttons.
+/// Usually this trait is implemented by the [`CallbackData`](derive@CallbackData) derive macro
+pub trait CallbackData: Sized {
+    /// Prefix of the packed data to distinguish different types of callback data
+    const PREFIX: &'static str;
+    /// Separator between the prefix and the values of the fields
+    const SEPARATOR: char;
+
+    /// Pack the data into a string to use as callback data of a button
+    /// # Errors
+    /// If a value contains the separator, a value of an optional field is empty or the packed data is longer than 64 bytes
+    fn pack(&self) -> Result<String, ErrorKind>;
+
+    /// Parse the data from a string packed by [`CallbackData::pack`]
+    /// # Errors
+    /// If the prefix doesn't match, the number of values is wrong or a value can't be parsed
+    fn unpack(data: &str) -> Result<Self, ErrorKind>;
+
+    /// Parse the data from callback query of the update
+    /// # Errors
+    /// If the update isn't a callback query with data or the data can't be parsed, see [`CallbackData::unpack`]
+    fn from_update(update: &Update) -> Result<Self, ErrorKind> {
+        match update.kind() {
+            UpdateKind::CallbackQuery(CallbackQuery {
+                data: Some(data), ..
+            }) => Self::unpack(data),
+            _ => Err(ErrorKind::Missing),
+        }
+    }
+
+    /// Create a filter that passes callback queries with data of this type
+    #[must_use]
+    fn filter() -> CallbackDataFilter<Self> {
+        CallbackDataFilter::new()
+    }
+}
+
+/// Packer of the values of the fields.
+/// This type is used by the [`CallbackData`](derive@CallbackData) derive macro
+#[derive(Debug)]
+pub struct Packer {
+    data: String,
+    separator: char,
+}
+
+impl Packer {
+    #[must_use]
+    pub fn new(prefix: &str, separator: char) -> Self {
+        Self {
+            data: prefix.to_owned(),
+            separator,
+        }
+    }
+
+    /// Add a value of the field
+    /// # Errors
+    /// If the value contains the separator
+    pub fn push(&mut self, field: &'static str, value: &impl Display) -> Result<(), ErrorKind> {
+        let value = value.to_string();
+
+        if value.contains(self.separator) {
+            return Err(ErrorKind::SeparatorInValue {
+                field,
+                separator: self.separator,
+            });
+        }
+
+        self.data.push(self.separator);
+        self.data.push_str(&value);
+
+        Ok(())
+    }
+
+    /// Add an optional value of the field, `None` is packed as an empty string
+    /// # Errors
+    /// - If the value contains the separator
+    /// - If the value is empty, because it would be unpacked as `None`
+    pub fn push_option(
+        &mut self,
+        field: &'static str,
+        value: Option<&impl Display>,
+    ) -> Result<(), ErrorKind> {
+        match value {
+            Some(value) => {
+                let value = value.to_string();
+
+                if value.is_empty() {
+                    return Err(ErrorKind::EmptyOptionalValue { field });
+                }
+
+                self.push(field, &value)
+            }
+            None => self.push(field, &""),
+        }
+    }
+
+    /// Get the packed data
+    /// # Errors
+    /// If the packed data is longer than 64 bytes
+    pub fn finish(self) -> Result<String, ErrorKind> {
+        if self.data.len() > MAX_CALLBACK_DATA_LEN {
+            return Err(ErrorKind::TooLong {
+                len: self.data.len(),
+                max: MAX_CALLBACK_DATA_LEN,
+            });
+        }
+
+        Ok(self.data)
+    }
+}
+
+/// Unpacker of the values of the fields.
+/// This type is used by the [`CallbackData`](derive@CallbackData) derive macro
+#[derive(Debug)]
+pub struct Unpacker<'a> {
+    values: Vec<&'a str>,
+    index: usize,
+}
+
+impl<'a> Unpacker<'a> {
+    /// # Errors
+    /// If the data doesn't start with the prefix or the number of values isn't equal to `count`
+    pub fn new(
+        data: &'a str,
+        prefix: &'static str,
+        separator: char,
+        count: usize,
+    ) -> Result<Self, ErrorKind> {
+        let mut parts = data.split(separator);
+
+        if parts.next() != Some(prefix) {
+            return Err(ErrorKind::InvalidPrefix { prefix });
+        }
+
+        let values = parts.collect::<Vec<_>>();
+
+        if values.len() != count {
+            return Err(ErrorKind::InvalidValuesCount {
+                count: values.len(),
+                expected: count,
+            });
+        }
+
+        Ok(Self { values, index: 0 })
+    }
+
+    fn next_value(&mut self) -> &'a str {
+        let value = self.values[self.index];
+        self.index += 1;
+        value
+    }
+
+    /// Parse the next value of the field
+    /// # Errors
+    /// If the value can't be parsed
+    pub fn next<T>(&mut self, field: &'static str) -> Result<T, ErrorKind>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.next_value()
+            .parse()
+            .map_err(|err: T::Err| ErrorKind::InvalidValue {
+                field,
+                message: err.to_string(),
+            })
+    }
+
+    /// Parse the next optional value of the field, an empty string is parsed as `None`
+    /// # Errors
+    /// If the value can't be parsed
+    pub fn next_option<T>(&mut self, field: &'static str) -> Result<Option<T>, ErrorKind>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        if self.values[self.index].is_empty() {
+            self.index += 1;
+            return Ok(None);
+        }
+
+        self.next(field).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, CallbackData)]
+    #[callback_data(prefix = "item", sep = ":")]
+    struct Item {
+        id: i64,
+        name: String,
+        count: Option<u32>,
+    }
+
+    #[derive(Debug, PartialEq, CallbackData)]
+    #[callback_data(prefix = "search")]
+    struct Search {
+        query: Option<String>,
+    }
+
+    #[derive(Debug, PartialEq, CallbackData)]
+    #[callback_data(prefix = "back")]
+    struct Back;
+
+    #[test]
+    fn test_pack_and_unpack() {
+        let item = Item {
+            id: -5,
+            name: "test".to_owned(),
+            count: None,
+        };
+        let data = item.pack().unwrap();
+
+        assert_eq!(data, "item:-5:test:");
+        assert_eq!(Item::unpack(&data).unwrap(), item);
+
+        let item = Item {
+            count: Some(3),
+            ..item
+        };
+        let data = item.pack().unwrap();
+
+        assert_eq!(data, "item:-5:test:3");
+        assert_eq!(Item::unpack(&data).unwrap(), item);
+
+        let search = Search { query: None };
+        let data = search.pack().unwrap();
+
+        assert_eq!(data, "search:");
+        assert_eq!(Search::unpack(&data).unwrap(), search);
+
+        assert_eq!(Back.pack().unwrap(), "back");
+        assert_eq!(Back::unpack("back").unwrap(), Back);
+    }
+
+    #[test]
+    fn test_pack_errors() {
+        assert_eq!(
+            Item {
+                id: 1,
+                name: "a:b".to_owned(),
+                count: None,
+            }
+            .pack(),
+            Err(ErrorKind::SeparatorInValue {
+                field: "name",
+                separator: ':'
+            })
+        );
+        assert_eq!(
+            Item {
+                id: 1,
+                name: "a".repeat(60),
+                count: None,
+            }
+            .pack(),
+            Err(ErrorKind::TooLong { len: 68, max: 64 })
+        );
+        // Empty value would be unpacked as `None`
+        assert_eq!(
+            Search {
+                query: Some(String::new()),
+            }
+            .pack(),
+            Err(ErrorKind::EmptyOptionalValue { field: "query" })
+        );
+    }
+
+    #[test]
+    fn test_unpack_errors() {
+        assert_eq!(
+            Item::unpack("items:1:test:"),
+            Err(ErrorKind::InvalidPrefix { prefix: "item" })
+        );
+        assert_eq!(
+            Item::unpack("item:1:test"),
+            Err(ErrorKind::InvalidValuesCount {
+                count: 2,
+                expected: 3
+            })
+        );
+        assert!(matches!(
+            Item::unpack("item:one:test:"),
+            Err(ErrorKind::InvalidValue { field: "id", .. })
+        ));
+        assert_eq!(
+            Back::unpack("back:"),
+            Err(ErrorKind::InvalidValuesCount {
+                count: 1,
+                expected: 0
+            })
+        );
+    }
+}
@@ -0,0 +1,82 @@
+//! Conversions between raw Telegram dates and durations and [`chrono`] types.
+//!
+//! Telegram represents dates as Unix time in seconds and durations as a number of seconds.
+//! Some fields use `0` as a sentinel value, for example `until_date` of a restricted or banned chat member
+//! means that restrictions will never be lifted, so conversions for these fields return [`None`] for the sentinel.
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+/// Convert Unix time in seconds to [`DateTime<Utc>`]
+/// # Returns
+/// [`None`] if the timestamp is out of range
+#[must_use]
+pub fn from_timestamp(timestamp: i64) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp(timestamp, 0)
+}
+
+/// Convert Unix time in seconds to [`DateTime<Utc>`], where `0` means that the date isn't set,
+/// for example, `until_date` of a chat member who is banned or restricted forever
+/// # Returns
+/// [`None`] if the timestamp is `0` or out of range
+#[must_use]
+pub fn from_timestamp_or_forever(timestamp: i64) -> Option<DateTime<Utc>> {
+    if timestamp == 0 {
+        None
+    } else {
+        from_timestamp(timestamp)
+    }
+}
+
+/// Convert [`DateTime<Utc>`] to Unix time in seconds
+#[must_use]
+pub fn to_timestamp(date: DateTime<Utc>) -> i64 {
+    date.timestamp()
+}
+
+/// Convert a number of seconds to [`TimeDelta`].
+/// If the number of seconds is out of range, [`TimeDelta::MAX`] or [`TimeDelta::MIN`] is returned
+#[must_use]
+pub fn from_seconds(seconds: i64) -> TimeDelta {
+    TimeDelta::try_seconds(seconds).unwrap_or(if seconds < 0 {
+        TimeDelta::MIN
+    } else {
+        TimeDelta::MAX
+    })
+}
+
+/// Convert [`TimeDelta`] to a number of whole seconds
+#[must_use]
+pub fn to_seconds(delta: TimeDelta) -> i64 {
+    delta.num_seconds()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamp() {
+        let date = from_timestamp(1_700_000_000).unwrap();
+
+        assert_eq!(to_timestamp(date), 1_700_000_000);
+        assert_eq!(from_timestamp(0), Some(DateTime::UNIX_EPOCH));
+        assert_eq!(from_timestamp(i64::MAX), None);
+    }
+
+    #[test]
+    fn test_timestamp_or_forever() {
+        assert_eq!(from_timestamp_or_forever(0), None);
+        assert_eq!(
+            from_timestamp_or_forever(1_700_000_000),
+            from_timestamp(1_700_000_000)
+        );
+    }
+
+    #[test]
+    fn test_seconds() {
+        assert_eq!(from_seconds(60), TimeDelta::minutes(1));
+        assert_eq!(to_seconds(TimeDelta::minutes(1)), 60);
+        assert_eq!(from_seconds(i64::MAX), TimeDelta::MAX);
+        assert_eq!(from_seconds(i64::MIN), TimeDelta::MIN);
+    }
+}
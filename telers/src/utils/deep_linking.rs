@@ -0,0 +1,207 @@
+//! Deep linking helpers.
+//!
+//! Telegram bots can be started with a payload by links like `https://t.me/<bot_username>?start=<payload>`,
+//! see [Deep linking](https://core.telegram.org/bots/features#deep-linking).
+//! The bot receives the payload in the `/start <payload>` message, which can be handled with [`CommandStart`] filter.
+//!
+//! ```
+//! use telers::utils::deep_linking::{create_start_link, decode_payload};
+//!
+//! assert_eq!(
+//!     create_start_link("bot_username", "ref_42", false).unwrap(),
+//!     "https://t.me/bot_username?start=ref_42",
+//! );
+//!
+//! let link = create_start_link("bot_username", "Hello, world!", true).unwrap();
+//! assert_eq!(link, "https://t.me/bot_username?start=SGVsbG8sIHdvcmxkIQ");
+//! assert_eq!(decode_payload("SGVsbG8sIHdvcmxkIQ").unwrap(), "Hello, world!");
+//! ```
+//!
+//! The payload can contain only `A-Z`, `a-z`, `0-9`, `_` and `-` chars and be up to 64 chars long.
+//! To pass an arbitrary payload, encode it with base64url by `encode` flag and decode it with [`decode_payload`]
+//! or [`CommandStart::deep_link_encoded`] filter. Keep in mind that the encoded payload is about a third longer.
+//!
+//! [`CommandStart`]: crate::filters::CommandStart
+//! [`CommandStart::deep_link_encoded`]: crate::filters::CommandStart::deep_link_encoded
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+
+/// Maximum length of the deep link payload
+pub const MAX_PAYLOAD_LEN: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ErrorKind {
+    #[error("Payload is {len} chars long, but the maximum is {max}")]
+    TooLong { len: usize, max: usize },
+    #[error("Payload contains invalid char `{char}`, only `A-Z`, `a-z`, `0-9`, `_` and `-` are allowed. Use `encode` flag to pass an arbitrary payload")]
+    InvalidChar { char: char },
+    #[error("Failed to decode payload: {message}")]
+    Decode { message: String },
+}
+
+/// Type of the deep link
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum LinkType {
+    /// Link to start a private chat with the bot
+    Start,
+    /// Link to add the bot to a group
+    StartGroup,
+    /// Link to open the main Mini App of the bot
+    StartApp,
+}
+
+impl LinkType {
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            LinkType::Start => "start",
+            LinkType::StartGroup => "startgroup",
+            LinkType::StartApp => "startapp",
+        }
+    }
+}
+
+/// Encode an arbitrary payload with base64url without padding
+#[must_use]
+pub fn encode_payload(payload: impl AsRef<[u8]>) -> String {
+    URL_SAFE_NO_PAD.encode(payload)
+}
+
+/// Decode a payload encoded by [`encode_payload`]
+/// # Errors
+/// If the payload isn't valid base64url or the decoded payload isn't valid UTF-8
+pub fn decode_payload(payload: &str) -> Result<String, ErrorKind> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(payload.trim_end_matches('='))
+        .map_err(|err| ErrorKind::Decode {
+            message: err.to_string(),
+        })?;
+
+    String::from_utf8(bytes).map_err(|err| ErrorKind::Decode {
+        message: err.to_string(),
+    })
+}
+
+/// Check the payload by Telegram rules: up to 64 chars, only `A-Z`, `a-z`, `0-9`, `_` and `-`
+/// # Errors
+/// If the payload is too long or contains an invalid char
+pub fn validate_payload(payload: &str) -> Result<(), ErrorKind> {
+    if let Some(char) = payload
+        .chars()
+        .find(|char| !(char.is_ascii_alphanumeric() || *char == '_' || *char == '-'))
+    {
+        return Err(ErrorKind::InvalidChar { char });
+    }
+
+    if payload.len() > MAX_PAYLOAD_LEN {
+        return Err(ErrorKind::TooLong {
+            len: payload.len(),
+            max: MAX_PAYLOAD_LEN,
+        });
+    }
+
+    Ok(())
+}
+
+/// Create a deep link to the bot
+/// # Arguments
+/// * `username` - Username of the bot without `@`
+/// * `link_type` - Type of the link
+/// * `payload` - Payload of the link
+/// * `encode` - Encode the payload with base64url, so it can contain any chars
+/// # Errors
+/// If the payload (encoded, if `encode` is `true`) is too long or contains an invalid char
+pub fn create_deep_link(
+    username: &str,
+    link_type: LinkType,
+    payload: &str,
+    encode: bool,
+) -> Result<String, ErrorKind> {
+    let payload = if encode {
+        encode_payload(payload)
+    } else {
+        payload.to_owned()
+    };
+
+    validate_payload(&payload)?;
+
+    Ok(format!(
+        "https://t.me/{username}?{link_type}={payload}",
+        username = username.trim_start_matches('@'),
+        link_type = link_type.as_str(),
+    ))
+}
+
+/// Create a link to start a private chat with the bot, for example, `https://t.me/<bot_username>?start=<payload>`
+/// # Errors
+/// See [`create_deep_link`]
+pub fn create_start_link(username: &str, payload: &str, encode: bool) -> Result<String, ErrorKind> {
+    create_deep_link(username, LinkType::Start, payload, encode)
+}
+
+/// Create a link to add the bot to a group, for example, `https://t.me/<bot_username>?startgroup=<payload>`
+/// # Errors
+/// See [`create_deep_link`]
+pub fn create_startgroup_link(
+    username: &str,
+    payload: &str,
+    encode: bool,
+) -> Result<String, ErrorKind> {
+    create_deep_link(username, LinkType::StartGroup, payload, encode)
+}
+
+/// Create a link to open the main Mini App of the bot, for example, `https://t.me/<bot_username>?startapp=<payload>`
+/// # Errors
+/// See [`create_deep_link`]
+pub fn create_startapp_link(
+    username: &str,
+    payload: &str,
+    encode: bool,
+) -> Result<String, ErrorKind> {
+    create_deep_link(username, LinkType::StartApp, payload, encode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_deep_link() {
+        assert_eq!(
+            create_start_link("@bot", "abc-_09", false).unwrap(),
+            "https://t.me/bot?start=abc-_09"
+        );
+        assert_eq!(
+            create_startgroup_link("bot", "", false).unwrap(),
+            "https://t.me/bot?startgroup="
+        );
+        assert_eq!(
+            create_startapp_link("bot", "a b", true).unwrap(),
+            "https://t.me/bot?startapp=YSBi"
+        );
+        assert_eq!(
+            create_start_link("bot", "a b", false),
+            Err(ErrorKind::InvalidChar { char: ' ' })
+        );
+        assert_eq!(
+            create_start_link("bot", &"a".repeat(65), false),
+            Err(ErrorKind::TooLong { len: 65, max: 64 })
+        );
+        assert!(create_start_link("bot", &"a".repeat(48), true).is_ok());
+        assert_eq!(
+            create_start_link("bot", &"a".repeat(49), true),
+            Err(ErrorKind::TooLong { len: 66, max: 64 })
+        );
+    }
+
+    #[test]
+    fn test_encode_and_decode_payload() {
+        let payload = "Привет, мир! ?&=/+";
+        let encoded = encode_payload(payload);
+
+        assert!(validate_payload(&encoded).is_ok());
+        assert_eq!(decode_payload(&encoded).unwrap(), payload);
+        assert!(decode_payload("a").is_err());
+        assert!(decode_payload("_w").is_err());
+    }
+}
@@ -0,0 +1,388 @@
+//! Builders of inline and reply keyboards.
+//!
+//! Instead of nesting rows of buttons by hand, you can add buttons to [`InlineKeyboardBuilder`] or [`ReplyKeyboardBuilder`]
+//! and let the builder arrange them with [`Builder::adjust`]:
+//!
+//! ```
+//! use telers::{types::InlineKeyboardButton, utils::keyboard::InlineKeyboardBuilder};
+//!
+//! let markup = InlineKeyboardBuilder::new()
+//!     .buttons((1..=5).map(|i| InlineKeyboardButton::new(i.to_string()).callback_data(i.to_string())))
+//!     .adjust(&[2, 3])
+//!     .build()
+//!     .unwrap();
+//!
+//! assert_eq!(markup.inline_keyboard.len(), 2);
+//! ```
+//!
+//! Keyboards are checked against Telegram limits when they are built, see [`Builder::validate`].
+
+use crate::types::{
+    InlineKeyboardButton, InlineKeyboardMarkup, KeyboardButton, ReplyKeyboardMarkup, ReplyMarkup,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ErrorKind {
+    #[error("Row {row} contains {count} buttons, but the maximum is {max}")]
+    TooManyButtonsInRow {
+        row: usize,
+        count: usize,
+        max: usize,
+    },
+    #[error("Keyboard contains {count} buttons, but the maximum is {max}")]
+    TooManyButtons { count: usize, max: usize },
+    #[error("Callback data of button `{text}` is {len} bytes long, but it must be 1-{max} bytes")]
+    InvalidCallbackData {
+        text: String,
+        len: usize,
+        max: usize,
+    },
+}
+
+/// Button that can be placed in a keyboard built by [`Builder`]
+pub trait Button: Sized {
+    type Markup;
+
+    /// Maximum number of buttons in a row
+    const MAX_IN_ROW: usize;
+    /// Maximum number of buttons in the keyboard
+    const MAX_TOTAL: usize;
+
+    /// Check the button itself against Telegram limits
+    /// # Errors
+    /// If the button is invalid
+    fn validate(&self) -> Result<(), ErrorKind>;
+
+    /// Create a markup from the rows of buttons
+    #[must_use]
+    fn markup(rows: Vec<Vec<Self>>) -> Self::Markup;
+}
+
+/// Maximum length of callback data in bytes
+pub const MAX_CALLBACK_DATA_LEN: usize = 64;
+
+impl Button for InlineKeyboardButton {
+    type Markup = InlineKeyboardMarkup;
+
+    const MAX_IN_ROW: usize = 8;
+    const MAX_TOTAL: usize = 100;
+
+    fn validate(&self) -> Result<(), ErrorKind> {
+        match self.callback_data {
+            Some(ref data) if data.is_empty() || data.len() > MAX_CALLBACK_DATA_LEN => {
+                Err(ErrorKind::InvalidCallbackData {
+                    text: self.text.clone(),
+                    len: data.len(),
+                    max: MAX_CALLBACK_DATA_LEN,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn markup(rows: Vec<Vec<Self>>) -> Self::Markup {
+        InlineKeyboardMarkup::new(rows)
+    }
+}
+
+impl Button for KeyboardButton {
+    type Markup = ReplyKeyboardMarkup;
+
+    const MAX_IN_ROW: usize = 12;
+    const MAX_TOTAL: usize = 300;
+
+    fn validate(&self) -> Result<(), ErrorKind> {
+        Ok(())
+    }
+
+    fn markup(rows: Vec<Vec<Self>>) -> Self::Markup {
+        ReplyKeyboardMarkup::new(rows)
+    }
+}
+
+/// Builder of a keyboard with rows of buttons.
+/// Use [`InlineKeyboardBuilder`] or [`ReplyKeyboardBuilder`] aliases instead of this type directly
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Builder<B> {
+    rows: Vec<Vec<B>>,
+}
+
+pub type InlineKeyboardBuilder = Builder<InlineKeyboardButton>;
+pub type ReplyKeyboardBuilder = Builder<KeyboardButton>;
+
+impl<B> Default for Builder<B> {
+    fn default() -> Self {
+        Self { rows: vec![] }
+    }
+}
+
+impl<B> Builder<B>
+where
+    B: Button,
+{
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a button to the last row
+    #[must_use]
+    pub fn button(self, button: B) -> Self {
+        self.buttons(Some(button))
+    }
+
+    /// Add buttons to the last row
+    #[must_use]
+    pub fn buttons(mut self, buttons: impl IntoIterator<Item = B>) -> Self {
+        match self.rows.last_mut() {
+            Some(row) => row.extend(buttons),
+            None => self.rows.push(buttons.into_iter().collect()),
+        }
+        self
+    }
+
+    /// Add a new row with the buttons
+    #[must_use]
+    pub fn row(mut self, buttons: impl IntoIterator<Item = B>) -> Self {
+        self.rows.push(buttons.into_iter().collect());
+        self
+    }
+
+    /// Add rows of another builder after the rows of this builder
+    #[must_use]
+    pub fn attach(mut self, other: Self) -> Self {
+        self.rows.extend(other.rows);
+        self
+    }
+
+    /// Rearrange all buttons into rows with the given sizes.
+    /// The last size is repeated for the rest of the buttons, so `&[2, 3, 1]` places
+    /// two buttons in the first row, three buttons in the second and one button in each row after that.
+    /// Zero sizes are ignored and without sizes the layout is unchanged
+    #[must_use]
+    pub fn adjust(self, sizes: &[usize]) -> Self {
+        let sizes = sizes
+            .iter()
+            .copied()
+            .filter(|size| *size > 0)
+            .collect::<Vec<_>>();
+        let Some(&last_size) = sizes.last() else {
+            return self;
+        };
+
+        let mut buttons = self.rows.into_iter().flatten().peekable();
+        let mut rows = vec![];
+        let mut sizes = sizes.into_iter().chain(std::iter::repeat(last_size));
+
+        while buttons.peek().is_some() {
+            let size = sizes.next().unwrap_or(last_size);
+
+            rows.push(buttons.by_ref().take(size).collect());
+        }
+
+        Self { rows }
+    }
+
+    /// Get rows of the keyboard
+    #[must_use]
+    pub fn rows(&self) -> &[Vec<B>] {
+        &self.rows
+    }
+
+    /// Check the keyboard against Telegram limits: the maximum number of buttons in a row and in total,
+    /// and the limits of each button, for example, 1-64 bytes of callback data of inline buttons
+    /// # Errors
+    /// If any limit is exceeded
+    pub fn validate(&self) -> Result<(), ErrorKind> {
+        let mut count = 0;
+
+        for (index, row) in self.rows.iter().enumerate() {
+            if row.len() > B::MAX_IN_ROW {
+                return Err(ErrorKind::TooManyButtonsInRow {
+                    row: index,
+                    count: row.len(),
+                    max: B::MAX_IN_ROW,
+                });
+            }
+
+            for button in row {
+                button.validate()?;
+            }
+
+            count += row.len();
+        }
+
+        if count > B::MAX_TOTAL {
+            return Err(ErrorKind::TooManyButtons {
+                count,
+                max: B::MAX_TOTAL,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Validate the keyboard and create a markup from it.
+    /// Empty rows are skipped.
+    /// # Errors
+    /// If any limit is exceeded, see [`Builder::validate`]
+    pub fn build(self) -> Result<B::Markup, ErrorKind> {
+        self.validate()?;
+
+        Ok(B::markup(
+            self.rows
+                .into_iter()
+                .filter(|row| !row.is_empty())
+                .collect(),
+        ))
+    }
+}
+
+impl TryFrom<InlineKeyboardBuilder> for InlineKeyboardMarkup {
+    type Error = ErrorKind;
+
+    fn try_from(builder: InlineKeyboardBuilder) -> Result<Self, Self::Error> {
+        builder.build()
+    }
+}
+
+impl TryFrom<ReplyKeyboardBuilder> for ReplyKeyboardMarkup {
+    type Error = ErrorKind;
+
+    fn try_from(builder: ReplyKeyboardBuilder) -> Result<Self, Self::Error> {
+        builder.build()
+    }
+}
+
+impl TryFrom<InlineKeyboardBuilder> for ReplyMarkup {
+    type Error = ErrorKind;
+
+    fn try_from(builder: InlineKeyboardBuilder) -> Result<Self, Self::Error> {
+        builder.build().map(Into::into)
+    }
+}
+
+impl TryFrom<ReplyKeyboardBuilder> for ReplyMarkup {
+    type Error = ErrorKind;
+
+    fn try_from(builder: ReplyKeyboardBuilder) -> Result<Self, Self::Error> {
+        builder.build().map(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inline_buttons(count: usize) -> impl Iterator<Item = InlineKeyboardButton> {
+        (0..count).map(|i| InlineKeyboardButton::new(i.to_string()).callback_data(i.to_string()))
+    }
+
+    fn row_sizes<B: Button>(builder: &Builder<B>) -> Vec<usize> {
+        builder.rows().iter().map(Vec::len).collect()
+    }
+
+    #[test]
+    fn test_button_and_row() {
+        let builder = InlineKeyboardBuilder::new()
+            .buttons(inline_buttons(2))
+            .row(inline_buttons(3))
+            .button(InlineKeyboardButton::new("test").callback_data("test"));
+
+        assert_eq!(row_sizes(&builder), [2, 4]);
+    }
+
+    #[test]
+    fn test_adjust() {
+        let builder = InlineKeyboardBuilder::new().buttons(inline_buttons(10));
+
+        assert_eq!(
+            row_sizes(&builder.clone().adjust(&[2, 3, 1])),
+            [2, 3, 1, 1, 1, 1, 1]
+        );
+        assert_eq!(row_sizes(&builder.clone().adjust(&[3])), [3, 3, 3, 1]);
+        assert_eq!(row_sizes(&builder.clone().adjust(&[0, 4])), [4, 4, 2]);
+        assert_eq!(row_sizes(&builder.clone().adjust(&[])), [10]);
+        assert_eq!(
+            row_sizes(&InlineKeyboardBuilder::new().adjust(&[2])),
+            [0; 0]
+        );
+    }
+
+    #[test]
+    fn test_attach() {
+        let builder = ReplyKeyboardBuilder::new()
+            .button(KeyboardButton::new("test"))
+            .attach(
+                ReplyKeyboardBuilder::new()
+                    .row([KeyboardButton::new("test"), KeyboardButton::new("test")]),
+            );
+
+        assert_eq!(row_sizes(&builder), [1, 2]);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(
+            InlineKeyboardBuilder::new()
+                .buttons(inline_buttons(9))
+                .validate(),
+            Err(ErrorKind::TooManyButtonsInRow {
+                row: 0,
+                count: 9,
+                max: 8
+            })
+        );
+        assert_eq!(
+            InlineKeyboardBuilder::new()
+                .buttons(inline_buttons(101))
+                .adjust(&[8])
+                .validate(),
+            Err(ErrorKind::TooManyButtons {
+                count: 101,
+                max: 100
+            })
+        );
+        assert_eq!(
+            InlineKeyboardBuilder::new()
+                .button(InlineKeyboardButton::new("test").callback_data("a".repeat(65)))
+                .validate(),
+            Err(ErrorKind::InvalidCallbackData {
+                text: "test".to_owned(),
+                len: 65,
+                max: 64
+            })
+        );
+        assert!(ReplyKeyboardBuilder::new()
+            .buttons((0..12).map(|i| KeyboardButton::new(i.to_string())))
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_build() {
+        let markup = InlineKeyboardBuilder::new()
+            .buttons(inline_buttons(3))
+            .adjust(&[2])
+            .row([])
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            markup,
+            InlineKeyboardMarkup::new([
+                inline_buttons(2).collect::<Vec<_>>(),
+                inline_buttons(3).skip(2).collect(),
+            ])
+        );
+
+        let markup =
+            ReplyMarkup::try_from(ReplyKeyboardBuilder::new().button(KeyboardButton::new("test")))
+                .unwrap();
+
+        assert_eq!(
+            markup,
+            ReplyMarkup::ReplyKeyboard(ReplyKeyboardMarkup::new([[KeyboardButton::new("test")]]))
+        );
+    }
+}
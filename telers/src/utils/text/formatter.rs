@@ -1,4 +1,9 @@
-use crate::types::{MessageEntity, MessageEntityKind};
+use crate::types::{
+    CustomEmojiMessageEntity, MessageEntity, MessageEntityKind, PreMessageEntity,
+    TextLinkMessageEntity, TextMentionMessageEntity, User,
+};
+
+use std::ops::Range;
 
 #[derive(Debug, thiserror::Error)]
 pub enum ErrorKind {
@@ -56,6 +61,24 @@ pub trait Formatter {
     where
         T: AsRef<str>;
 
+    /// Format the text as a blockquote, which is collapsed by default.
+    /// By default, the text is formatted as a regular blockquote with [`Formatter::blockquote`]
+    #[must_use]
+    fn expandable_blockquote<T>(&self, text: T) -> String
+    where
+        T: AsRef<str>,
+    {
+        self.blockquote(text)
+    }
+
+    /// Separator, which is rendered before the blockquote right after another blockquote,
+    /// so the adjacent blockquotes aren't merged into one.
+    /// By default, the separator is empty
+    #[must_use]
+    fn blockquote_separator(&self) -> &str {
+        ""
+    }
+
     #[must_use]
     fn text_link<T, U>(&self, text: T, url: U) -> String
     where
@@ -89,6 +112,44 @@ pub trait Formatter {
     where
         T: AsRef<str>;
 
+    /// Quote the URL of the text link or custom emoji.
+    /// By default, the URL is quoted as a text with [`Formatter::quote`]
+    #[must_use]
+    fn quote_url<U>(&self, url: U) -> String
+    where
+        U: AsRef<str>,
+    {
+        self.quote(url)
+    }
+
+    /// Render the text with entities, for example, text and entities of a received message.
+    /// Text outside of entities is quoted, entities can be nested.
+    /// # Notes
+    /// Offsets and lengths of the entities are in UTF-16 code units, like in entities received from Telegram.
+    /// Entities out of the text bounds and entities crossing other entities are skipped.
+    ///
+    /// Unlike [`Formatter::apply_entity`], prefixes like `@` of mentions aren't added, because they are already in the text.
+    #[must_use]
+    fn render_entities<T>(&self, text: T, entities: &[MessageEntity]) -> String
+    where
+        T: AsRef<str>,
+    {
+        let text = text.as_ref();
+
+        let mut ranges = entities
+            .iter()
+            .filter_map(|entity| entity.byte_range(text).map(|range| (range, entity)))
+            .collect::<Vec<_>>();
+        // Outer entities go before inner ones with the same start
+        ranges.sort_by(|(left, _), (right, _)| {
+            left.start
+                .cmp(&right.start)
+                .then_with(|| right.end.cmp(&left.end))
+        });
+
+        render_range(self, text, &ranges, 0..text.len())
+    }
+
     /// Apply the [`MessageEntity`] to the given text with offset and length.
     /// # Notes
     /// Differences between [`Formatter::apply_entity`] and [`Formatter::apply_entity_kind`]:
@@ -134,6 +195,104 @@ pub trait Formatter {
     }
 }
 
+/// Render the range of the text with entities sorted by start, entities should be inside the range
+fn render_range<F: Formatter + ?Sized>(
+    formatter: &F,
+    text: &str,
+    entities: &[(Range<usize>, &MessageEntity)],
+    range: Range<usize>,
+) -> String {
+    let mut result = String::new();
+    let mut position = range.start;
+    let mut index = 0;
+    // Previous rendered entity of the range
+    let mut previous: Option<&MessageEntity> = None;
+
+    while let Some((entity_range, entity)) = entities.get(index) {
+        // Index of the first entity after the current one and its nested entities
+        let next_index = index
+            + 1
+            + entities[index + 1..]
+                .iter()
+                .take_while(|(nested_range, _)| nested_range.start < entity_range.end)
+                .count();
+
+        // Entity crosses the previous entity or the range
+        if entity_range.start < position || entity_range.end > range.end {
+            index += 1;
+            continue;
+        }
+
+        let gap = &text[position..entity_range.start];
+        result.push_str(&formatter.quote(gap));
+
+        if entity.kind == MessageEntityKind::Blockquote
+            && previous.map_or(false, |previous| {
+                matches!(
+                    previous.kind,
+                    MessageEntityKind::Blockquote | MessageEntityKind::ExpandableBlockquote
+                )
+            })
+            && gap.chars().all(|char| char == '\n')
+        {
+            result.push_str(formatter.blockquote_separator());
+        }
+
+        let nested = render_range(
+            formatter,
+            text,
+            &entities[index + 1..next_index],
+            entity_range.clone(),
+        );
+        result.push_str(&render_entity(formatter, nested, entity));
+
+        position = entity_range.end;
+        index = next_index;
+        previous = Some(entity);
+    }
+
+    result.push_str(&formatter.quote(&text[position..range.end]));
+    result
+}
+
+/// Wrap the rendered text of the entity with the formatting of its kind
+fn render_entity<F: Formatter + ?Sized>(
+    formatter: &F,
+    text: String,
+    entity: &MessageEntity,
+) -> String {
+    match &entity.kind {
+        MessageEntityKind::Mention
+        | MessageEntityKind::Hashtag
+        | MessageEntityKind::Cashtag
+        | MessageEntityKind::BotCommand
+        | MessageEntityKind::Url
+        | MessageEntityKind::Email
+        | MessageEntityKind::PhoneNumber => text,
+        MessageEntityKind::Bold => formatter.bold(text),
+        MessageEntityKind::Italic => formatter.italic(text),
+        MessageEntityKind::Underline => formatter.underline(text),
+        MessageEntityKind::Strikethrough => formatter.strikethrough(text),
+        MessageEntityKind::Spoiler => formatter.spoiler(text),
+        MessageEntityKind::Blockquote => formatter.blockquote(text),
+        MessageEntityKind::ExpandableBlockquote => formatter.expandable_blockquote(text),
+        MessageEntityKind::Code => formatter.code(text),
+        MessageEntityKind::Pre(PreMessageEntity { language }) => match language {
+            Some(language) => formatter.pre_language(text, language),
+            None => formatter.pre(text),
+        },
+        MessageEntityKind::TextLink(TextLinkMessageEntity { url }) => {
+            formatter.text_link(text, formatter.quote_url(url))
+        }
+        MessageEntityKind::TextMention(TextMentionMessageEntity {
+            user: User { id: user_id, .. },
+        }) => formatter.text_mention(text, *user_id),
+        MessageEntityKind::CustomEmoji(CustomEmojiMessageEntity { custom_emoji_id }) => {
+            formatter.custom_emoji(text, formatter.quote_url(custom_emoji_id))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,6 +128,16 @@ impl TextFormatter for Formatter {
         format!("<blockquote>{text}</blockquote>", text = text.as_ref())
     }
 
+    fn expandable_blockquote<T>(&self, text: T) -> String
+    where
+        T: AsRef<str>,
+    {
+        format!(
+            "<blockquote expandable>{text}</blockquote>",
+            text = text.as_ref()
+        )
+    }
+
     fn text_link<T, U>(&self, text: T, url: U) -> String
     where
         T: AsRef<str>,
@@ -199,6 +209,13 @@ impl TextFormatter for Formatter {
             .replace('>', "&gt;")
     }
 
+    fn quote_url<U>(&self, url: U) -> String
+    where
+        U: AsRef<str>,
+    {
+        self.quote(url).replace('"', "&quot;")
+    }
+
     fn apply_entity<T>(&self, text: T, entity: &MessageEntity) -> Result<String, FormatterErrorKind>
     where
         T: AsRef<str>,
@@ -235,7 +252,7 @@ impl TextFormatter for Formatter {
             MessageEntityKind::Strikethrough => self.strikethrough(editable_text),
             MessageEntityKind::Spoiler => self.spoiler(editable_text),
             MessageEntityKind::Blockquote => self.blockquote(editable_text),
-            MessageEntityKind::ExpandableBlockquote => self.blockquote(editable_text),
+            MessageEntityKind::ExpandableBlockquote => self.expandable_blockquote(editable_text),
             MessageEntityKind::Code => self.code(editable_text),
             MessageEntityKind::Pre(PreMessageEntity { language }) => match language {
                 Some(language) => self.pre_language(editable_text, language),
@@ -282,6 +299,10 @@ pub fn blockquote(text: impl AsRef<str>) -> String {
     FORMATTER.blockquote(text)
 }
 
+pub fn expandable_blockquote(text: impl AsRef<str>) -> String {
+    FORMATTER.expandable_blockquote(text)
+}
+
 pub fn text_link(text: impl AsRef<str>, url: impl AsRef<str>) -> String {
     FORMATTER.text_link(text, url)
 }
@@ -310,10 +331,16 @@ pub fn quote(text: impl AsRef<str>) -> String {
     FORMATTER.quote(text)
 }
 
+pub fn render_entities(text: impl AsRef<str>, entities: &[MessageEntity]) -> String {
+    FORMATTER.render_entities(text, entities)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use crate::utils::text::Builder;
+
     #[test]
     fn test_bold() {
         let formatter = Formatter::default();
@@ -353,6 +380,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_expandable_blockquote() {
+        let formatter = Formatter::default();
+        assert_eq!(
+            formatter.expandable_blockquote("text"),
+            "<blockquote expandable>text</blockquote>"
+        );
+    }
+
     #[test]
     fn test_text_link() {
         let formatter = Formatter::default();
@@ -408,4 +444,47 @@ mod tests {
         assert_eq!(formatter.quote("<text>"), "&lt;text&gt;");
         assert_eq!(formatter.quote("&text"), "&amp;text");
     }
+
+    #[test]
+    fn test_render_entities() {
+        let formatter = Formatter::default();
+        let text = "👋 Hello, world! <tag> & x < y @user";
+        let entities = [
+            MessageEntity::new_bold(3, 5),
+            MessageEntity::new_italic(3, 5),
+            MessageEntity::new_text_link(10, 5, "https://example.com"),
+            MessageEntity::new_code(25, 5),
+            MessageEntity::new_mention(31, 5),
+        ];
+
+        let builder = Builder::new(formatter)
+            .text("👋 ")
+            .bold(italic("Hello"))
+            .text(", ")
+            .text_link("world", "https://example.com")
+            .quote("! <tag> & ")
+            .code(quote("x < y"))
+            .text(" ")
+            .mention("user");
+
+        assert_eq!(
+            formatter.render_entities(text, &entities),
+            builder.get_text()
+        );
+        assert_eq!(formatter.render_entities(text, &[]), quote(text));
+
+        // Entities crossing other entities or out of the text bounds are skipped
+        let entities = [
+            MessageEntity::new_bold(3, 5),
+            MessageEntity::new_italic(5, 5),
+            MessageEntity::new_underline(10, 40),
+            MessageEntity::new_spoiler(0, 1),
+            MessageEntity::new_text_link(10, 5, "https://example.com/?a=\"&b"),
+        ];
+
+        assert_eq!(
+            formatter.render_entities(text, &entities),
+            "👋 <b>Hello</b>, <a href=\"https://example.com/?a=&quot;&amp;b\">world</a>! &lt;tag&gt; &amp; x &lt; y @user"
+        );
+    }
 }
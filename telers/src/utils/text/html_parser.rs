@@ -0,0 +1,508 @@
+//! Parser of HTML-style formatted text into plain text and [`MessageEntity`] list.
+//!
+//! Text can be sent with the entities instead of `parse_mode`, so formatting errors are found locally
+//! with their positions instead of "can't parse entities" errors of Telegram.
+//! Supported tags and HTML entities are the same as described in the
+//! [HTML style](https://core.telegram.org/bots/api#html-style) documentation.
+//!
+//! ```
+//! use telers::{types::MessageEntity, utils::text::parse_html};
+//!
+//! let (text, entities) = parse_html("<b>Hello</b>, <a href=\"https://example.com\">world</a> &amp; co").unwrap();
+//!
+//! assert_eq!(text, "Hello, world & co");
+//! assert_eq!(
+//!     entities,
+//!     [
+//!         MessageEntity::new_bold(0, 5),
+//!         MessageEntity::new_text_link(7, 5, "https://example.com"),
+//!     ],
+//! );
+//!
+//! let err = parse_html("<b>Hello</i>").unwrap_err();
+//! assert_eq!(err.position, 8);
+//! ```
+
+use super::parser::{Error, ErrorKind, Output};
+
+use crate::types::{
+    CustomEmojiMessageEntity, MessageEntity, MessageEntityKind, PreMessageEntity,
+    TextLinkMessageEntity,
+};
+
+/// Opened tag, which is waiting for its end tag
+struct Tag {
+    name: Box<str>,
+    /// `None` if the tag doesn't produce entity itself, for example, `code` inside `pre`
+    kind: Option<MessageEntityKind>,
+    /// Offset of the tag content in the plain text in UTF-16 code units
+    start: usize,
+    /// Position of the tag in the formatted text
+    position: usize,
+}
+
+type Attributes = Vec<(Box<str>, String)>;
+
+/// Parse HTML-style formatted text into plain text and its entities
+/// # Errors
+/// If the text isn't valid HTML-style formatted text, the error contains byte offset of the problem in the text
+pub fn parse(text: impl AsRef<str>) -> Result<(String, Vec<MessageEntity>), Error> {
+    let text = text.as_ref();
+
+    let mut output = Output::default();
+    let mut tags: Vec<Tag> = vec![];
+    let mut position = 0;
+
+    while let Some(index) = text[position..].find(&['<', '>', '&'][..]) {
+        output.push_str(&text[position..position + index]);
+        position += index;
+
+        let rest = &text[position..];
+
+        match rest.as_bytes()[0] {
+            b'&' => {
+                let (char, len) = parse_html_entity(rest, position)?;
+
+                output.push_char(char);
+                position += len;
+            }
+            b'<' if rest[1..]
+                .starts_with(|char: char| char.is_ascii_alphabetic() || char == '/') =>
+            {
+                let Some(len) = find_tag_end(rest) else {
+                    return Err(Error::new(position, ErrorKind::InvalidTag));
+                };
+
+                match rest[1..len].strip_prefix('/') {
+                    Some(name) => end_tag(name, position, &mut tags, &mut output)?,
+                    None => start_tag(&rest[1..len], position, &mut tags, &output)?,
+                }
+
+                position += len + 1;
+            }
+            byte => {
+                return Err(Error::new(
+                    position,
+                    ErrorKind::UnescapedChar {
+                        char: char::from(byte),
+                    },
+                ))
+            }
+        }
+    }
+
+    output.push_str(&text[position..]);
+
+    if let Some(tag) = tags.pop() {
+        return Err(Error::new(
+            tag.position,
+            ErrorKind::Unclosed { entity: tag.name },
+        ));
+    }
+
+    Ok(output.finish())
+}
+
+/// Find index of `>` which ends the tag, skipping `>` in quoted attribute values
+fn find_tag_end(text: &str) -> Option<usize> {
+    let mut quote = None;
+
+    for (index, char) in text.char_indices() {
+        match (quote, char) {
+            (None, '"' | '\'') => quote = Some(char),
+            (None, '>') => return Some(index),
+            (Some(quote_char), _) if quote_char == char => quote = None,
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn start_tag(
+    tag: &str,
+    position: usize,
+    tags: &mut Vec<Tag>,
+    output: &Output,
+) -> Result<(), Error> {
+    let (name, attributes) = parse_start_tag(tag, position)?;
+
+    if let Some(parent) = tags.last_mut() {
+        match parent.kind {
+            // `code` inside `pre` specifies language of the pre-formatted block
+            Some(MessageEntityKind::Pre(_)) if &*name == "code" => {
+                let language = attribute(&attributes, "class")
+                    .and_then(|class| class.strip_prefix("language-"))
+                    .map(ToOwned::to_owned);
+
+                parent.kind = Some(MessageEntityKind::Pre(PreMessageEntity { language }));
+
+                tags.push(Tag {
+                    name,
+                    kind: None,
+                    start: output.offset(),
+                    position,
+                });
+
+                return Ok(());
+            }
+            Some(MessageEntityKind::Pre(_) | MessageEntityKind::Code) | None => {
+                return Err(Error::new(
+                    position,
+                    ErrorKind::NestedEntity {
+                        entity: parent.name.clone(),
+                    },
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    let kind = match &*name {
+        "b" | "strong" => MessageEntityKind::Bold,
+        "i" | "em" => MessageEntityKind::Italic,
+        "u" | "ins" => MessageEntityKind::Underline,
+        "s" | "strike" | "del" => MessageEntityKind::Strikethrough,
+        "tg-spoiler" => MessageEntityKind::Spoiler,
+        "span" => {
+            if attribute(&attributes, "class") != Some("tg-spoiler") {
+                return Err(Error::new(
+                    position,
+                    ErrorKind::MissingAttribute {
+                        tag: name,
+                        attribute: "class=\"tg-spoiler\"",
+                    },
+                ));
+            }
+
+            MessageEntityKind::Spoiler
+        }
+        "a" => {
+            let Some(url) = attribute(&attributes, "href") else {
+                return Err(Error::new(
+                    position,
+                    ErrorKind::MissingAttribute {
+                        tag: name,
+                        attribute: "href",
+                    },
+                ));
+            };
+
+            MessageEntityKind::TextLink(TextLinkMessageEntity::new(url))
+        }
+        "tg-emoji" => {
+            let Some(custom_emoji_id) = attribute(&attributes, "emoji-id") else {
+                return Err(Error::new(
+                    position,
+                    ErrorKind::MissingAttribute {
+                        tag: name,
+                        attribute: "emoji-id",
+                    },
+                ));
+            };
+
+            MessageEntityKind::CustomEmoji(CustomEmojiMessageEntity::new(custom_emoji_id))
+        }
+        "code" => MessageEntityKind::Code,
+        "pre" => MessageEntityKind::Pre(PreMessageEntity::new()),
+        "blockquote" => {
+            if attribute(&attributes, "expandable").is_some() {
+                MessageEntityKind::ExpandableBlockquote
+            } else {
+                MessageEntityKind::Blockquote
+            }
+        }
+        _ => {
+            return Err(Error::new(
+                position,
+                ErrorKind::UnsupportedTag { tag: name },
+            ))
+        }
+    };
+
+    tags.push(Tag {
+        name,
+        kind: Some(kind),
+        start: output.offset(),
+        position,
+    });
+
+    Ok(())
+}
+
+fn end_tag(
+    name: &str,
+    position: usize,
+    tags: &mut Vec<Tag>,
+    output: &mut Output,
+) -> Result<(), Error> {
+    let name = name.trim().to_ascii_lowercase();
+
+    let Some(index) = tags.iter().rposition(|tag| *tag.name == name) else {
+        return Err(Error::new(
+            position,
+            ErrorKind::UnexpectedEndTag { tag: name.into() },
+        ));
+    };
+
+    let tag = tags
+        .pop()
+        .expect("Tags can't be empty, because the tag is found");
+
+    if index != tags.len() {
+        return Err(Error::new(
+            tag.position,
+            ErrorKind::Unclosed { entity: tag.name },
+        ));
+    }
+
+    match tag.kind {
+        Some(kind) => output.add_entity(tag.start, output.offset(), kind, tag.position),
+        None => Ok(()),
+    }
+}
+
+/// Parse name and attributes of the start tag without `<` and `>`
+fn parse_start_tag(tag: &str, position: usize) -> Result<(Box<str>, Attributes), Error> {
+    let name_len = tag
+        .find(|char: char| !(char.is_ascii_alphanumeric() || char == '-'))
+        .unwrap_or(tag.len());
+    let name = tag[..name_len].to_ascii_lowercase().into_boxed_str();
+
+    let mut attributes = vec![];
+    let mut rest = &tag[name_len..];
+
+    loop {
+        let trimmed = rest.trim_start();
+
+        if trimmed.is_empty() {
+            break;
+        }
+        // Attributes must be separated by whitespace
+        if trimmed.len() == rest.len() {
+            return Err(Error::new(position, ErrorKind::InvalidTag));
+        }
+
+        let attribute_len = trimmed
+            .find(|char: char| !(char.is_ascii_alphanumeric() || char == '-' || char == '_'))
+            .unwrap_or(trimmed.len());
+
+        if attribute_len == 0 {
+            return Err(Error::new(position, ErrorKind::InvalidTag));
+        }
+
+        let attribute = trimmed[..attribute_len]
+            .to_ascii_lowercase()
+            .into_boxed_str();
+        rest = &trimmed[attribute_len..];
+
+        let Some(value) = rest.trim_start().strip_prefix('=') else {
+            attributes.push((attribute, String::new()));
+            continue;
+        };
+        let value = value.trim_start();
+
+        let (raw_value, value_len) = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let Some(end) = value[1..].find(quote) else {
+                    return Err(Error::new(position, ErrorKind::InvalidTag));
+                };
+
+                (&value[1..=end], end + 2)
+            }
+            Some(_) => {
+                let end = value.find(char::is_whitespace).unwrap_or(value.len());
+
+                (&value[..end], end)
+            }
+            None => return Err(Error::new(position, ErrorKind::InvalidTag)),
+        };
+
+        attributes.push((attribute, decode_html_entities(raw_value, position)?));
+        rest = &value[value_len..];
+    }
+
+    Ok((name, attributes))
+}
+
+fn attribute<'a>(attributes: &'a [(Box<str>, String)], name: &str) -> Option<&'a str> {
+    attributes
+        .iter()
+        .find(|(attribute, _)| &**attribute == name)
+        .map(|(_, value)| value.as_str())
+}
+
+/// Parse HTML entity at the start of the text
+/// # Returns
+/// Char of the entity and length of the entity in the text
+fn parse_html_entity(text: &str, position: usize) -> Result<(char, usize), Error> {
+    let Some(end) = text.find(';').filter(|end| {
+        *end > 1
+            && text[1..*end]
+                .chars()
+                .all(|char| char.is_ascii_alphanumeric() || char == '#')
+    }) else {
+        return Err(Error::new(position, ErrorKind::UnescapedChar { char: '&' }));
+    };
+
+    let char = match &text[1..end] {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        name => name
+            .strip_prefix('#')
+            .and_then(|code| match code.strip_prefix(&['x', 'X'][..]) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                None => code.parse().ok(),
+            })
+            .filter(|code| *code != 0)
+            .and_then(char::from_u32),
+    };
+
+    match char {
+        Some(char) => Ok((char, end + 1)),
+        None => Err(Error::new(
+            position,
+            ErrorKind::UnsupportedHtmlEntity {
+                entity: text[..=end].into(),
+            },
+        )),
+    }
+}
+
+/// Replace HTML entities in the text, for example, in attribute values
+fn decode_html_entities(text: &str, position: usize) -> Result<String, Error> {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(index) = rest.find('&') {
+        decoded.push_str(&rest[..index]);
+        rest = &rest[index..];
+
+        let (char, len) = parse_html_entity(rest, position)?;
+
+        decoded.push(char);
+        rest = &rest[len..];
+    }
+
+    decoded.push_str(rest);
+
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::text::html_formatter;
+
+    #[test]
+    fn test_parse() {
+        let (text, entities) = parse(
+            "👋 <b>Hello <i>world</i></b> &lt;&amp;&gt; &#128075;\
+             <a href='https://example.com/?a=1&amp;b=2'>link</a>",
+        )
+        .unwrap();
+
+        assert_eq!(text, "👋 Hello world <&> 👋link");
+        assert_eq!(
+            entities,
+            [
+                MessageEntity::new_bold(3, 11),
+                MessageEntity::new_italic(9, 5),
+                MessageEntity::new_text_link(21, 4, "https://example.com/?a=1&b=2"),
+            ]
+        );
+        assert_eq!(
+            parse(html_formatter::render_entities(&text, &entities)).unwrap(),
+            (text, entities)
+        );
+
+        let (text, entities) = parse(
+            "<s>a</s><u>b</u><tg-spoiler>c</tg-spoiler><span class=\"tg-spoiler\">d</span>\
+             <tg-emoji emoji-id=\"1\">👍</tg-emoji><code>e</code>\
+             <pre><code class=\"language-rust\">f</code></pre><pre>g</pre>\
+             <blockquote>h</blockquote><blockquote expandable>i</blockquote><B>j</B><b></b>",
+        )
+        .unwrap();
+
+        assert_eq!(text, "abcd👍efghij");
+        assert_eq!(
+            entities,
+            [
+                MessageEntity::new_strikethrough(0, 1),
+                MessageEntity::new_underline(1, 1),
+                MessageEntity::new_spoiler(2, 1),
+                MessageEntity::new_spoiler(3, 1),
+                MessageEntity::new_custom_emoji(4, 2, "1"),
+                MessageEntity::new_code(6, 1),
+                MessageEntity::new_pre_language(7, 1, "rust"),
+                MessageEntity::new_pre(8, 1),
+                MessageEntity::new_blockquote(9, 1),
+                MessageEntity::new_expandable_blockquote(10, 1),
+                MessageEntity::new_bold(11, 1),
+            ]
+        );
+
+        assert_eq!(
+            parse("<b><i>x</i></b>").unwrap().1,
+            [
+                MessageEntity::new_bold(0, 1),
+                MessageEntity::new_italic(0, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases = [
+            (
+                "<b>Hello</i>",
+                8,
+                ErrorKind::UnexpectedEndTag { tag: "i".into() },
+            ),
+            (
+                "<b><i>x</b></i>",
+                3,
+                ErrorKind::Unclosed { entity: "i".into() },
+            ),
+            ("<b>x", 0, ErrorKind::Unclosed { entity: "b".into() }),
+            ("a < b", 2, ErrorKind::UnescapedChar { char: '<' }),
+            ("a > b", 2, ErrorKind::UnescapedChar { char: '>' }),
+            ("a & b", 2, ErrorKind::UnescapedChar { char: '&' }),
+            (
+                "a &nbsp;",
+                2,
+                ErrorKind::UnsupportedHtmlEntity {
+                    entity: "&nbsp;".into(),
+                },
+            ),
+            ("<p>x</p>", 0, ErrorKind::UnsupportedTag { tag: "p".into() }),
+            (
+                "<a>x</a>",
+                0,
+                ErrorKind::MissingAttribute {
+                    tag: "a".into(),
+                    attribute: "href",
+                },
+            ),
+            (
+                "<code><b>x</b></code>",
+                6,
+                ErrorKind::NestedEntity {
+                    entity: "code".into(),
+                },
+            ),
+            ("x<b y", 1, ErrorKind::InvalidTag),
+        ];
+
+        for (text, position, kind) in cases {
+            assert_eq!(
+                parse(text).unwrap_err(),
+                Error::new(position, kind),
+                "{text}"
+            );
+        }
+    }
+}
@@ -8,11 +8,12 @@ use crate::types::{
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-const QUOTE_PATTERN: &str = r"([_*\[\]()~`>#+\-=|{}.!\\])";
+/// `\r` is escaped too, because unescaped `\r` chars are removed by Telegram
+const QUOTE_PATTERN: &str = r"([_*\[\]()~`>#+\-=|{}.!\\\r])";
 
-/// This is a legacy mode, retained for backward compatibility. To use this mode, pass `Markdown` in the `parse_mode` field.
+/// To use this mode, pass `MarkdownV2` in the `parse_mode` field
 /// # Documentation
-/// <https://core.telegram.org/bots/api#markdown-style>
+/// <https://core.telegram.org/bots/api#markdownv2-style>
 #[derive(Debug, Clone)]
 pub struct Formatter {
     regex: Regex,
@@ -71,7 +72,7 @@ impl TextFormatter for Formatter {
     where
         T: AsRef<str>,
     {
-        format!("|{text}|", text = text.as_ref())
+        format!("||{text}||", text = text.as_ref())
     }
 
     fn blockquote<T>(&self, text: T) -> String
@@ -85,6 +86,18 @@ impl TextFormatter for Formatter {
             .join("\n")
     }
 
+    fn expandable_blockquote<T>(&self, text: T) -> String
+    where
+        T: AsRef<str>,
+    {
+        format!("**{blockquote}||", blockquote = self.blockquote(text))
+    }
+
+    /// An empty bold entity, which separates the adjacent blockquotes
+    fn blockquote_separator(&self) -> &str {
+        "**"
+    }
+
     fn text_link<T, U>(&self, text: T, url: U) -> String
     where
         T: AsRef<str>,
@@ -105,9 +118,10 @@ impl TextFormatter for Formatter {
         T: AsRef<str>,
         E: AsRef<str>,
     {
-        self.text_link(
-            emoji,
-            format!("tg://emoji?id={emoji_id}", emoji_id = emoji_id.as_ref()),
+        format!(
+            "![{emoji}](tg://emoji?id={emoji_id})",
+            emoji = emoji.as_ref(),
+            emoji_id = emoji_id.as_ref()
         )
     }
 
@@ -144,6 +158,13 @@ impl TextFormatter for Formatter {
         self.regex.replacen(text.as_ref(), 0, r"\$1").to_string()
     }
 
+    fn quote_url<U>(&self, url: U) -> String
+    where
+        U: AsRef<str>,
+    {
+        url.as_ref().replace('\\', r"\\").replace(')', r"\)")
+    }
+
     fn apply_entity<T>(&self, text: T, entity: &MessageEntity) -> Result<String, FormatterErrorKind>
     where
         T: AsRef<str>,
@@ -180,7 +201,7 @@ impl TextFormatter for Formatter {
             MessageEntityKind::Strikethrough => self.strikethrough(editable_text),
             MessageEntityKind::Spoiler => self.spoiler(editable_text),
             MessageEntityKind::Blockquote => self.blockquote(editable_text),
-            MessageEntityKind::ExpandableBlockquote => self.blockquote(editable_text),
+            MessageEntityKind::ExpandableBlockquote => self.expandable_blockquote(editable_text),
             MessageEntityKind::Code => self.code(editable_text),
             MessageEntityKind::Pre(PreMessageEntity { language }) => match language {
                 Some(language) => self.pre_language(editable_text, language),
@@ -227,6 +248,10 @@ pub fn blockquote(text: impl AsRef<str>) -> String {
     FORMATTER.blockquote(text)
 }
 
+pub fn expandable_blockquote(text: impl AsRef<str>) -> String {
+    FORMATTER.expandable_blockquote(text)
+}
+
 pub fn text_link(text: impl AsRef<str>, url: &str) -> String {
     FORMATTER.text_link(text, url)
 }
@@ -255,10 +280,16 @@ pub fn quote(text: impl AsRef<str>) -> String {
     FORMATTER.quote(text)
 }
 
+pub fn render_entities(text: impl AsRef<str>, entities: &[MessageEntity]) -> String {
+    FORMATTER.render_entities(text, entities)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use crate::utils::text::Builder;
+
     #[test]
     fn test_bold() {
         let formatter = Formatter::default();
@@ -286,7 +317,7 @@ mod tests {
     #[test]
     fn test_spoiler() {
         let formatter = Formatter::default();
-        assert_eq!(formatter.spoiler("text"), "|text|");
+        assert_eq!(formatter.spoiler("text"), "||text||");
     }
 
     #[test]
@@ -296,6 +327,16 @@ mod tests {
         assert_eq!(formatter.blockquote("text\ntext"), ">text\n>text");
     }
 
+    #[test]
+    fn test_expandable_blockquote() {
+        let formatter = Formatter::default();
+        assert_eq!(formatter.expandable_blockquote("text"), "**>text||");
+        assert_eq!(
+            formatter.expandable_blockquote("text\ntext"),
+            "**>text\n>text||"
+        );
+    }
+
     #[test]
     fn test_text_link() {
         let formatter = Formatter::default();
@@ -311,6 +352,15 @@ mod tests {
         assert_eq!(formatter.text_mention("text", 1), "[text](tg://user?id=1)");
     }
 
+    #[test]
+    fn test_custom_emoji() {
+        let formatter = Formatter::default();
+        assert_eq!(
+            formatter.custom_emoji("👍", "5368324170671202286"),
+            "![👍](tg://emoji?id=5368324170671202286)"
+        );
+    }
+
     #[test]
     fn test_code() {
         let formatter = Formatter::default();
@@ -355,6 +405,7 @@ mod tests {
         assert_eq!(formatter.quote("test ) test"), r"test \) test");
         assert_eq!(formatter.quote("test { test"), r"test \{ test");
         assert_eq!(formatter.quote("test } test"), r"test \} test");
+        assert_eq!(formatter.quote("test \r test"), "test \\\r test");
 
         // Test for all symbols (yes, I'm paranoid)
         assert_eq!(
@@ -362,4 +413,65 @@ mod tests {
             r"test \` \* \_ \~ \| \> \# \+ \- \= \. \! \[ \] \( \) \{ \} test"
         );
     }
+
+    #[test]
+    fn test_render_entities() {
+        let formatter = Formatter::default();
+        let text = "👋 Hello, world! 1.5 x_y user";
+        let entities = [
+            MessageEntity::new_bold(3, 5),
+            MessageEntity::new_italic(3, 5),
+            MessageEntity::new_text_link(10, 5, "https://example.com"),
+            MessageEntity::new_code(21, 3),
+            MessageEntity::new_spoiler(25, 4),
+        ];
+
+        let builder = Builder::new(formatter.clone())
+            .text("👋 ")
+            .bold(italic("Hello"))
+            .text(", ")
+            .text_link("world", "https://example.com")
+            .quote("! 1.5 ")
+            .code(quote("x_y"))
+            .text(" ")
+            .spoiler("user");
+
+        assert_eq!(
+            formatter.render_entities(text, &entities),
+            builder.get_text()
+        );
+        assert_eq!(
+            formatter.render_entities(
+                "link",
+                &[MessageEntity::new_text_link(
+                    0,
+                    4,
+                    "https://example.com/(a)"
+                )]
+            ),
+            r"[link](https://example.com/(a\))"
+        );
+
+        // Adjacent blockquotes are separated, so they aren't merged into one
+        assert_eq!(
+            formatter.render_entities(
+                "a\nb",
+                &[
+                    MessageEntity::new_blockquote(0, 1),
+                    MessageEntity::new_blockquote(2, 1),
+                ]
+            ),
+            ">a\n**>b"
+        );
+        assert_eq!(
+            formatter.render_entities(
+                "a\nb",
+                &[
+                    MessageEntity::new_blockquote(0, 1),
+                    MessageEntity::new_expandable_blockquote(2, 1),
+                ]
+            ),
+            ">a\n**>b||"
+        );
+    }
 }
@@ -0,0 +1,645 @@
+//! Parser of MarkdownV2-style formatted text into plain text and [`MessageEntity`] list.
+//!
+//! Text can be sent with the entities instead of `parse_mode`, so formatting errors are found locally
+//! with their positions instead of "can't parse entities" errors of Telegram.
+//! Syntax and escaping rules are the same as described in the
+//! [MarkdownV2 style](https://core.telegram.org/bots/api#markdownv2-style) documentation.
+//! `\r` chars are removed from the text, so they can be used to separate ambiguous markers, for example, `___italic underline_\r__`.
+//! Each line of a blockquote starts with `>`, including lines of code and pre-formatted blocks inside the blockquote.
+//!
+//! ```
+//! use telers::{types::MessageEntity, utils::text::parse_markdown};
+//!
+//! let (text, entities) = parse_markdown(r"*Hello*, [world](https://example.com)\!").unwrap();
+//!
+//! assert_eq!(text, "Hello, world!");
+//! assert_eq!(
+//!     entities,
+//!     [
+//!         MessageEntity::new_bold(0, 5),
+//!         MessageEntity::new_text_link(7, 5, "https://example.com"),
+//!     ],
+//! );
+//!
+//! let err = parse_markdown("Hello, world!").unwrap_err();
+//! assert_eq!(err.position, 12);
+//! ```
+
+use super::parser::{Error, ErrorKind, Output};
+
+use crate::types::{
+    CustomEmojiMessageEntity, MessageEntity, MessageEntityKind, PreMessageEntity,
+    TextLinkMessageEntity,
+};
+
+/// Chars which must be escaped outside of entities with their own escaping rules
+const RESERVED_CHARS: &[char] = &[
+    '_', '*', '[', ']', '(', ')', '~', '`', '>', '#', '+', '-', '=', '|', '{', '}', '.', '!',
+];
+const CUSTOM_EMOJI_URL_PREFIX: &str = "tg://emoji?id=";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Marker {
+    Bold,
+    Italic,
+    Underline,
+    Strikethrough,
+    Spoiler,
+    TextLink,
+    CustomEmoji,
+    Blockquote,
+    ExpandableBlockquote,
+}
+
+impl Marker {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Marker::Bold => "*",
+            Marker::Italic => "_",
+            Marker::Underline => "__",
+            Marker::Strikethrough => "~",
+            Marker::Spoiler => "||",
+            Marker::TextLink => "[",
+            Marker::CustomEmoji => "![",
+            Marker::Blockquote => ">",
+            Marker::ExpandableBlockquote => "**>",
+        }
+    }
+
+    const fn is_blockquote(self) -> bool {
+        matches!(self, Marker::Blockquote | Marker::ExpandableBlockquote)
+    }
+}
+
+/// Opened entity, which is waiting for its closing marker
+struct Frame {
+    marker: Marker,
+    /// Offset of the entity content in the plain text in UTF-16 code units
+    start: usize,
+    /// Position of the opening marker in the formatted text
+    position: usize,
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+    output: Output,
+    frames: Vec<Frame>,
+    /// Offset of the end of the last line of the opened blockquote in UTF-16 code units
+    blockquote_end: usize,
+}
+
+/// Parse MarkdownV2-style formatted text into plain text and its entities
+/// # Errors
+/// If the text isn't valid MarkdownV2-style formatted text, the error contains byte offset of the problem in the text
+pub fn parse(text: impl AsRef<str>) -> Result<(String, Vec<MessageEntity>), Error> {
+    let text = text.as_ref();
+
+    let mut parser = Parser {
+        text,
+        position: 0,
+        output: Output::default(),
+        frames: vec![],
+        blockquote_end: 0,
+    };
+    parser.parse()?;
+
+    Ok(parser.output.finish())
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.text[self.position..]
+    }
+
+    fn error(&self, kind: ErrorKind) -> Error {
+        Error::new(self.position, kind)
+    }
+
+    fn parse(&mut self) -> Result<(), Error> {
+        let mut line_start = true;
+
+        while let Some(char) = self.rest().chars().next() {
+            if line_start {
+                line_start = false;
+
+                self.parse_line_start()?;
+                continue;
+            }
+
+            match char {
+                '\\' => {
+                    let escaped = self.parse_escaped()?;
+
+                    self.output.push_char(escaped);
+                }
+                '\r' => self.position += 1,
+                '\n' => {
+                    self.blockquote_end = self.output.offset();
+                    self.output.push_char('\n');
+                    self.position += 1;
+
+                    line_start = true;
+                }
+                '*' => self.toggle(Marker::Bold)?,
+                '_' if self.rest().starts_with("__") => self.toggle(Marker::Underline)?,
+                '_' => self.toggle(Marker::Italic)?,
+                '~' => self.toggle(Marker::Strikethrough)?,
+                '|' if self.rest().starts_with("||") => {
+                    let is_line_end = matches!(self.rest()[2..].chars().next(), None | Some('\n'));
+
+                    match self.frames.last() {
+                        // `||` at the end of the line closes expandable blockquote, if it isn't spoiler end
+                        Some(Frame {
+                            marker: Marker::ExpandableBlockquote,
+                            ..
+                        }) if is_line_end => {
+                            self.close_blockquote(self.output.offset(), true)?;
+                            self.position += 2;
+                        }
+                        _ => self.toggle(Marker::Spoiler)?,
+                    }
+                }
+                '[' => self.open(Marker::TextLink),
+                '!' if self.rest().starts_with("![") => self.open(Marker::CustomEmoji),
+                ']' => self.parse_url()?,
+                '`' if self.rest().starts_with("```") => self.parse_pre()?,
+                '`' => self.parse_code()?,
+                char if RESERVED_CHARS.contains(&char) => {
+                    return Err(self.error(ErrorKind::UnescapedChar { char }));
+                }
+                char => {
+                    self.output.push_char(char);
+                    self.position += char.len_utf8();
+                }
+            }
+        }
+
+        if self
+            .frames
+            .last()
+            .map_or(false, |frame| frame.marker.is_blockquote())
+        {
+            // Line break at the end of the text isn't part of the blockquote
+            let end = if line_start {
+                self.blockquote_end
+            } else {
+                self.output.offset()
+            };
+
+            self.close_blockquote(end, false)?;
+        }
+
+        match self.frames.pop() {
+            Some(frame) => Err(Error::new(
+                frame.position,
+                ErrorKind::Unclosed {
+                    entity: frame.marker.as_str().into(),
+                },
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Parse blockquote markers at the start of the line
+    fn parse_line_start(&mut self) -> Result<(), Error> {
+        let marker = if self.rest().starts_with('>') {
+            Some(Marker::Blockquote)
+        } else if self.rest().starts_with("**>") {
+            Some(Marker::ExpandableBlockquote)
+        } else {
+            None
+        };
+        match (self.in_blockquote(), marker) {
+            // Continuation of the blockquote
+            (true, Some(Marker::Blockquote)) => {
+                self.position += 1;
+
+                return Ok(());
+            }
+            (true, _) => self.close_blockquote(self.blockquote_end, false)?,
+            (false, _) => {}
+        }
+
+        // Blockquotes can't be part of other entities
+        if let (Some(marker), true) = (marker, self.frames.is_empty()) {
+            self.open(marker);
+        }
+
+        Ok(())
+    }
+
+    fn in_blockquote(&self) -> bool {
+        self.frames
+            .first()
+            .map_or(false, |frame| frame.marker.is_blockquote())
+    }
+
+    fn parse_escaped(&mut self) -> Result<char, Error> {
+        match self.rest()[1..].chars().next() {
+            Some(char) if ('\u{1}'..='\u{7e}').contains(&char) => {
+                self.position += 2;
+
+                Ok(char)
+            }
+            _ => Err(self.error(ErrorKind::UnescapedChar { char: '\\' })),
+        }
+    }
+
+    fn open(&mut self, marker: Marker) {
+        self.frames.push(Frame {
+            marker,
+            start: self.output.offset(),
+            position: self.position,
+        });
+        self.position += marker.as_str().len();
+    }
+
+    /// Close the entity, if the marker is opened, otherwise open it
+    fn toggle(&mut self, marker: Marker) -> Result<(), Error> {
+        if !self.frames.iter().any(|frame| frame.marker == marker) {
+            self.open(marker);
+
+            return Ok(());
+        }
+
+        let frame = self.pop_frame(|frame_marker| frame_marker == marker)?;
+        let kind = match marker {
+            Marker::Bold => MessageEntityKind::Bold,
+            Marker::Italic => MessageEntityKind::Italic,
+            Marker::Underline => MessageEntityKind::Underline,
+            Marker::Strikethrough => MessageEntityKind::Strikethrough,
+            Marker::Spoiler => MessageEntityKind::Spoiler,
+            _ => {
+                unreachable!("Only markers with the same opening and closing parts can be toggled")
+            }
+        };
+
+        self.output
+            .add_entity(frame.start, self.output.offset(), kind, frame.position)?;
+        self.position += marker.as_str().len();
+
+        Ok(())
+    }
+
+    /// Pop the last opened frame, which must match the marker
+    /// # Errors
+    /// If the last opened frame doesn't match the marker, so entities cross each other
+    fn pop_frame(&mut self, marker: impl Fn(Marker) -> bool) -> Result<Frame, Error> {
+        match self.frames.pop() {
+            Some(frame) if marker(frame.marker) => Ok(frame),
+            Some(frame) => Err(Error::new(
+                frame.position,
+                ErrorKind::Unclosed {
+                    entity: frame.marker.as_str().into(),
+                },
+            )),
+            None => unreachable!("Frames can't be empty, because the marker is opened"),
+        }
+    }
+
+    /// Close the blockquote.
+    /// Blockquote opened with `**>` is expandable only if it's closed with `||`,
+    /// otherwise `**` is an empty bold entity, which separates it from the previous blockquote
+    fn close_blockquote(&mut self, end: usize, is_expandable_end: bool) -> Result<(), Error> {
+        let frame = self.pop_frame(Marker::is_blockquote)?;
+        let kind = if frame.marker == Marker::ExpandableBlockquote && is_expandable_end {
+            MessageEntityKind::ExpandableBlockquote
+        } else {
+            MessageEntityKind::Blockquote
+        };
+
+        self.output
+            .add_entity(frame.start, end, kind, frame.position)
+    }
+
+    /// Parse `](url)` part of text link or custom emoji
+    fn parse_url(&mut self) -> Result<(), Error> {
+        if !self
+            .frames
+            .iter()
+            .any(|frame| matches!(frame.marker, Marker::TextLink | Marker::CustomEmoji))
+        {
+            return Err(self.error(ErrorKind::UnescapedChar { char: ']' }));
+        }
+
+        let frame =
+            self.pop_frame(|marker| matches!(marker, Marker::TextLink | Marker::CustomEmoji))?;
+        let end = self.output.offset();
+
+        self.position += 1;
+
+        if !self.rest().starts_with('(') {
+            return Err(self.error(ErrorKind::MissingUrl {
+                entity: frame.marker.as_str().into(),
+            }));
+        }
+
+        let url_position = self.position;
+        let mut url = String::new();
+
+        self.position += 1;
+
+        loop {
+            match self.rest().chars().next() {
+                Some(')') => break,
+                Some('\\') => url.push(self.parse_escaped()?),
+                Some(char) => {
+                    url.push(char);
+                    self.position += char.len_utf8();
+                }
+                None => {
+                    return Err(Error::new(
+                        url_position,
+                        ErrorKind::Unclosed { entity: "(".into() },
+                    ));
+                }
+            }
+        }
+
+        let kind = if frame.marker == Marker::CustomEmoji {
+            match url.strip_prefix(CUSTOM_EMOJI_URL_PREFIX) {
+                Some(custom_emoji_id) if !custom_emoji_id.is_empty() => {
+                    MessageEntityKind::CustomEmoji(CustomEmojiMessageEntity::new(custom_emoji_id))
+                }
+                _ => {
+                    return Err(Error::new(
+                        url_position,
+                        ErrorKind::InvalidCustomEmojiUrl { url: url.into() },
+                    ));
+                }
+            }
+        } else {
+            MessageEntityKind::TextLink(TextLinkMessageEntity::new(url))
+        };
+
+        self.position += 1;
+        self.output
+            .add_entity(frame.start, end, kind, frame.position)
+    }
+
+    /// Parse content of code or pre-formatted block until the closing marker.
+    /// Inside them all `` ` `` and `\` chars must be escaped
+    fn parse_code_content(&mut self, marker: &str) -> Result<String, Error> {
+        let position = self.position;
+        let mut content = String::new();
+
+        self.position += marker.len();
+
+        loop {
+            match self.rest().chars().next() {
+                Some('`') if self.rest().starts_with(marker) => break,
+                Some('`') => return Err(self.error(ErrorKind::UnescapedChar { char: '`' })),
+                Some('\\') => content.push(self.parse_escaped()?),
+                Some('\n') => {
+                    content.push('\n');
+                    self.position += 1;
+
+                    // Lines of the blockquote start with `>`, even inside code
+                    if self.in_blockquote() && self.rest().starts_with('>') {
+                        self.position += 1;
+                    }
+                }
+                Some(char) => {
+                    content.push(char);
+                    self.position += char.len_utf8();
+                }
+                None => {
+                    return Err(Error::new(
+                        position,
+                        ErrorKind::Unclosed {
+                            entity: marker.into(),
+                        },
+                    ));
+                }
+            }
+        }
+
+        self.position += marker.len();
+
+        Ok(content)
+    }
+
+    fn parse_code(&mut self) -> Result<(), Error> {
+        let position = self.position;
+        let content = self.parse_code_content("`")?;
+        let start = self.output.offset();
+
+        self.output.push_str(&content);
+        self.output.add_entity(
+            start,
+            self.output.offset(),
+            MessageEntityKind::Code,
+            position,
+        )
+    }
+
+    /// Parse pre-formatted block. The first line is language of the block, if the block is multiline
+    fn parse_pre(&mut self) -> Result<(), Error> {
+        let position = self.position;
+        let content = self.parse_code_content("```")?;
+
+        let (language, content) = match content.split_once('\n') {
+            Some((language, content)) => (
+                Some(language).filter(|language| !language.is_empty()),
+                content.strip_suffix('\n').unwrap_or(content),
+            ),
+            None => (None, content.as_str()),
+        };
+        let start = self.output.offset();
+
+        self.output.push_str(content);
+        self.output.add_entity(
+            start,
+            self.output.offset(),
+            MessageEntityKind::Pre(PreMessageEntity {
+                language: language.map(ToOwned::to_owned),
+            }),
+            position,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::text::markdown_formatter;
+
+    #[test]
+    fn test_parse() {
+        let (text, entities) = parse(
+            r"👋 *Hello _world_*\! __u__ ~s~ ||sp|| [link](https://example.com/(a\)) ![👍](tg://emoji?id=1) `a\`b` ```rust
+fn main() {}
+```",
+        )
+        .unwrap();
+
+        assert_eq!(text, "👋 Hello world! u s sp link 👍 a`b fn main() {}");
+        assert_eq!(
+            entities,
+            [
+                MessageEntity::new_bold(3, 11),
+                MessageEntity::new_italic(9, 5),
+                MessageEntity::new_underline(16, 1),
+                MessageEntity::new_strikethrough(18, 1),
+                MessageEntity::new_spoiler(20, 2),
+                MessageEntity::new_text_link(23, 4, "https://example.com/(a)"),
+                MessageEntity::new_custom_emoji(28, 2, "1"),
+                MessageEntity::new_code(31, 3),
+                MessageEntity::new_pre_language(35, 12, "rust"),
+            ]
+        );
+        assert_eq!(
+            parse(markdown_formatter::render_entities(&text, &entities)).unwrap(),
+            (text, entities)
+        );
+
+        assert_eq!(
+            parse("___italic underline_\r__").unwrap(),
+            (
+                "italic underline".to_owned(),
+                vec![
+                    MessageEntity::new_underline(0, 16),
+                    MessageEntity::new_italic(0, 16),
+                ]
+            )
+        );
+        assert_eq!(
+            parse("```\npre\n```").unwrap().1,
+            [MessageEntity::new_pre(0, 3)]
+        );
+    }
+
+    #[test]
+    fn test_parse_blockquotes() {
+        let (text, entities) =
+            parse(">quote\n>line\nplain\n**>expandable\n>more||\n>last\n").unwrap();
+
+        assert_eq!(text, "quote\nline\nplain\nexpandable\nmore\nlast\n");
+        assert_eq!(
+            entities,
+            [
+                MessageEntity::new_blockquote(0, 10),
+                MessageEntity::new_expandable_blockquote(17, 15),
+                MessageEntity::new_blockquote(33, 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_blockquotes_round_trip() {
+        let cases = [
+            (
+                "x",
+                vec![
+                    MessageEntity::new_blockquote(0, 1),
+                    MessageEntity::new_pre(0, 1),
+                ],
+            ),
+            (
+                "fn main() {}\nmain()",
+                vec![
+                    MessageEntity::new_expandable_blockquote(0, 19),
+                    MessageEntity::new_pre_language(0, 19, "rust"),
+                ],
+            ),
+            (
+                "a\nb c",
+                vec![
+                    MessageEntity::new_blockquote(0, 5),
+                    MessageEntity::new_code(0, 3),
+                ],
+            ),
+            (
+                "quote sp\nline",
+                vec![
+                    MessageEntity::new_expandable_blockquote(0, 13),
+                    MessageEntity::new_spoiler(6, 2),
+                ],
+            ),
+            ("a\rb", vec![]),
+            (
+                "a\nb",
+                vec![
+                    MessageEntity::new_blockquote(0, 1),
+                    MessageEntity::new_blockquote(2, 1),
+                ],
+            ),
+            (
+                "a\nb",
+                vec![
+                    MessageEntity::new_expandable_blockquote(0, 1),
+                    MessageEntity::new_blockquote(2, 1),
+                ],
+            ),
+            (
+                "a\nb",
+                vec![
+                    MessageEntity::new_blockquote(0, 1),
+                    MessageEntity::new_expandable_blockquote(2, 1),
+                ],
+            ),
+        ];
+
+        for (text, entities) in cases {
+            let formatted = markdown_formatter::render_entities(text, &entities);
+
+            assert_eq!(
+                parse(&formatted).unwrap(),
+                (text.to_owned(), entities),
+                "{formatted:?}"
+            );
+        }
+
+        assert_eq!(
+            parse(">```x```").unwrap(),
+            (
+                "x".to_owned(),
+                vec![
+                    MessageEntity::new_blockquote(0, 1),
+                    MessageEntity::new_pre(0, 1)
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases = [
+            ("Hello, world!", 12, ErrorKind::UnescapedChar { char: '!' }),
+            ("a|b", 1, ErrorKind::UnescapedChar { char: '|' }),
+            ("a\\", 1, ErrorKind::UnescapedChar { char: '\\' }),
+            (
+                "*bold _italic* x_",
+                6,
+                ErrorKind::Unclosed { entity: "_".into() },
+            ),
+            ("*bold", 0, ErrorKind::Unclosed { entity: "*".into() }),
+            ("`code", 0, ErrorKind::Unclosed { entity: "`".into() }),
+            ("```a`b```", 4, ErrorKind::UnescapedChar { char: '`' }),
+            ("[link]", 6, ErrorKind::MissingUrl { entity: "[".into() }),
+            ("[link](url", 6, ErrorKind::Unclosed { entity: "(".into() }),
+            (
+                "![👍](https://example.com)",
+                7,
+                ErrorKind::InvalidCustomEmojiUrl {
+                    url: "https://example.com".into(),
+                },
+            ),
+            (
+                ">*quote\nplain*",
+                1,
+                ErrorKind::Unclosed { entity: "*".into() },
+            ),
+        ];
+
+        for (text, position, kind) in cases {
+            assert_eq!(
+                parse(text).unwrap_err(),
+                Error::new(position, kind),
+                "{text}"
+            );
+        }
+    }
+}
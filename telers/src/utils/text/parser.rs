@@ -0,0 +1,121 @@
+use crate::types::{MessageEntity, MessageEntityKind};
+
+use std::cmp::Reverse;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ErrorKind {
+    #[error("Unsupported tag `{tag}`")]
+    UnsupportedTag { tag: Box<str> },
+    #[error("Invalid syntax of tag")]
+    InvalidTag,
+    #[error("Tag `{tag}` must have `{attribute}` attribute")]
+    MissingAttribute {
+        tag: Box<str>,
+        attribute: &'static str,
+    },
+    #[error("Unexpected end tag `{tag}`")]
+    UnexpectedEndTag { tag: Box<str> },
+    #[error("Unsupported HTML entity `{entity}`")]
+    UnsupportedHtmlEntity { entity: Box<str> },
+    #[error("Entity `{entity}` isn't closed")]
+    Unclosed { entity: Box<str> },
+    #[error("Entity `{entity}` can't contain other entities")]
+    NestedEntity { entity: Box<str> },
+    #[error("Entity `{entity}` must be followed by URL in parentheses")]
+    MissingUrl { entity: Box<str> },
+    #[error("Invalid custom emoji URL `{url}`, expected `tg://emoji?id=<custom_emoji_id>`")]
+    InvalidCustomEmojiUrl { url: Box<str> },
+    #[error("Char `{char}` must be escaped")]
+    UnescapedChar { char: char },
+    #[error("Entities can't cover text after the first {max} UTF-16 code units", max = u16::MAX)]
+    TooLong,
+}
+
+/// Error of parsing formatted text into plain text and entities
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{kind} at position {position}")]
+pub struct Error {
+    /// Byte offset in the formatted text where the error occurred
+    pub position: usize,
+    pub kind: ErrorKind,
+}
+
+impl Error {
+    #[must_use]
+    pub const fn new(position: usize, kind: ErrorKind) -> Self {
+        Self { position, kind }
+    }
+}
+
+/// Plain text and its entities collected by parsers.
+/// Offsets of entities are tracked in UTF-16 code units as Telegram expects
+#[derive(Debug, Default)]
+pub(super) struct Output {
+    text: String,
+    utf16_len: usize,
+    entities: Vec<MessageEntity>,
+}
+
+impl Output {
+    pub(super) fn push_str(&mut self, text: &str) {
+        self.text.push_str(text);
+        self.utf16_len += text.encode_utf16().count();
+    }
+
+    pub(super) fn push_char(&mut self, char: char) {
+        self.text.push(char);
+        self.utf16_len += char.len_utf16();
+    }
+
+    /// Current length of the plain text in UTF-16 code units
+    pub(super) const fn offset(&self) -> usize {
+        self.utf16_len
+    }
+
+    /// Add the entity from `start` to `end` offset of the plain text.
+    /// Empty entities are skipped, because Telegram skips them too
+    /// # Arguments
+    /// * `start` - Offset of the entity start in UTF-16 code units
+    /// * `end` - Offset of the entity end in UTF-16 code units
+    /// * `kind` - Kind of the entity
+    /// * `position` - Position of the entity in the formatted text for errors
+    /// # Errors
+    /// If the entity is out of the range that can be described by [`MessageEntity`]
+    pub(super) fn add_entity(
+        &mut self,
+        start: usize,
+        end: usize,
+        kind: MessageEntityKind,
+        position: usize,
+    ) -> Result<(), Error> {
+        if start == end {
+            return Ok(());
+        }
+
+        let (Ok(offset), Ok(length), Ok(_)) = (
+            u16::try_from(start),
+            u16::try_from(end - start),
+            u16::try_from(end),
+        ) else {
+            return Err(Error::new(position, ErrorKind::TooLong));
+        };
+
+        self.entities.push(MessageEntity::new(offset, length, kind));
+
+        Ok(())
+    }
+
+    /// Get the plain text and its entities sorted by offset, outer entities go first
+    pub(super) fn finish(self) -> (String, Vec<MessageEntity>) {
+        // Entities are added when they are closed, so inner entities are added before outer ones
+        let mut entities = self.entities.into_iter().enumerate().collect::<Vec<_>>();
+        entities.sort_by_key(|(index, entity)| {
+            (entity.offset, Reverse(entity.length), Reverse(*index))
+        });
+
+        (
+            self.text,
+            entities.into_iter().map(|(_, entity)| entity).collect(),
+        )
+    }
+}